@@ -0,0 +1,14 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "createBookmark")]
+pub struct Request {
+    pub id: Uuid,
+    // Position in milliseconds.
+    pub position: u64,
+    pub comment: Option<String>,
+}
+
+#[api_derive]
+pub struct Response;
@@ -0,0 +1,28 @@
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+
+use crate::id3;
+
+#[api_derive]
+#[endpoint(path = "getBookmarks")]
+pub struct Request;
+
+#[api_derive]
+pub struct Bookmark {
+    pub position: u64,
+    pub username: String,
+    pub comment: Option<String>,
+    pub created: OffsetDateTime,
+    pub changed: OffsetDateTime,
+    pub entry: id3::song::Full,
+}
+
+#[api_derive]
+pub struct Bookmarks {
+    pub bookmark: Vec<Bookmark>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub bookmarks: Bookmarks,
+}
@@ -1,4 +1,5 @@
 use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::id3;
@@ -13,6 +14,9 @@ pub struct Playqueue {
     pub entry: Vec<id3::song::Short>,
     pub current: Option<Uuid>,
     pub position: Option<u64>,
+    pub username: Option<String>,
+    pub changed: Option<OffsetDateTime>,
+    pub changed_by: Option<String>,
 }
 
 #[api_derive]
@@ -0,0 +1,26 @@
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+
+use crate::id3;
+
+#[api_derive]
+#[endpoint(path = "getPlayQueueByIndex")]
+pub struct Request;
+
+#[api_derive]
+#[derive(Default)]
+pub struct PlayqueueByIndex {
+    pub entry: Vec<id3::song::Short>,
+    pub current_index: Option<u32>,
+    pub position: Option<u64>,
+    pub username: Option<String>,
+    pub changed: Option<OffsetDateTime>,
+    pub changed_by: Option<String>,
+}
+
+#[api_derive]
+#[derive(Default)]
+pub struct Response {
+    #[serde(rename = "playQueueByIndex")]
+    pub playqueue: PlayqueueByIndex,
+}
@@ -1,2 +1,7 @@
+pub mod create_bookmark;
+pub mod delete_bookmark;
+pub mod get_bookmarks;
 pub mod get_playqueue;
+pub mod get_playqueue_by_index;
 pub mod save_playqueue;
+pub mod save_playqueue_by_index;
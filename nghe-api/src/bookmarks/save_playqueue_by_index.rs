@@ -0,0 +1,14 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "savePlayQueueByIndex")]
+pub struct Request {
+    #[serde(rename = "id")]
+    pub ids: Vec<Uuid>,
+    pub current_index: Option<u32>,
+    pub position: Option<u64>,
+}
+
+#[api_derive]
+pub struct Response;
@@ -7,6 +7,7 @@ use crate::id3;
 #[endpoint(path = "getArtist")]
 pub struct Request {
     pub id: Uuid,
+    pub release_type: Option<String>,
 }
 
 #[api_derive]
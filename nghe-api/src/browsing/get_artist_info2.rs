@@ -1,10 +1,13 @@
 use nghe_proc_macro::api_derive;
 use uuid::Uuid;
 
+use crate::id3;
+
 #[api_derive]
 #[endpoint(path = "getArtistInfo2")]
 pub struct Request {
     pub id: Uuid,
+    pub count: Option<u32>,
 }
 
 #[api_derive]
@@ -14,6 +17,7 @@ pub struct ArtistInfo2 {
     #[serde(rename = "lastFmUrl")]
     pub lastfm_url: Option<String>,
     pub biography: Option<String>,
+    pub similar_artist: Vec<id3::artist::Artist>,
 }
 
 #[api_derive]
@@ -9,6 +9,7 @@ use crate::id3;
 pub struct Request {
     #[serde(rename = "musicFolderId")]
     pub music_folder_ids: Option<Vec<Uuid>>,
+    pub role: Option<id3::artist::Role>,
 }
 
 #[api_derive]
@@ -0,0 +1,37 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+use super::get_music_directory::Child;
+
+#[api_derive]
+#[endpoint(path = "getIndexes")]
+#[cfg_attr(feature = "test", derive(Default))]
+pub struct Request {
+    #[serde(rename = "musicFolderId")]
+    pub music_folder_ids: Option<Vec<Uuid>>,
+}
+
+#[api_derive]
+pub struct Artist {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[api_derive]
+pub struct Index {
+    pub name: String,
+    pub artist: Vec<Artist>,
+}
+
+#[api_derive]
+pub struct Indexes {
+    pub ignored_articles: String,
+    pub last_modified: u64,
+    pub index: Vec<Index>,
+    pub child: Vec<Child>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub indexes: Indexes,
+}
@@ -0,0 +1,46 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+use crate::id3;
+
+#[api_derive]
+#[endpoint(path = "getMusicDirectory")]
+pub struct Request {
+    pub id: Uuid,
+}
+
+#[api_derive]
+pub struct Folder {
+    pub id: Uuid,
+    pub parent: Uuid,
+    pub title: String,
+    pub is_dir: bool,
+}
+
+#[api_derive]
+pub struct File {
+    #[serde(flatten)]
+    pub song: id3::song::Short,
+    pub parent: Uuid,
+    pub is_dir: bool,
+}
+
+#[api_derive]
+#[serde(untagged)]
+pub enum Child {
+    File(Box<File>),
+    Folder(Folder),
+}
+
+#[api_derive]
+pub struct Directory {
+    pub id: Uuid,
+    pub parent: Option<Uuid>,
+    pub name: String,
+    pub child: Vec<Child>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub directory: Directory,
+}
@@ -0,0 +1,21 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+use crate::id3;
+
+#[api_derive]
+#[endpoint(path = "getSimilarSongs")]
+pub struct Request {
+    pub id: Uuid,
+    pub count: Option<u32>,
+}
+
+#[api_derive]
+pub struct SimilarSongs {
+    pub song: Vec<id3::song::Full>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub similar_songs: SimilarSongs,
+}
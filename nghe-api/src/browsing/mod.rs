@@ -4,6 +4,10 @@ pub mod get_artist;
 pub mod get_artist_info2;
 pub mod get_artists;
 pub mod get_genres;
+pub mod get_indexes;
+pub mod get_music_directory;
 pub mod get_music_folders;
+pub mod get_similar_songs;
+pub mod get_similar_songs2;
 pub mod get_song;
 pub mod get_top_songs;
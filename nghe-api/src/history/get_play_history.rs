@@ -0,0 +1,27 @@
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::id3;
+
+#[api_derive]
+#[endpoint(path = "getPlayHistory", internal = true)]
+pub struct Request {
+    pub count: Option<u32>,
+    pub offset: Option<u32>,
+    pub from: Option<OffsetDateTime>,
+    pub to: Option<OffsetDateTime>,
+}
+
+#[api_derive]
+pub struct Play {
+    pub song_id: Uuid,
+    pub played_at: OffsetDateTime,
+    pub client: Option<String>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub plays: Vec<Play>,
+    pub songs: Vec<id3::song::Short>,
+}
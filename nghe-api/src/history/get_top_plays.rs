@@ -0,0 +1,26 @@
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "getTopPlays", internal = true)]
+pub struct Request {
+    pub count: Option<u32>,
+    pub from: Option<OffsetDateTime>,
+    pub to: Option<OffsetDateTime>,
+}
+
+#[api_derive]
+pub struct Top {
+    pub id: Uuid,
+    pub name: String,
+    pub play_count: u64,
+}
+
+#[api_derive]
+pub struct Response {
+    pub songs: Vec<Top>,
+    pub albums: Vec<Top>,
+    pub artists: Vec<Top>,
+    pub genres: Vec<Top>,
+}
@@ -0,0 +1,2 @@
+pub mod get_play_history;
+pub mod get_top_plays;
@@ -8,6 +8,11 @@ use uuid::Uuid;
 
 use super::{artist, date, genre};
 
+#[api_derive]
+pub struct RecordLabel {
+    pub name: String,
+}
+
 #[api_derive]
 #[derive(Builder)]
 #[builder(on(_, required))]
@@ -21,6 +26,7 @@ pub struct Album {
     pub created: OffsetDateTime,
     pub year: Option<u16>,
     pub music_brainz_id: Option<Uuid>,
+    pub sort_name: Option<String>,
     #[builder(default)]
     pub genres: genre::Genres,
     #[builder(default)]
@@ -30,4 +36,14 @@ pub struct Album {
     #[builder(default)]
     pub release_date: date::Date,
     pub starred: Option<OffsetDateTime>,
+    pub user_rating: Option<u8>,
+    pub average_rating: Option<f32>,
+    #[builder(default)]
+    pub release_types: Vec<String>,
+    #[builder(default)]
+    pub record_labels: Vec<RecordLabel>,
+    #[builder(default)]
+    pub catalog_number: Option<String>,
+    #[builder(default)]
+    pub barcode: Option<String>,
 }
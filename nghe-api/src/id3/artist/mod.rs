@@ -10,11 +10,17 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 #[api_derive(response = false)]
-#[derive(PartialEq, Eq, IntoStaticStr)]
+#[derive(Clone, PartialEq, Eq, IntoStaticStr)]
 #[strum(serialize_all = "lowercase")]
 pub enum Role {
     Artist,
     AlbumArtist,
+    Composer,
+    Conductor,
+    Performer,
+    Lyricist,
+    Arranger,
+    Producer,
 }
 
 #[api_derive]
@@ -28,7 +34,10 @@ pub struct Artist {
     pub cover_art: Option<Uuid>,
     pub album_count: u16,
     pub starred: Option<OffsetDateTime>,
+    pub user_rating: Option<u8>,
+    pub average_rating: Option<f32>,
     pub music_brainz_id: Option<Uuid>,
+    pub sort_name: Option<String>,
     #[builder(default)]
     pub roles: Vec<Role>,
 }
@@ -2,6 +2,7 @@ use nghe_proc_macro::api_derive;
 use uuid::Uuid;
 
 #[api_derive]
+#[derive(Clone)]
 #[cfg_attr(feature = "test", derive(PartialEq))]
 pub struct Required {
     pub id: Uuid,
@@ -4,13 +4,14 @@ use nghe_proc_macro::api_derive;
 pub use with_count::WithCount;
 
 #[api_derive]
-#[cfg_attr(feature = "test", derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash))]
+#[derive(Clone)]
+#[cfg_attr(feature = "test", derive(PartialEq, Eq, PartialOrd, Ord, Hash))]
 pub struct Genre {
     pub name: String,
 }
 
 #[api_derive(serde_apply = false)]
-#[derive(Default)]
+#[derive(Clone, Default)]
 #[serde(transparent)]
 pub struct Genres {
     pub value: Vec<Genre>,
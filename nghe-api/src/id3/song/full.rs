@@ -4,6 +4,7 @@ use super::Short;
 use crate::id3::genre;
 
 #[api_derive]
+#[derive(Clone)]
 pub struct Full {
     #[serde(flatten)]
     pub short: Short,
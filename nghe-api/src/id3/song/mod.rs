@@ -13,7 +13,24 @@ use uuid::Uuid;
 use super::artist;
 
 #[api_derive]
-#[derive(Builder)]
+#[derive(Clone)]
+pub struct ReplayGain {
+    pub track_gain: Option<f32>,
+    pub album_gain: Option<f32>,
+    pub track_peak: Option<f32>,
+    pub album_peak: Option<f32>,
+}
+
+#[api_derive]
+#[derive(Clone)]
+pub struct Contributor {
+    pub role: artist::Role,
+    pub sub_role: Option<String>,
+    pub artist: artist::Required,
+}
+
+#[api_derive]
+#[derive(Clone, Builder)]
 #[builder(on(_, required))]
 #[builder(state_mod(vis = "pub"))]
 pub struct Song {
@@ -36,5 +53,11 @@ pub struct Song {
     pub artist_id: Uuid,
     pub artists: Vec<artist::Required>,
     pub music_brainz_id: Option<Uuid>,
+    pub sort_name: Option<String>,
     pub starred: Option<OffsetDateTime>,
+    pub user_rating: Option<u8>,
+    pub average_rating: Option<f32>,
+    pub replay_gain: Option<ReplayGain>,
+    pub contributors: Vec<Contributor>,
+    pub display_composer: Option<String>,
 }
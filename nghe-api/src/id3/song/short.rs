@@ -4,6 +4,7 @@ use uuid::Uuid;
 use super::Song;
 
 #[api_derive]
+#[derive(Clone)]
 pub struct Short {
     #[serde(flatten)]
     pub song: Song,
@@ -0,0 +1,43 @@
+use nghe_proc_macro::api_derive;
+
+#[api_derive]
+#[endpoint(path = "createInternetRadioStation")]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Request {
+    pub stream_url: String,
+    pub name: String,
+    pub homepage_url: Option<String>,
+}
+
+#[api_derive]
+pub struct Response;
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        "streamUrl=https://radio.example/stream&name=radio",
+        Some(Request {
+            stream_url: "https://radio.example/stream".to_owned(),
+            name: "radio".to_owned(),
+            homepage_url: None,
+        })
+    )]
+    #[case(
+        "streamUrl=https://radio.example/stream&name=radio&homepageUrl=https://radio.example",
+        Some(Request {
+            stream_url: "https://radio.example/stream".to_owned(),
+            name: "radio".to_owned(),
+            homepage_url: Some("https://radio.example".to_owned()),
+        })
+    )]
+    #[case("name=radio", None)]
+    fn test_deserialize(#[case] url: &str, #[case] request: Option<Request>) {
+        assert_eq!(serde_html_form::from_str::<Request>(url).ok(), request);
+    }
+}
@@ -0,0 +1,17 @@
+use nghe_proc_macro::api_derive;
+
+use super::InternetRadioStation;
+
+#[api_derive]
+#[endpoint(path = "getInternetRadioStations")]
+pub struct Request;
+
+#[api_derive]
+pub struct InternetRadioStations {
+    pub internet_radio_station: Vec<InternetRadioStation>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub internet_radio_stations: InternetRadioStations,
+}
@@ -0,0 +1,17 @@
+pub mod create_internet_radio_station;
+pub mod delete_internet_radio_station;
+pub mod get_internet_radio_stations;
+pub mod stream_internet_radio_station;
+pub mod update_internet_radio_station;
+
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+pub struct InternetRadioStation {
+    pub id: Uuid,
+    pub name: String,
+    pub stream_url: String,
+    #[serde(rename = "homePageUrl")]
+    pub homepage_url: Option<String>,
+}
@@ -0,0 +1,14 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+use crate::common::format;
+
+#[api_derive]
+#[endpoint(path = "streamInternetRadioStation", url_only = true)]
+#[derive(Clone, Copy)]
+pub struct Request {
+    pub id: Uuid,
+    pub max_bit_rate: Option<u32>,
+    // The station is re-encoded to this format, `opus` if not specified.
+    pub format: Option<format::Transcode>,
+}
@@ -0,0 +1,14 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "updateInternetRadioStation")]
+pub struct Request {
+    pub id: Uuid,
+    pub stream_url: String,
+    pub name: String,
+    pub homepage_url: Option<String>,
+}
+
+#[api_derive]
+pub struct Response;
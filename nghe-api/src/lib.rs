@@ -8,7 +8,9 @@ pub mod bookmarks;
 pub mod browsing;
 pub mod common;
 pub mod constant;
+pub mod history;
 pub mod id3;
+pub mod internet_radio;
 pub mod key;
 pub mod lists;
 pub mod media_annotation;
@@ -16,8 +18,11 @@ pub mod media_retrieval;
 pub mod music_folder;
 pub mod permission;
 pub mod playlists;
+pub mod podcasts;
 pub mod scan;
+pub mod scrobbler;
 pub mod search;
+pub mod sharing;
 pub mod system;
 pub mod time;
 pub mod user;
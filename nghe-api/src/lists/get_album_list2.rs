@@ -13,6 +13,7 @@ pub enum Type {
     Newest,
     Frequent,
     Recent,
+    Highest,
     AlphabeticalByName,
     ByYear {
         #[serde_as(as = "serde_with::DisplayFromStr")]
@@ -35,6 +36,7 @@ pub struct Request {
     pub offset: Option<u32>,
     #[serde(rename = "musicFolderId")]
     pub music_folder_ids: Option<Vec<Uuid>>,
+    pub release_type: Option<String>,
 }
 
 #[api_derive]
@@ -63,6 +65,7 @@ mod tests {
     #[case("type=newest", Some(Request { ty: Type::Newest, ..Default::default() }))]
     #[case("type=frequent", Some(Request { ty: Type::Frequent, ..Default::default() }))]
     #[case("type=recent", Some(Request { ty: Type::Recent, ..Default::default() }))]
+    #[case("type=highest", Some(Request { ty: Type::Highest, ..Default::default() }))]
     #[case(
         "type=alphabeticalByName",
         Some(Request { ty: Type::AlphabeticalByName, ..Default::default() })
@@ -95,6 +98,12 @@ mod tests {
             ty: Type::ByGenre { genre: "Test".to_owned() }, size: Some(10), ..Default::default()
         })
     )]
+    #[case(
+        "type=newest&releaseType=single",
+        Some(Request {
+            ty: Type::Newest, release_type: Some("single".to_owned()), ..Default::default()
+        })
+    )]
     #[case("type=byYear&toYear=2000", None)]
     #[case("type=byYear&fromYear=From&toYear=2000", None)]
     #[case("type=byGenre", None)]
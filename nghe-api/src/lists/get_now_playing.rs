@@ -0,0 +1,27 @@
+use nghe_proc_macro::api_derive;
+
+use crate::id3;
+
+#[api_derive]
+#[endpoint(path = "getNowPlaying")]
+pub struct Request;
+
+#[api_derive]
+pub struct Entry {
+    #[serde(flatten)]
+    pub song: id3::song::Full,
+    pub username: String,
+    pub minutes_ago: u32,
+    pub player_id: u32,
+    pub player_name: Option<String>,
+}
+
+#[api_derive]
+pub struct NowPlaying {
+    pub entry: Vec<Entry>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub now_playing: NowPlaying,
+}
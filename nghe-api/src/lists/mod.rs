@@ -1,4 +1,5 @@
 pub mod get_album_list2;
+pub mod get_now_playing;
 pub mod get_random_songs;
 pub mod get_songs_by_genre;
 pub mod get_starred2;
@@ -1,4 +1,5 @@
 pub mod scrobble;
+pub mod set_rating;
 pub mod star;
 pub mod unstar;
 pub mod update_artist_information;
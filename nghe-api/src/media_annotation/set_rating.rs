@@ -0,0 +1,13 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "setRating")]
+pub struct Request {
+    pub id: Uuid,
+    // Zero removes the rating.
+    pub rating: u8,
+}
+
+#[api_derive]
+pub struct Response;
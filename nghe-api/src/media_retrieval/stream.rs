@@ -11,6 +11,13 @@ pub enum Format {
     Transcode(format::Transcode),
 }
 
+#[api_derive]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReplayGain {
+    Track,
+    Album,
+}
+
 #[api_derive]
 #[endpoint(path = "stream", url_only = true)]
 #[derive(Clone, Copy)]
@@ -19,6 +26,7 @@ pub struct Request {
     pub max_bit_rate: Option<u32>,
     pub format: Option<Format>,
     pub time_offset: Option<u32>,
+    pub replay_gain: Option<ReplayGain>,
 }
 
 impl From<format::Transcode> for Format {
@@ -0,0 +1,13 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "addPlaylistUser", internal = true)]
+pub struct Request {
+    pub playlist_id: Uuid,
+    pub user_id: Uuid,
+    pub write: bool,
+}
+
+#[api_derive]
+pub struct Response;
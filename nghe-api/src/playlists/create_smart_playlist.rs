@@ -0,0 +1,16 @@
+use nghe_proc_macro::api_derive;
+
+use super::playlist;
+use super::rules::Rules;
+
+#[api_derive]
+#[endpoint(path = "createSmartPlaylist", internal = true)]
+pub struct Request {
+    pub name: String,
+    pub rules: Rules,
+}
+
+#[api_derive]
+pub struct Response {
+    pub playlist: playlist::Full,
+}
@@ -0,0 +1,21 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    M3u8,
+    Xspf,
+}
+
+#[api_derive]
+#[endpoint(path = "exportPlaylist", internal = true)]
+pub struct Request {
+    pub id: Uuid,
+    pub format: Format,
+}
+
+#[api_derive]
+pub struct Response {
+    pub content: String,
+}
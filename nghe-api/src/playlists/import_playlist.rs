@@ -0,0 +1,21 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+use super::playlist;
+
+#[api_derive]
+#[endpoint(path = "importPlaylist", internal = true)]
+pub struct Request {
+    pub name: String,
+    // Relative paths of the playlist are resolved against this music folder.
+    pub music_folder_id: Uuid,
+    // M3U, M3U8 or XSPF content.
+    pub content: String,
+}
+
+#[api_derive]
+pub struct Response {
+    pub playlist: playlist::Full,
+    // Locations of the entries without any matching song.
+    pub unresolved: Vec<String>,
+}
@@ -1,6 +1,14 @@
+pub mod add_playlist_user;
 pub mod create_playlist;
+pub mod create_smart_playlist;
 pub mod delete_playlist;
+pub mod export_playlist;
 pub mod get_playlist;
 pub mod get_playlists;
+pub mod import_playlist;
 pub mod playlist;
+pub mod remove_playlist_user;
+pub mod rules;
+pub mod transfer_playlist_owner;
 pub mod update_playlist;
+pub mod update_smart_playlist;
@@ -14,6 +14,8 @@ pub struct Playlist {
     pub name: String,
     pub comment: Option<String>,
     pub public: bool,
+    pub owner: String,
+    pub readonly: bool,
     pub song_count: u16,
     pub duration: time::Duration,
     pub created: OffsetDateTime,
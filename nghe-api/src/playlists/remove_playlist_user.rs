@@ -0,0 +1,12 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "removePlaylistUser", internal = true)]
+pub struct Request {
+    pub playlist_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[api_derive]
+pub struct Response;
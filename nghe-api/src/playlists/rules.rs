@@ -0,0 +1,44 @@
+use nghe_proc_macro::api_derive;
+
+#[repr(i16)]
+#[api_derive]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum Sort {
+    #[default]
+    Title,
+    Year,
+    Added,
+    PlayCount,
+    LastPlayed,
+    Rating,
+    Random,
+}
+
+// All given rules must match.
+#[api_derive]
+#[derive(Clone)]
+#[cfg_attr(feature = "test", derive(Default))]
+pub struct Rules {
+    // Any of these genres.
+    pub genres: Vec<String>,
+    // Any of these artists.
+    pub artists: Vec<String>,
+    // Any of these formats, e.g. `flac`.
+    pub formats: Vec<String>,
+    pub from_year: Option<u16>,
+    pub to_year: Option<u16>,
+    pub min_bit_depth: Option<u8>,
+    pub max_bit_depth: Option<u8>,
+    pub min_play_count: Option<u32>,
+    pub max_play_count: Option<u32>,
+    pub played_within_days: Option<u32>,
+    pub not_played_within_days: Option<u32>,
+    pub starred: Option<bool>,
+    pub min_rating: Option<u8>,
+    pub added_within_days: Option<u32>,
+    #[serde(default)]
+    pub sort: Sort,
+    #[serde(default)]
+    pub descending: bool,
+    pub limit: Option<u32>,
+}
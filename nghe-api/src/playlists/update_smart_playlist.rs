@@ -0,0 +1,14 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+use super::rules::Rules;
+
+#[api_derive]
+#[endpoint(path = "updateSmartPlaylist", internal = true)]
+pub struct Request {
+    pub playlist_id: Uuid,
+    pub rules: Rules,
+}
+
+#[api_derive]
+pub struct Response;
@@ -0,0 +1,10 @@
+use nghe_proc_macro::api_derive;
+
+#[api_derive]
+#[endpoint(path = "createPodcastChannel")]
+pub struct Request {
+    pub url: String,
+}
+
+#[api_derive]
+pub struct Response;
@@ -0,0 +1,11 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "deletePodcastEpisode")]
+pub struct Request {
+    pub id: Uuid,
+}
+
+#[api_derive]
+pub struct Response;
@@ -0,0 +1,19 @@
+use nghe_proc_macro::api_derive;
+
+use super::Episode;
+
+#[api_derive]
+#[endpoint(path = "getNewestPodcasts")]
+pub struct Request {
+    pub count: Option<u32>,
+}
+
+#[api_derive]
+pub struct NewestPodcasts {
+    pub episode: Vec<Episode>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub newest_podcasts: NewestPodcasts,
+}
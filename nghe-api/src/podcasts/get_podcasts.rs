@@ -0,0 +1,21 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+use super::Channel;
+
+#[api_derive]
+#[endpoint(path = "getPodcasts")]
+pub struct Request {
+    pub include_episodes: Option<bool>,
+    pub id: Option<Uuid>,
+}
+
+#[api_derive]
+pub struct Podcasts {
+    pub channel: Vec<Channel>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub podcasts: Podcasts,
+}
@@ -0,0 +1,54 @@
+pub mod create_podcast_channel;
+pub mod delete_podcast_channel;
+pub mod delete_podcast_episode;
+pub mod download_podcast_episode;
+pub mod get_newest_podcasts;
+pub mod get_podcasts;
+pub mod refresh_podcasts;
+
+use std::borrow::Cow;
+
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[repr(i16)]
+#[api_derive]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    New,
+    Downloading,
+    Completed,
+    Error,
+    Deleted,
+}
+
+#[api_derive]
+pub struct Episode {
+    pub id: Uuid,
+    pub stream_id: Option<Uuid>,
+    pub channel_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub publish_date: Option<OffsetDateTime>,
+    pub status: Status,
+    pub is_dir: bool,
+    pub size: Option<u32>,
+    pub content_type: Option<Cow<'static, str>>,
+    pub suffix: Option<Cow<'static, str>>,
+    // In seconds.
+    pub duration: Option<u32>,
+    pub bit_rate: Option<u32>,
+}
+
+#[api_derive]
+pub struct Channel {
+    pub id: Uuid,
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub original_image_url: Option<String>,
+    pub status: Status,
+    pub error_message: Option<String>,
+    pub episode: Vec<Episode>,
+}
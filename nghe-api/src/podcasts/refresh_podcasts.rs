@@ -0,0 +1,8 @@
+use nghe_proc_macro::api_derive;
+
+#[api_derive]
+#[endpoint(path = "refreshPodcasts")]
+pub struct Request;
+
+#[api_derive]
+pub struct Response;
@@ -0,0 +1,23 @@
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::set_scan_schedule::Schedule;
+use super::start::Full;
+
+#[api_derive]
+#[endpoint(path = "getScanSchedules", internal = true)]
+pub struct Request;
+
+#[api_derive]
+pub struct ScanSchedule {
+    pub music_folder_id: Uuid,
+    pub schedule: Schedule,
+    pub full: Full,
+    pub next_scan_at: OffsetDateTime,
+}
+
+#[api_derive]
+pub struct Response {
+    pub scan_schedules: Vec<ScanSchedule>,
+}
@@ -0,0 +1,18 @@
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+
+#[api_derive]
+#[endpoint(path = "getScanStatus")]
+pub struct Request;
+
+#[api_derive]
+pub struct ScanStatus {
+    pub scanning: bool,
+    pub count: u64,
+    pub last_scan: Option<OffsetDateTime>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub scan_status: ScanStatus,
+}
@@ -0,0 +1,32 @@
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "getScans", internal = true)]
+pub struct Request {
+    pub music_folder_id: Uuid,
+    pub count: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[api_derive]
+pub struct Scan {
+    pub started_at: OffsetDateTime,
+    pub is_scanning: bool,
+    pub finished_at: Option<OffsetDateTime>,
+    pub scanned_song_count: u64,
+    pub upserted_song_count: u64,
+    pub deleted_song_count: u64,
+    pub deleted_album_count: u64,
+    pub deleted_artist_count: u64,
+    pub deleted_genre_count: u64,
+    pub scan_error_count: u64,
+    pub unrecoverable: Option<bool>,
+    pub cancelled: bool,
+}
+
+#[api_derive]
+pub struct Response {
+    pub scans: Vec<Scan>,
+}
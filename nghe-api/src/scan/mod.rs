@@ -1 +1,7 @@
+pub mod cancel;
+pub mod clear_scan_schedule;
+pub mod get_scan_schedules;
+pub mod get_scan_status;
+pub mod get_scans;
+pub mod set_scan_schedule;
 pub mod start;
@@ -0,0 +1,27 @@
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::start::Full;
+
+#[api_derive]
+#[derive(Clone)]
+pub enum Schedule {
+    Cron(String),
+    // In seconds
+    Interval(u32),
+}
+
+#[api_derive]
+#[endpoint(path = "setScanSchedule", internal = true)]
+pub struct Request {
+    pub music_folder_id: Uuid,
+    pub schedule: Schedule,
+    #[serde(default)]
+    pub full: Full,
+}
+
+#[api_derive]
+pub struct Response {
+    pub next_scan_at: OffsetDateTime,
+}
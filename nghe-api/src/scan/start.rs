@@ -12,6 +12,8 @@ pub struct Full {
     pub dir_image: bool,
     #[serde(default)]
     pub information: bool,
+    #[serde(default)]
+    pub loudness: bool,
 }
 
 #[api_derive]
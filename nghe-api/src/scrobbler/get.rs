@@ -0,0 +1,18 @@
+use nghe_proc_macro::api_derive;
+
+use super::Service;
+
+#[api_derive]
+#[endpoint(path = "getScrobblers", internal = true)]
+pub struct Request;
+
+#[api_derive]
+pub struct Scrobbler {
+    pub service: Service,
+    pub username: String,
+}
+
+#[api_derive]
+pub struct Response {
+    pub scrobblers: Vec<Scrobbler>,
+}
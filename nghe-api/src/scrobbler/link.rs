@@ -0,0 +1,16 @@
+use nghe_proc_macro::api_derive;
+
+use super::Service;
+
+#[api_derive]
+#[endpoint(path = "linkScrobbler", internal = true)]
+pub struct Request {
+    pub service: Service,
+    // Last.fm web authentication token or ListenBrainz user token.
+    pub token: String,
+}
+
+#[api_derive]
+pub struct Response {
+    pub username: String,
+}
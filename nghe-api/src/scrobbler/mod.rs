@@ -0,0 +1,13 @@
+pub mod get;
+pub mod link;
+pub mod unlink;
+
+use nghe_proc_macro::api_derive;
+
+#[repr(i16)]
+#[api_derive]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Service {
+    Lastfm,
+    Listenbrainz,
+}
@@ -0,0 +1,12 @@
+use nghe_proc_macro::api_derive;
+
+use super::Service;
+
+#[api_derive]
+#[endpoint(path = "unlinkScrobbler", internal = true)]
+pub struct Request {
+    pub service: Service,
+}
+
+#[api_derive]
+pub struct Response;
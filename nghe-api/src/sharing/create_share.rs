@@ -0,0 +1,61 @@
+use nghe_proc_macro::api_derive;
+use serde_with::TimestampMilliSeconds;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::get_shares::Shares;
+
+#[api_derive(serde_as = true)]
+#[endpoint(path = "createShare")]
+#[cfg_attr(test, derive(Default, PartialEq))]
+pub struct Request {
+    // Song, album or playlist ids.
+    #[serde(rename = "id")]
+    pub ids: Vec<Uuid>,
+    pub description: Option<String>,
+    #[serde_with(skip_apply)]
+    #[serde_as(as = "Option<TimestampMilliSeconds<i64>>")]
+    #[serde(default)]
+    pub expires: Option<OffsetDateTime>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub shares: Shares,
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+    use time::macros::datetime;
+    use uuid::uuid;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        "id=d4ea6896-a838-446c-ace4-d9d13d336391",
+        Some(Request {
+            ids: vec![uuid!("d4ea6896-a838-446c-ace4-d9d13d336391")],
+            ..Default::default()
+        })
+    )]
+    #[case(
+        "id=d4ea6896-a838-446c-ace4-d9d13d336391&\
+        id=2b839103-04ab-4b39-9b05-8c664590eda4&\
+        description=description&expires=1000000000000",
+        Some(Request {
+            ids: vec![
+                uuid!("d4ea6896-a838-446c-ace4-d9d13d336391"),
+                uuid!("2b839103-04ab-4b39-9b05-8c664590eda4")
+            ],
+            description: Some("description".to_owned()),
+            expires: Some(datetime!(2001-09-09 01:46:40.000 UTC)),
+        })
+    )]
+    #[case("id=d4ea6896-a838-446c-ace4-d9d13d336391&expires=none", None)]
+    fn test_deserialize(#[case] url: &str, #[case] request: Option<Request>) {
+        assert_eq!(serde_html_form::from_str::<Request>(url).ok(), request);
+    }
+}
@@ -0,0 +1,9 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "downloadPublicShare", url_only = true)]
+pub struct Request {
+    pub share_id: Uuid,
+    pub id: Uuid,
+}
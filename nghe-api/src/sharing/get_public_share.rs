@@ -0,0 +1,15 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+use super::share::Share;
+
+#[api_derive]
+#[endpoint(path = "getPublicShare", internal = true)]
+pub struct Request {
+    pub id: Uuid,
+}
+
+#[api_derive]
+pub struct Response {
+    pub share: Share,
+}
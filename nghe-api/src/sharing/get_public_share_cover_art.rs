@@ -0,0 +1,11 @@
+use nghe_proc_macro::api_derive;
+use uuid::Uuid;
+
+#[api_derive]
+#[endpoint(path = "getPublicShareCoverArt", url_only = true)]
+#[derive(Clone, Copy)]
+pub struct Request {
+    pub share_id: Uuid,
+    pub id: Uuid,
+    pub size: Option<u32>,
+}
@@ -0,0 +1,17 @@
+use nghe_proc_macro::api_derive;
+
+use super::share::Share;
+
+#[api_derive]
+#[endpoint(path = "getShares")]
+pub struct Request;
+
+#[api_derive]
+pub struct Shares {
+    pub share: Vec<Share>,
+}
+
+#[api_derive]
+pub struct Response {
+    pub shares: Shares,
+}
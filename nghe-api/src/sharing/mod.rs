@@ -0,0 +1,8 @@
+pub mod create_share;
+pub mod delete_share;
+pub mod download_public_share;
+pub mod get_public_share;
+pub mod get_public_share_cover_art;
+pub mod get_shares;
+pub mod share;
+pub mod update_share;
@@ -0,0 +1,18 @@
+use nghe_proc_macro::api_derive;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::id3;
+
+#[api_derive]
+pub struct Share {
+    pub id: Uuid,
+    pub url: String,
+    pub description: Option<String>,
+    pub username: String,
+    pub created: OffsetDateTime,
+    pub expires: Option<OffsetDateTime>,
+    pub last_visited: Option<OffsetDateTime>,
+    pub visit_count: u32,
+    pub entry: Vec<id3::song::Short>,
+}
@@ -0,0 +1,20 @@
+use nghe_proc_macro::api_derive;
+use serde_with::TimestampMilliSeconds;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[api_derive(serde_as = true)]
+#[endpoint(path = "updateShare")]
+pub struct Request {
+    pub id: Uuid,
+    // An empty description removes the current one.
+    pub description: Option<String>,
+    // An expiry at the unix epoch removes the current one.
+    #[serde_with(skip_apply)]
+    #[serde_as(as = "Option<TimestampMilliSeconds<i64>>")]
+    #[serde(default)]
+    pub expires: Option<OffsetDateTime>,
+}
+
+#[api_derive]
+pub struct Response;
@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+drop index music_folders_scan_next_at_idx;
+
+alter table music_folders
+drop constraint music_folders_scan_schedule,
+drop constraint music_folders_scan_interval_positive,
+drop column scan_cron,
+drop column scan_interval,
+drop column scan_full_file,
+drop column scan_full_external_lyric,
+drop column scan_full_dir_image,
+drop column scan_full_information,
+drop column scan_next_at;
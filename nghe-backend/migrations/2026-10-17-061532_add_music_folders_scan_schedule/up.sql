@@ -0,0 +1,30 @@
+-- Your SQL goes here
+alter table music_folders
+add column scan_cron text,
+add column scan_interval bigint,
+add column scan_full_file boolean not null default false,
+add column scan_full_external_lyric boolean not null default false,
+add column scan_full_dir_image boolean not null default false,
+add column scan_full_information boolean not null default false,
+add column scan_next_at timestamptz,
+add constraint music_folders_scan_interval_positive check (scan_interval > 0),
+add constraint music_folders_scan_schedule check (
+    (
+        scan_cron is null
+        and scan_interval is null
+        and scan_next_at is null
+    )
+    or (
+        scan_cron is not null
+        and scan_interval is null
+        and scan_next_at is not null
+    )
+    or (
+        scan_cron is null
+        and scan_interval is not null
+        and scan_next_at is not null
+    )
+);
+
+create index music_folders_scan_next_at_idx on music_folders (scan_next_at)
+where scan_next_at is not null;
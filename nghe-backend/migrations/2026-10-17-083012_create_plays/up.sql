@@ -0,0 +1,17 @@
+-- Your SQL goes here
+create table plays (
+    id uuid not null default gen_random_uuid() constraint plays_pkey primary key,
+    user_id uuid not null,
+    song_id uuid not null,
+    played_at timestamptz not null,
+    client text,
+    constraint plays_user_id_fkey foreign key (
+        user_id
+    ) references users (id) on delete cascade,
+    constraint plays_song_id_fkey foreign key (
+        song_id
+    ) references songs (id) on delete cascade
+);
+
+create index plays_user_id_played_at_idx on plays (user_id, played_at);
+create index plays_song_id_idx on plays (song_id);
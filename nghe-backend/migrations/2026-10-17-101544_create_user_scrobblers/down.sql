@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+drop table scrobble_retries;
+drop table user_scrobblers;
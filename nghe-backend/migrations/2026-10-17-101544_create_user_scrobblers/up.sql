@@ -0,0 +1,23 @@
+-- Your SQL goes here
+create table user_scrobblers (
+    user_id uuid not null,
+    service smallint not null,
+    username text not null,
+    token bytea not null,
+    created_at timestamptz not null default now(),
+    constraint user_scrobblers_pkey primary key (user_id, service),
+    constraint user_scrobblers_user_id_fkey foreign key (
+        user_id
+    ) references users (id) on delete cascade
+);
+
+create table scrobble_retries (
+    play_id uuid not null,
+    service smallint not null,
+    attempt integer not null default 0,
+    created_at timestamptz not null default now(),
+    constraint scrobble_retries_pkey primary key (play_id, service),
+    constraint scrobble_retries_play_id_fkey foreign key (
+        play_id
+    ) references plays (id) on delete cascade
+);
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+create table now_playings (
+    user_id uuid not null,
+    client text not null,
+    player_id integer not null generated always as identity,
+    song_id uuid not null,
+    updated_at timestamptz not null default now(),
+    constraint now_playings_pkey primary key (user_id, client),
+    constraint now_playings_user_id_fkey foreign key (
+        user_id
+    ) references users (id) on delete cascade,
+    constraint now_playings_song_id_fkey foreign key (
+        song_id
+    ) references songs (id) on delete cascade
+);
+
+create index now_playings_updated_at_idx on now_playings (updated_at);
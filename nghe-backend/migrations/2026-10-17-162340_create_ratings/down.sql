@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+drop table rating_songs;
+drop table rating_albums;
+drop table rating_artists;
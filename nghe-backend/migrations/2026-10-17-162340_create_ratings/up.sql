@@ -0,0 +1,48 @@
+-- Your SQL goes here
+create table rating_artists (
+    user_id uuid not null,
+    artist_id uuid not null,
+    rating smallint not null,
+    updated_at timestamptz not null default now(),
+    constraint rating_artists_pkey primary key (user_id, artist_id),
+    constraint rating_artists_user_id_fkey foreign key (
+        user_id
+    ) references users (id) on delete cascade,
+    constraint rating_artists_artist_id_fkey foreign key (
+        artist_id
+    ) references artists (id) on delete cascade,
+    constraint rating_artists_rating check (rating between 1 and 5)
+);
+create index rating_artists_artist_id_idx on rating_artists (artist_id);
+
+create table rating_albums (
+    user_id uuid not null,
+    album_id uuid not null,
+    rating smallint not null,
+    updated_at timestamptz not null default now(),
+    constraint rating_albums_pkey primary key (user_id, album_id),
+    constraint rating_albums_user_id_fkey foreign key (
+        user_id
+    ) references users (id) on delete cascade,
+    constraint rating_albums_album_id_fkey foreign key (
+        album_id
+    ) references albums (id) on delete cascade,
+    constraint rating_albums_rating check (rating between 1 and 5)
+);
+create index rating_albums_album_id_idx on rating_albums (album_id);
+
+create table rating_songs (
+    user_id uuid not null,
+    song_id uuid not null,
+    rating smallint not null,
+    updated_at timestamptz not null default now(),
+    constraint rating_songs_pkey primary key (user_id, song_id),
+    constraint rating_songs_user_id_fkey foreign key (
+        user_id
+    ) references users (id) on delete cascade,
+    constraint rating_songs_song_id_fkey foreign key (
+        song_id
+    ) references songs (id) on delete cascade,
+    constraint rating_songs_rating check (rating between 1 and 5)
+);
+create index rating_songs_song_id_idx on rating_songs (song_id);
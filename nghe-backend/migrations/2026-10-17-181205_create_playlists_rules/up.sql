@@ -0,0 +1,25 @@
+-- Your SQL goes here
+create table playlists_rules (
+    playlist_id uuid not null,
+    genres text [] not null default array[]::text [],
+    artists text [] not null default array[]::text [],
+    formats text [] not null default array[]::text [],
+    from_year smallint,
+    to_year smallint,
+    min_bit_depth smallint,
+    max_bit_depth smallint,
+    min_play_count integer,
+    max_play_count integer,
+    played_within_days integer,
+    not_played_within_days integer,
+    starred boolean,
+    min_rating smallint,
+    added_within_days integer,
+    sort smallint not null,
+    descending boolean not null default false,
+    song_limit integer,
+    constraint playlists_rules_pkey primary key (playlist_id),
+    constraint playlists_rules_playlist_id_fkey foreign key (
+        playlist_id
+    ) references playlists (id) on delete cascade
+);
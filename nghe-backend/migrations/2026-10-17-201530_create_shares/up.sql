@@ -0,0 +1,16 @@
+-- Your SQL goes here
+create table shares (
+    id uuid not null default gen_random_uuid() constraint shares_pkey primary key,
+    user_id uuid not null,
+    description text,
+    song_ids uuid [] not null default array[]::uuid [],
+    album_ids uuid [] not null default array[]::uuid [],
+    playlist_ids uuid [] not null default array[]::uuid [],
+    visit_count integer not null default 0,
+    expires_at timestamptz,
+    last_visited_at timestamptz,
+    created_at timestamptz not null default now(),
+    constraint shares_user_id_fkey foreign key (
+        user_id
+    ) references users (id) on delete cascade
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+drop table podcast_episodes;
+
+drop table podcast_channels;
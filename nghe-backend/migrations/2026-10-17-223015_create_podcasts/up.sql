@@ -0,0 +1,59 @@
+-- Your SQL goes here
+create table podcast_channels (
+    id uuid not null default gen_random_uuid() constraint podcast_channels_pkey primary key,
+    url text not null,
+    title text,
+    description text,
+    image_url text,
+    status smallint not null default 1,
+    error_message text,
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now(),
+    constraint podcast_channels_url_key unique (url)
+);
+
+select add_updated_at('podcast_channels');
+
+create table podcast_episodes (
+    id uuid not null default gen_random_uuid() constraint podcast_episodes_pkey primary key,
+    channel_id uuid not null,
+    guid text not null,
+    title text not null,
+    description text,
+    url text not null,
+    published_at timestamptz,
+    status smallint not null default 1,
+    error_message text,
+    path text,
+    duration real,
+    bitrate integer,
+    file_hash bigint,
+    file_size integer,
+    format text,
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now(),
+    constraint podcast_episodes_channel_id_guid_key unique (channel_id, guid),
+    constraint podcast_episodes_channel_id_fkey foreign key (
+        channel_id
+    ) references podcast_channels (id) on delete cascade,
+    constraint podcast_episodes_file_if_path check (
+        (
+            path is null
+            and file_hash is null
+            and file_size is null
+            and format is null
+        )
+        or (
+            path is not null
+            and file_hash is not null
+            and file_size is not null
+            and format is not null
+        )
+    )
+);
+
+select add_updated_at('podcast_episodes');
+
+create index podcast_episodes_published_at_idx on podcast_episodes (
+    published_at desc nulls last
+);
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+create table internet_radio_stations (
+    id uuid not null default gen_random_uuid() constraint internet_radio_stations_pkey primary key,
+    name text not null,
+    stream_url text not null,
+    homepage_url text,
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now()
+);
+
+select add_updated_at('internet_radio_stations');
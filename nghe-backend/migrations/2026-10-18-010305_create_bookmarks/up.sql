@@ -0,0 +1,18 @@
+-- Your SQL goes here
+create table bookmarks (
+    user_id uuid not null,
+    song_id uuid not null,
+    position bigint not null,
+    comment text,
+    created_at timestamptz not null default now(),
+    updated_at timestamptz not null default now(),
+    constraint bookmarks_pkey primary key (user_id, song_id),
+    constraint bookmarks_user_id_fkey foreign key (
+        user_id
+    ) references users (id) on delete cascade,
+    constraint bookmarks_song_id_fkey foreign key (
+        song_id
+    ) references songs (id) on delete cascade
+);
+
+select add_updated_at('bookmarks');
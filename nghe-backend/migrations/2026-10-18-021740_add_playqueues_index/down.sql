@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+alter table playqueues
+drop constraint playqueues_current_index_range,
+add column current uuid;
+
+update playqueues set current = ids[current_index + 1];
+
+alter table playqueues
+drop column current_index,
+drop column changed_by,
+drop column changed_at;
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+alter table playqueues
+add column current_index integer,
+add column changed_by text,
+add column changed_at timestamptz not null default now();
+
+update playqueues set current_index = array_position(ids, current) - 1;
+
+alter table playqueues
+drop column current,
+add constraint playqueues_current_index_range check (
+    current_index >= 0 and current_index < coalesce(array_length(ids, 1), 0)
+);
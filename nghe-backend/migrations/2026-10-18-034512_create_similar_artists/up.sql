@@ -0,0 +1,12 @@
+-- Your SQL goes here
+create table similar_artists (
+    artist_id uuid not null,
+    name text not null,
+    mbz_id uuid,
+    score real not null,
+    created_at timestamptz not null default now(),
+    constraint similar_artists_pkey primary key (artist_id, name),
+    constraint similar_artists_artist_id_fkey foreign key (
+        artist_id
+    ) references artists (id) on delete cascade
+);
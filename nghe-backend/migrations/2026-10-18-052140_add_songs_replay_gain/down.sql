@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+alter table songs
+drop column replay_gain_track_gain,
+drop column replay_gain_track_peak,
+drop column replay_gain_album_gain,
+drop column replay_gain_album_peak;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+alter table songs
+add column replay_gain_track_gain real,
+add column replay_gain_track_peak real,
+add column replay_gain_album_gain real,
+add column replay_gain_album_peak real;
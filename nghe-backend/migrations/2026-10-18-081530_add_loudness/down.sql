@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+alter table music_folders
+drop column scan_full_loudness;
+
+alter table albums
+drop column loudness_integrated,
+drop column loudness_peak;
+
+alter table songs
+drop column loudness_integrated,
+drop column loudness_peak;
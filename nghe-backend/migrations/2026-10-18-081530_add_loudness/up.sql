@@ -0,0 +1,11 @@
+-- Your SQL goes here
+alter table songs
+add column loudness_integrated real,
+add column loudness_peak real;
+
+alter table albums
+add column loudness_integrated real,
+add column loudness_peak real;
+
+alter table music_folders
+add column scan_full_loudness boolean not null default false;
@@ -0,0 +1,24 @@
+-- Your SQL goes here
+create table
+songs_contributors (
+    song_id uuid not null,
+    artist_id uuid not null,
+    role smallint not null,
+    sub_role text not null default '',
+    upserted_at timestamptz not null default now(),
+    constraint songs_contributors_pkey primary key (
+        song_id, artist_id, role, sub_role
+    ),
+    constraint songs_contributors_song_id_fkey foreign key (
+        song_id
+    ) references songs (id) on delete cascade,
+    constraint songs_contributors_artist_id_fkey foreign key (
+        artist_id
+    ) references artists (id) on delete cascade
+);
+
+create index songs_contributors_song_id_idx on songs_contributors (song_id);
+
+create index songs_contributors_artist_id_role_idx on songs_contributors (
+    artist_id, role
+);
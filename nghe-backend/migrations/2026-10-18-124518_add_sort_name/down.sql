@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+alter table songs
+drop column sort_name;
+
+alter table albums
+drop column sort_name;
+
+alter table artists
+drop column sort_name;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+alter table artists
+add column sort_name text;
+
+alter table albums
+add column sort_name text;
+
+alter table songs
+add column sort_name text;
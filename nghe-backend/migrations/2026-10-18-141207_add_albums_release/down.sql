@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+alter table albums
+drop column release_types,
+drop column record_labels,
+drop column catalog_number,
+drop column barcode;
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+alter table albums
+add column release_types text [] not null default array[]::text [] check (
+    array_position(release_types, null) is null
+),
+add column record_labels text [] not null default array[]::text [] check (
+    array_position(record_labels, null) is null
+),
+add column catalog_number text,
+add column barcode text;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+alter table scans
+drop column cancelled;
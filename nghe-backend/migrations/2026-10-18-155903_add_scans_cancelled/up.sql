@@ -0,0 +1,3 @@
+-- Your SQL goes here
+alter table scans
+add column cancelled boolean not null default false;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+alter table songs
+drop column similar_songs_fetched_at;
+
+drop table similar_songs;
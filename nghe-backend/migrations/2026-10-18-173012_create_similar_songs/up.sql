@@ -0,0 +1,15 @@
+-- Your SQL goes here
+create table similar_songs (
+    song_id uuid not null,
+    name text not null,
+    artist_name text not null,
+    score real not null,
+    created_at timestamptz not null default now(),
+    constraint similar_songs_pkey primary key (song_id, name, artist_name),
+    constraint similar_songs_song_id_fkey foreign key (
+        song_id
+    ) references songs (id) on delete cascade
+);
+
+alter table songs
+add column similar_songs_fetched_at timestamptz;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+alter table songs
+drop column loudness_measured_at;
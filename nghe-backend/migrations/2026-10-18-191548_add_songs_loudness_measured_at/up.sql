@@ -0,0 +1,6 @@
+-- Your SQL goes here
+alter table songs
+add column loudness_measured_at timestamptz;
+
+update songs set loudness_measured_at = updated_at
+where loudness_integrated is not null;
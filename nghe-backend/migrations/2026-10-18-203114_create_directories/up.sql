@@ -0,0 +1,12 @@
+-- Your SQL goes here
+create table directories (
+    id uuid not null constraint directories_pkey primary key,
+    music_folder_id uuid not null,
+    parent_id uuid not null,
+    path text not null,
+    constraint directories_music_folder_id_fkey foreign key (
+        music_folder_id
+    ) references music_folders (id) on delete cascade
+);
+
+create index directories_parent_id_idx on directories (parent_id);
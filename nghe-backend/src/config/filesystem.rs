@@ -14,6 +14,18 @@ pub struct Scan {
     pub channel_size: Option<usize>,
     #[educe(Default(expression = 10))]
     pub pool_size: usize,
+    #[educe(Default(expression = false))]
+    pub loudness: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Educe)]
+#[educe(Default)]
+pub struct Watch {
+    #[educe(Default(expression = false))]
+    pub enable: bool,
+    // 2 seconds in milliseconds
+    #[educe(Default(expression = 2000))]
+    pub debounce: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Educe)]
@@ -51,6 +63,7 @@ pub struct S3 {
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Filesystem {
     pub scan: Scan,
+    pub watch: Watch,
     pub tls: Tls,
     pub s3: S3,
 }
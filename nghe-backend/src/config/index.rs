@@ -15,7 +15,7 @@ pub struct Index {
 }
 
 impl Index {
-    fn split(s: &str) -> Vec<String> {
+    pub fn split(s: &str) -> Vec<String> {
         s.split_ascii_whitespace().map(|v| concat_string::concat_string!(v, " ")).collect()
     }
 
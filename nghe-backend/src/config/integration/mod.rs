@@ -24,12 +24,22 @@ pub struct Spotify {
 pub struct Lastfm {
     #[educe(Debug(ignore))]
     pub key: Option<String>,
+    #[educe(Debug(ignore))]
+    pub secret: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Listenbrainz {
+    #[educe(Default(expression = "https://api.listenbrainz.org".into()))]
+    pub url: String,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Integration {
     pub spotify: Spotify,
     pub lastfm: Lastfm,
+    pub listenbrainz: Listenbrainz,
 }
 
 #[cfg(test)]
@@ -56,18 +66,22 @@ mod test {
     impl Lastfm {
         #[cfg(not(lastfm_env))]
         pub fn from_env() -> Self {
-            Self { key: None }
+            Self { key: None, secret: None }
         }
 
         #[cfg(lastfm_env)]
         pub fn from_env() -> Self {
-            Self { key: Some(env!("LASTFM_KEY").to_owned()) }
+            Self { key: Some(env!("LASTFM_KEY").to_owned()), secret: None }
         }
     }
 
     impl Integration {
         pub fn from_env() -> Self {
-            Self { spotify: Spotify::from_env(), lastfm: Lastfm::from_env() }
+            Self {
+                spotify: Spotify::from_env(),
+                lastfm: Lastfm::from_env(),
+                listenbrainz: Listenbrainz::default(),
+            }
         }
     }
 }
@@ -5,7 +5,9 @@ mod index;
 pub mod integration;
 pub mod log;
 pub mod parsing;
+mod podcast;
 mod server;
+mod share;
 mod transcode;
 
 pub use cover_art::CoverArt;
@@ -18,8 +20,10 @@ pub use integration::Integration;
 pub use log::Log;
 use nghe_api::constant;
 pub use parsing::Parsing;
+pub use podcast::Podcast;
 use serde::Deserialize;
 pub use server::Server;
+pub use share::Share;
 pub use transcode::Transcode;
 
 #[derive(Debug, Deserialize)]
@@ -32,6 +36,8 @@ pub struct Config {
     pub transcode: Transcode,
     pub cover_art: CoverArt,
     pub integration: Integration,
+    pub share: Share,
+    pub podcast: Podcast,
     pub log: Log,
 }
 
@@ -46,6 +52,8 @@ impl Default for Config {
             .join(Serialized::default("transcode", Transcode::default()))
             .join(Serialized::default("cover_art", CoverArt::default()))
             .join(Serialized::default("integration", Integration::default()))
+            .join(Serialized::default("share", Share::default()))
+            .join(Serialized::default("podcast", Podcast::default()))
             .join(Serialized::default("log", Log::default()))
             .extract()
             .expect("Could not parse config")
@@ -1,5 +1,7 @@
+use std::borrow::Cow;
 use std::str::FromStr;
 
+use concat_string::concat_string;
 use lofty::id3::v2::FrameId;
 use strum::{EnumDiscriminants, EnumString, IntoStaticStr};
 
@@ -15,15 +17,22 @@ pub enum Id {
     UserText(String),
     #[strum_discriminants(strum(serialize = "TIME"))]
     Time(FrameId<'static>),
+    // Key value frames like `TIPL` or `TMCL`. If a key is specified, only values of that key are
+    // read, otherwise the key of each pair is used as the sub role.
+    #[strum_discriminants(strum(serialize = "LIST"))]
+    KeyValue(FrameId<'static>, Option<String>),
 }
 
 impl Id {
     pub const ID3V24_SEPARATOR: char = '\0';
 
-    fn as_str(&self) -> &str {
+    fn as_str(&self) -> Cow<'_, str> {
         match self {
-            Id::UserText(description) => description,
-            Id::Text(frame_id) | Id::Time(frame_id) => frame_id.as_str(),
+            Id::UserText(description) => description.into(),
+            Id::Text(frame_id) | Id::Time(frame_id) | Id::KeyValue(frame_id, None) => {
+                frame_id.as_str().into()
+            }
+            Id::KeyValue(frame_id, Some(key)) => concat_string!(frame_id.as_str(), ":", key).into(),
         }
     }
 }
@@ -45,13 +54,22 @@ impl FromStr for Id {
             IdDiscriminants::Time => Self::Time(
                 FrameId::new(id).map_err(|_| error::Kind::InvalidId3v2FrameIdConfigFormat)?,
             ),
+            IdDiscriminants::KeyValue => {
+                let (id, key) = match id.split_once(':') {
+                    Some((id, key)) => (id.to_owned(), Some(key.to_owned())),
+                    None => (id, None),
+                };
+                Self::KeyValue(
+                    FrameId::new(id).map_err(|_| error::Kind::InvalidId3v2FrameIdConfigFormat)?,
+                    key,
+                )
+            }
         })
     }
 }
 
 mod serde {
     use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
-    use concat_string::concat_string;
 
     use super::*;
 
@@ -62,7 +80,7 @@ mod serde {
         {
             let variant: IdDiscriminants = self.into();
             let variant: &'static str = variant.into();
-            serializer.serialize_str(&concat_string!(variant, ":", self.as_str()))
+            serializer.serialize_str(&concat_string!(variant, ":", &self.as_str()))
         }
     }
 
@@ -96,6 +114,11 @@ mod tests {
     #[case("TEXT:IDID", Some(Id::Text(FrameId::Valid("IDID".to_owned().into()))))]
     #[case("TXXX:Test description", Some(Id::UserText("Test description".to_owned())))]
     #[case("TIME:IDID", Some(Id::Time(FrameId::Valid("IDID".to_owned().into()))))]
+    #[case("LIST:IDID", Some(Id::KeyValue(FrameId::Valid("IDID".to_owned().into()), None)))]
+    #[case(
+        "LIST:IDID:key value",
+        Some(Id::KeyValue(FrameId::Valid("IDID".to_owned().into()), Some("key value".to_owned())))
+    )]
     #[case("Invalid", None)]
     fn test_deserialize(#[case] input: &str, #[case] id: Option<Id>) {
         assert_eq!(
@@ -108,6 +131,11 @@ mod tests {
     #[case(Id::Text(FrameId::Valid("IDID".to_owned().into())), "TEXT:IDID")]
     #[case(Id::UserText("Test description".to_owned()), "TXXX:Test description")]
     #[case(Id::Time(FrameId::Valid("IDID".to_owned().into())), "TIME:IDID")]
+    #[case(Id::KeyValue(FrameId::Valid("IDID".to_owned().into()), None), "LIST:IDID")]
+    #[case(
+        Id::KeyValue(FrameId::Valid("IDID".to_owned().into()), Some("key value".to_owned())),
+        "LIST:IDID:key value"
+    )]
     fn test_serialize(#[case] id: Id, #[case] result: &str) {
         assert_eq!(
             serde_json::to_string(&Test { id }).unwrap(),
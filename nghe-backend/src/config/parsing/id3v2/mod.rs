@@ -13,12 +13,27 @@ pub struct Common {
     pub release_date: Option<frame::Id>,
     pub original_release_date: Option<frame::Id>,
     pub mbz_id: frame::Id,
+    pub sort_name: frame::Id,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Release {
+    #[educe(Default(expression = "TXXX:MusicBrainz Album Type".parse().unwrap()))]
+    pub release_types: frame::Id,
+    #[educe(Default(expression = "TEXT:TPUB".parse().unwrap()))]
+    pub record_labels: frame::Id,
+    #[educe(Default(expression = "TXXX:CATALOGNUMBER".parse().unwrap()))]
+    pub catalog_number: frame::Id,
+    #[educe(Default(expression = "TXXX:BARCODE".parse().unwrap()))]
+    pub barcode: frame::Id,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Artist {
     pub name: frame::Id,
     pub mbz_id: frame::Id,
+    pub sort_name: frame::Id,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Educe)]
@@ -30,6 +45,23 @@ pub struct Artists {
     pub album: Artist,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Contributors {
+    #[educe(Default(expression = "TEXT:TCOM".parse().unwrap()))]
+    pub composer: frame::Id,
+    #[educe(Default(expression = "TEXT:TPE3".parse().unwrap()))]
+    pub conductor: frame::Id,
+    #[educe(Default(expression = "LIST:TMCL".parse().unwrap()))]
+    pub performer: frame::Id,
+    #[educe(Default(expression = "TEXT:TEXT".parse().unwrap()))]
+    pub lyricist: frame::Id,
+    #[educe(Default(expression = "LIST:TIPL:arranger".parse().unwrap()))]
+    pub arranger: frame::Id,
+    #[educe(Default(expression = "LIST:TIPL:producer".parse().unwrap()))]
+    pub producer: frame::Id,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Educe)]
 #[educe(Default)]
 pub struct TrackDisc {
@@ -39,6 +71,19 @@ pub struct TrackDisc {
     pub disc_position: frame::Id,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct ReplayGain {
+    #[educe(Default(expression = "TXXX:REPLAYGAIN_TRACK_GAIN".parse().unwrap()))]
+    pub track_gain: frame::Id,
+    #[educe(Default(expression = "TXXX:REPLAYGAIN_TRACK_PEAK".parse().unwrap()))]
+    pub track_peak: frame::Id,
+    #[educe(Default(expression = "TXXX:REPLAYGAIN_ALBUM_GAIN".parse().unwrap()))]
+    pub album_gain: frame::Id,
+    #[educe(Default(expression = "TXXX:REPLAYGAIN_ALBUM_PEAK".parse().unwrap()))]
+    pub album_peak: frame::Id,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Educe)]
 #[educe(Default)]
 pub struct Id3v2 {
@@ -46,7 +91,9 @@ pub struct Id3v2 {
     pub song: Common,
     #[educe(Default(expression = Common::default_album()))]
     pub album: Common,
+    pub release: Release,
     pub artists: Artists,
+    pub contributors: Contributors,
     pub track_disc: TrackDisc,
     #[educe(Default(expression = "TEXT:TLAN".parse().unwrap()))]
     pub languages: frame::Id,
@@ -54,6 +101,7 @@ pub struct Id3v2 {
     pub genres: frame::Id,
     #[educe(Default(expression = "TXXX:compilation".parse().unwrap()))]
     pub compilation: frame::Id,
+    pub replay_gain: ReplayGain,
     #[educe(Default(expression = '/'))]
     pub separator: char,
 }
@@ -66,6 +114,7 @@ impl Common {
             release_date: None,
             original_release_date: None,
             mbz_id: "TXXX:MusicBrainz Release Track Id".parse().unwrap(),
+            sort_name: "TEXT:TSOT".parse().unwrap(),
         }
     }
 
@@ -76,6 +125,7 @@ impl Common {
             release_date: Some("TIME:TDRL".parse().unwrap()),
             original_release_date: Some("TIME:TDOR".parse().unwrap()),
             mbz_id: "TXXX:MusicBrainz Album Id".parse().unwrap(),
+            sort_name: "TEXT:TSOA".parse().unwrap(),
         }
     }
 }
@@ -85,6 +135,7 @@ impl Artist {
         Self {
             name: "TEXT:TPE1".parse().unwrap(),
             mbz_id: "TXXX:MusicBrainz Artist Id".parse().unwrap(),
+            sort_name: "TEXT:TSOP".parse().unwrap(),
         }
     }
 
@@ -92,6 +143,7 @@ impl Artist {
         Self {
             name: "TEXT:TPE2".parse().unwrap(),
             mbz_id: "TXXX:MusicBrainz Album Artist Id".parse().unwrap(),
+            sort_name: "TEXT:TSO2".parse().unwrap(),
         }
     }
 }
@@ -0,0 +1,129 @@
+use std::str::FromStr;
+
+use concat_string::concat_string;
+use lofty::mp4::AtomIdent;
+
+use crate::{Error, error};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Id(pub AtomIdent<'static>);
+
+impl Id {
+    const FREEFORM_PREFIX: &'static str = "----:";
+
+    fn to_fourcc(s: &str) -> Option<[u8; 4]> {
+        // Fourcc are stored as latin1 bytes, e.g. `©nam` is `[0xa9, b'n', b'a', b'm']`.
+        s.chars().map(|c| u8::try_from(c).ok()).collect::<Option<Vec<_>>>()?.try_into().ok()
+    }
+}
+
+impl FromStr for Id {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(if let Some(freeform) = s.strip_prefix(Self::FREEFORM_PREFIX) {
+            let (mean, name) = freeform
+                .split_once(':')
+                .ok_or_else(|| error::Kind::InvalidIlstAtomIdConfigFormat)?;
+            AtomIdent::Freeform { mean: mean.to_owned().into(), name: name.to_owned().into() }
+        } else {
+            AtomIdent::Fourcc(
+                Self::to_fourcc(s).ok_or_else(|| error::Kind::InvalidIlstAtomIdConfigFormat)?,
+            )
+        }))
+    }
+}
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            AtomIdent::Fourcc(fourcc) => {
+                f.write_str(&fourcc.iter().copied().map(char::from).collect::<String>())
+            }
+            AtomIdent::Freeform { mean, name } => {
+                f.write_str(&concat_string!(Self::FREEFORM_PREFIX, mean, ":", name))
+            }
+        }
+    }
+}
+
+mod serde {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+    use super::*;
+
+    impl Serialize for Id {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Id {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            <String>::deserialize(deserializer)?
+                .parse()
+                .map_err(|error: Error| de::Error::custom(error.source))
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use nghe_proc_macro::api_derive;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn fourcc(fourcc: [u8; 4]) -> Id {
+        Id(AtomIdent::Fourcc(fourcc))
+    }
+
+    fn freeform(mean: &'static str, name: &'static str) -> Id {
+        Id(AtomIdent::Freeform { mean: mean.into(), name: name.into() })
+    }
+
+    #[api_derive]
+    #[derive(PartialEq)]
+    struct Test {
+        pub id: Id,
+    }
+
+    #[rstest]
+    #[case("©nam", Some(fourcc(*b"\xa9nam")))]
+    #[case("aART", Some(fourcc(*b"aART")))]
+    #[case(
+        "----:com.apple.iTunes:MusicBrainz Album Id",
+        Some(freeform("com.apple.iTunes", "MusicBrainz Album Id"))
+    )]
+    #[case("----:Invalid", None)]
+    #[case("Invalid", None)]
+    #[case("日本語x", None)]
+    fn test_deserialize(#[case] input: &str, #[case] id: Option<Id>) {
+        assert_eq!(
+            serde_json::from_value(serde_json::json!({"id": input})).ok(),
+            id.map(|id| Test { id })
+        );
+    }
+
+    #[rstest]
+    #[case(fourcc(*b"\xa9nam"), "©nam")]
+    #[case(fourcc(*b"aART"), "aART")]
+    #[case(
+        freeform("com.apple.iTunes", "MusicBrainz Album Id"),
+        "----:com.apple.iTunes:MusicBrainz Album Id"
+    )]
+    fn test_serialize(#[case] id: Id, #[case] result: &str) {
+        assert_eq!(
+            serde_json::to_string(&Test { id }).unwrap(),
+            serde_json::to_string(&serde_json::json!({"id": result})).unwrap()
+        );
+    }
+}
@@ -0,0 +1,168 @@
+pub mod atom;
+
+use educe::Educe;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Common {
+    pub name: atom::Id,
+    pub date: Option<atom::Id>,
+    pub release_date: Option<atom::Id>,
+    pub original_release_date: Option<atom::Id>,
+    pub mbz_id: atom::Id,
+    pub sort_name: atom::Id,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Release {
+    #[educe(Default(expression = "----:com.apple.iTunes:MusicBrainz Album Type".parse().unwrap()))]
+    pub release_types: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:LABEL".parse().unwrap()))]
+    pub record_labels: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:CATALOGNUMBER".parse().unwrap()))]
+    pub catalog_number: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:BARCODE".parse().unwrap()))]
+    pub barcode: atom::Id,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Artist {
+    pub name: atom::Id,
+    pub mbz_id: atom::Id,
+    pub sort_name: atom::Id,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Artists {
+    #[educe(Default(expression = Artist::default_song()))]
+    pub song: Artist,
+    #[educe(Default(expression = Artist::default_album()))]
+    pub album: Artist,
+}
+
+// Performer values can carry an instrument as `Name (instrument)`.
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Contributors {
+    #[educe(Default(expression = "©wrt".parse().unwrap()))]
+    pub composer: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:CONDUCTOR".parse().unwrap()))]
+    pub conductor: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:PERFORMER".parse().unwrap()))]
+    pub performer: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:LYRICIST".parse().unwrap()))]
+    pub lyricist: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:ARRANGER".parse().unwrap()))]
+    pub arranger: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:PRODUCER".parse().unwrap()))]
+    pub producer: atom::Id,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Lyric {
+    #[educe(Default(expression = "©lyr".parse().unwrap()))]
+    pub unsync: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:SYNCEDLYRICS".parse().unwrap()))]
+    pub sync: atom::Id,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct ReplayGain {
+    #[educe(Default(expression = "----:com.apple.iTunes:REPLAYGAIN_TRACK_GAIN".parse().unwrap()))]
+    pub track_gain: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:REPLAYGAIN_TRACK_PEAK".parse().unwrap()))]
+    pub track_peak: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:REPLAYGAIN_ALBUM_GAIN".parse().unwrap()))]
+    pub album_gain: atom::Id,
+    #[educe(Default(expression = "----:com.apple.iTunes:REPLAYGAIN_ALBUM_PEAK".parse().unwrap()))]
+    pub album_peak: atom::Id,
+}
+
+// Track and disc positions are always read from the binary `trkn` and `disk` atoms.
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Ilst {
+    #[educe(Default(expression = Common::default_song()))]
+    pub song: Common,
+    #[educe(Default(expression = Common::default_album()))]
+    pub album: Common,
+    pub release: Release,
+    pub artists: Artists,
+    pub contributors: Contributors,
+    #[educe(Default(expression = "----:com.apple.iTunes:LANGUAGE".parse().unwrap()))]
+    pub languages: atom::Id,
+    #[educe(Default(expression = "©gen".parse().unwrap()))]
+    pub genres: atom::Id,
+    #[educe(Default(expression = "cpil".parse().unwrap()))]
+    pub compilation: atom::Id,
+    pub lyric: Lyric,
+    pub replay_gain: ReplayGain,
+}
+
+impl Common {
+    fn default_song() -> Self {
+        Self {
+            name: "©nam".parse().unwrap(),
+            date: None,
+            release_date: None,
+            original_release_date: None,
+            mbz_id: "----:com.apple.iTunes:MusicBrainz Release Track Id".parse().unwrap(),
+            sort_name: "sonm".parse().unwrap(),
+        }
+    }
+
+    fn default_album() -> Self {
+        Self {
+            name: "©alb".parse().unwrap(),
+            date: Some("©day".parse().unwrap()),
+            release_date: Some("----:com.apple.iTunes:RELEASEDATE".parse().unwrap()),
+            original_release_date: Some("----:com.apple.iTunes:ORIGINALDATE".parse().unwrap()),
+            mbz_id: "----:com.apple.iTunes:MusicBrainz Album Id".parse().unwrap(),
+            sort_name: "soal".parse().unwrap(),
+        }
+    }
+}
+
+impl Artist {
+    fn default_song() -> Self {
+        Self {
+            name: "©ART".parse().unwrap(),
+            mbz_id: "----:com.apple.iTunes:MusicBrainz Artist Id".parse().unwrap(),
+            sort_name: "soar".parse().unwrap(),
+        }
+    }
+
+    fn default_album() -> Self {
+        Self {
+            name: "aART".parse().unwrap(),
+            mbz_id: "----:com.apple.iTunes:MusicBrainz Album Artist Id".parse().unwrap(),
+            sort_name: "soaa".parse().unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod test {
+    use super::*;
+
+    impl Ilst {
+        pub fn test() -> Self {
+            Self {
+                song: Common {
+                    date: Some("----:com.apple.iTunes:SDATE".parse().unwrap()),
+                    release_date: Some("----:com.apple.iTunes:SRELEASEDATE".parse().unwrap()),
+                    original_release_date: Some(
+                        "----:com.apple.iTunes:SORIGINALDATE".parse().unwrap(),
+                    ),
+                    ..Common::default_song()
+                },
+                ..Self::default()
+            }
+        }
+    }
+}
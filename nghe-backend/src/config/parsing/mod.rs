@@ -1,7 +1,9 @@
 pub mod id3v2;
+pub mod ilst;
 pub mod vorbis_comments;
 
 use id3v2::Id3v2;
+use ilst::Ilst;
 use serde::{Deserialize, Serialize};
 use vorbis_comments::VorbisComments;
 
@@ -9,6 +11,7 @@ use vorbis_comments::VorbisComments;
 pub struct Parsing {
     pub vorbis_comments: VorbisComments,
     pub id3v2: Id3v2,
+    pub ilst: Ilst,
 }
 
 #[cfg(test)]
@@ -18,7 +21,11 @@ mod test {
 
     impl Parsing {
         pub fn test() -> Self {
-            Self { vorbis_comments: VorbisComments::test(), id3v2: Id3v2::test() }
+            Self {
+                vorbis_comments: VorbisComments::test(),
+                id3v2: Id3v2::test(),
+                ilst: Ilst::test(),
+            }
         }
     }
 }
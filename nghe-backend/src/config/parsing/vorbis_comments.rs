@@ -13,12 +13,27 @@ pub struct Common {
     #[serde_as(as = "serde_with::NoneAsEmptyString")]
     pub original_release_date: Option<String>,
     pub mbz_id: String,
+    pub sort_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Release {
+    #[educe(Default(expression = "RELEASETYPE".into()))]
+    pub release_types: String,
+    #[educe(Default(expression = "LABEL".into()))]
+    pub record_labels: String,
+    #[educe(Default(expression = "CATALOGNUMBER".into()))]
+    pub catalog_number: String,
+    #[educe(Default(expression = "BARCODE".into()))]
+    pub barcode: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Artist {
     pub name: String,
     pub mbz_id: String,
+    pub sort_name: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Educe)]
@@ -30,6 +45,24 @@ pub struct Artists {
     pub album: Artist,
 }
 
+// Performer values can carry an instrument as `Name (instrument)`.
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Contributors {
+    #[educe(Default(expression = "COMPOSER".into()))]
+    pub composer: String,
+    #[educe(Default(expression = "CONDUCTOR".into()))]
+    pub conductor: String,
+    #[educe(Default(expression = "PERFORMER".into()))]
+    pub performer: String,
+    #[educe(Default(expression = "LYRICIST".into()))]
+    pub lyricist: String,
+    #[educe(Default(expression = "ARRANGER".into()))]
+    pub arranger: String,
+    #[educe(Default(expression = "PRODUCER".into()))]
+    pub producer: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Educe)]
 #[educe(Default)]
 pub struct TrackDisc {
@@ -52,6 +85,19 @@ pub struct Lyric {
     pub sync: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct ReplayGain {
+    #[educe(Default(expression = "REPLAYGAIN_TRACK_GAIN".into()))]
+    pub track_gain: String,
+    #[educe(Default(expression = "REPLAYGAIN_TRACK_PEAK".into()))]
+    pub track_peak: String,
+    #[educe(Default(expression = "REPLAYGAIN_ALBUM_GAIN".into()))]
+    pub album_gain: String,
+    #[educe(Default(expression = "REPLAYGAIN_ALBUM_PEAK".into()))]
+    pub album_peak: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Educe)]
 #[educe(Default)]
 pub struct VorbisComments {
@@ -59,7 +105,9 @@ pub struct VorbisComments {
     pub song: Common,
     #[educe(Default(expression = Common::default_album()))]
     pub album: Common,
+    pub release: Release,
     pub artists: Artists,
+    pub contributors: Contributors,
     pub track_disc: TrackDisc,
     #[educe(Default(expression = "LANGUAGE".into()))]
     pub languages: String,
@@ -68,6 +116,7 @@ pub struct VorbisComments {
     #[educe(Default(expression = "COMPILATION".into()))]
     pub compilation: String,
     pub lyric: Lyric,
+    pub replay_gain: ReplayGain,
 }
 
 impl Common {
@@ -78,6 +127,7 @@ impl Common {
             release_date: None,
             original_release_date: None,
             mbz_id: "MUSICBRAINZ_RELEASETRACKID".into(),
+            sort_name: "TITLESORT".into(),
         }
     }
 
@@ -88,17 +138,26 @@ impl Common {
             release_date: Some("RELEASEDATE".into()),
             original_release_date: Some("ORIGYEAR".into()),
             mbz_id: "MUSICBRAINZ_ALBUMID".into(),
+            sort_name: "ALBUMSORT".into(),
         }
     }
 }
 
 impl Artist {
     fn default_song() -> Self {
-        Self { name: "ARTIST".into(), mbz_id: "MUSICBRAINZ_ARTISTID".into() }
+        Self {
+            name: "ARTIST".into(),
+            mbz_id: "MUSICBRAINZ_ARTISTID".into(),
+            sort_name: "ARTISTSORT".into(),
+        }
     }
 
     fn default_album() -> Self {
-        Self { name: "ALBUMARTIST".into(), mbz_id: "MUSICBRAINZ_ALBUMARTISTID".into() }
+        Self {
+            name: "ALBUMARTIST".into(),
+            mbz_id: "MUSICBRAINZ_ALBUMARTISTID".into(),
+            sort_name: "ALBUMARTISTSORT".into(),
+        }
     }
 }
 
@@ -0,0 +1,43 @@
+use educe::Educe;
+use serde::{Deserialize, Serialize};
+use typed_path::Utf8PlatformPathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Educe)]
+#[educe(Default)]
+pub struct Podcast {
+    #[serde(with = "crate::filesystem::path::serde::option")]
+    #[educe(Default(expression = Some(data_dir().join("nghe").join("podcast"))))]
+    pub dir: Option<Utf8PlatformPathBuf>,
+    // 1 hour in seconds, 0 to disable
+    #[educe(Default(expression = 3600))]
+    pub refresh_interval: u64,
+}
+
+// Downloaded episodes are not a cache so they are kept in the data directory instead of the
+// temporary one.
+fn data_dir() -> Utf8PlatformPathBuf {
+    std::env::var("XDG_DATA_HOME")
+        .ok()
+        .filter(|dir| !dir.is_empty())
+        .map(Utf8PlatformPathBuf::from)
+        .or_else(|| {
+            std::env::home_dir()
+                .and_then(|dir| dir.into_os_string().into_string().ok())
+                .map(|dir| Utf8PlatformPathBuf::from(dir).join(".local").join("share"))
+        })
+        .unwrap_or_else(|| Utf8PlatformPathBuf::from("data"))
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod test {
+    use typed_path::Utf8PlatformPath;
+
+    use super::*;
+
+    impl Podcast {
+        pub fn with_prefix(self, prefix: impl AsRef<Utf8PlatformPath>) -> Self {
+            Self { dir: self.dir.map(|_| prefix.as_ref().join("podcast")), ..self }
+        }
+    }
+}
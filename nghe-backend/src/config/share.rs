@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Share {
+    // Public address of this server, e.g. `https://music.example.com`. Share links are relative
+    // to the current host when it is missing.
+    pub base_url: Option<String>,
+}
@@ -68,6 +68,22 @@ pub enum Kind {
     #[into(StatusCode| StatusCode::BAD_REQUEST)]
     #[into(OpensubsonicCode| OpensubsonicCode::RequiredParameterIsMissing)]
     InvalidScrobbleTimeSize,
+    #[error("Rating {0} is not between 0 and 5")]
+    #[into(StatusCode| StatusCode::BAD_REQUEST)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    InvalidRating(u8),
+    #[error("Songs of a smart playlist can not be edited")]
+    #[into(StatusCode| StatusCode::BAD_REQUEST)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    SmartPlaylistNotEditable,
+    #[error("Could not parse playlist content")]
+    #[into(StatusCode| StatusCode::BAD_REQUEST)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    InvalidPlaylistContent,
+    #[error("Play queue index {0} is out of range")]
+    #[into(StatusCode| StatusCode::BAD_REQUEST)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    InvalidPlayqueueIndex(u32),
 
     // Database error
     #[error("Could not decrypt database value")]
@@ -127,6 +143,10 @@ pub enum Kind {
     #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
     #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
     MissingId3V2Tag(audio::Format),
+    #[error("Could not found ilst tag in format {0}")]
+    #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    MissingIlstTag(audio::Format),
 
     #[error("Missing media name")]
     #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
@@ -178,6 +198,10 @@ pub enum Kind {
     #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
     #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
     InvalidId3v2FrameIdConfigType,
+    #[error("Invalid ilst atom id config format")]
+    #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    InvalidIlstAtomIdConfigFormat,
 
     // Image error
     #[error("Missing image format")]
@@ -211,6 +235,20 @@ pub enum Kind {
     #[into(StatusCode| StatusCode::BAD_REQUEST)]
     #[into(OpensubsonicCode| OpensubsonicCode::RequiredParameterIsMissing)]
     BuildLastFMRequestURLFailed,
+    #[error("Missing LastFM shared secret")]
+    #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    LastFMSecretMissing,
+
+    // Scrobbler error
+    #[error("Scrobbling to {0:?} is not enabled")]
+    #[into(StatusCode| StatusCode::BAD_REQUEST)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    ScrobblerNotEnabled(crate::orm::user_scrobblers::Service),
+    #[error("Invalid {0:?} token")]
+    #[into(StatusCode| StatusCode::BAD_REQUEST)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    InvalidScrobblerToken(crate::orm::user_scrobblers::Service),
 
     // Transcode error
     #[error("No audio track found in media")]
@@ -233,6 +271,48 @@ pub enum Kind {
     #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
     #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
     MissingSampleFmtName(i32),
+    #[error("Missing loudness measurement")]
+    #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    MissingLoudnessMeasurement,
+
+    // Internet radio error
+    #[error("Invalid internet radio url {0}, only http and https are supported")]
+    #[into(StatusCode| StatusCode::BAD_REQUEST)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    InvalidInternetRadioUrl(String),
+
+    // Podcast error
+    #[error("Could not parse podcast feed")]
+    #[into(StatusCode| StatusCode::BAD_REQUEST)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    InvalidPodcastFeed,
+    #[error("Unsupported podcast episode format {0}")]
+    #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    UnsupportedPodcastEpisodeFormat(String),
+    #[error("Missing podcast directory config")]
+    #[into(StatusCode| StatusCode::NOT_FOUND)]
+    #[into(OpensubsonicCode| OpensubsonicCode::TheRequestedDataWasNotFound)]
+    MissingPodcastDirectoryConfig,
+
+    // Scan error
+    #[error("Music folder {0} is already being scanned")]
+    #[into(StatusCode| StatusCode::CONFLICT)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    ScanAlreadyRunning(uuid::Uuid),
+    #[error("Music folder {0} is not being scanned")]
+    #[into(StatusCode| StatusCode::NOT_FOUND)]
+    #[into(OpensubsonicCode| OpensubsonicCode::TheRequestedDataWasNotFound)]
+    ScanNotRunning(uuid::Uuid),
+    #[error("Scan is cancelled")]
+    #[into(StatusCode| StatusCode::INTERNAL_SERVER_ERROR)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    ScanCancelled,
+    #[error("Invalid scan schedule: {0}")]
+    #[into(StatusCode| StatusCode::BAD_REQUEST)]
+    #[into(OpensubsonicCode| OpensubsonicCode::AGenericError)]
+    InvalidScanSchedule(String),
 
     // Various error
     #[error("Invalid index ignore prefixes format")]
@@ -253,6 +333,7 @@ pub enum Kind {
 )]
 #[from_owned(std::string::FromUtf8Error)]
 #[from_owned(std::num::TryFromIntError)]
+#[from_owned(std::num::ParseFloatError)]
 #[from_owned(time::error::ComponentRange)]
 #[from_owned(time::error::ConversionRange)]
 #[from_owned(time::error::Parse)]
@@ -266,6 +347,7 @@ pub enum Kind {
 #[from_owned(std::str::Utf8Error)]
 #[from_owned(tracing_subscriber::util::TryInitError)]
 #[from_owned(image::ImageError)]
+#[from_owned(notify_debouncer_full::notify::Error)]
 pub struct Error {
     pub status_code: StatusCode,
     pub opensubsonic_code: OpensubsonicCode,
@@ -400,6 +482,8 @@ mod s3 {
 
 impl IntoResponse for Error {
     fn into_response(self) -> Response {
-        (self.status_code, self.source.to_string()).into_response()
+        let mut response = (self.status_code, self.source.to_string()).into_response();
+        response.extensions_mut().insert(self.opensubsonic_code);
+        response
     }
 }
@@ -15,7 +15,7 @@ use uuid::Uuid;
 
 use crate::database::Database;
 use crate::orm::upsert::Insert as _;
-use crate::orm::{artists, songs_album_artists, songs_artists};
+use crate::orm::{artists, songs_album_artists, songs_artists, songs_contributors};
 use crate::{Error, error};
 
 #[derive(Debug, PartialEq, Eq, Hash, o2o)]
@@ -27,14 +27,17 @@ pub struct Artist<'a> {
     #[cfg_attr(test, dummy(expr = "Faker.fake::<String>().into()"))]
     pub name: Cow<'a, str>,
     pub mbz_id: Option<Uuid>,
+    #[ref_into(~.as_deref().map(Cow::Borrowed))]
+    #[cfg_attr(test, dummy(expr = "Faker.fake::<Option<String>>().map(Cow::Owned)"))]
+    pub sort_name: Option<Cow<'a, str>>,
 }
 
 #[derive(Debug)]
 #[cfg_attr(test, derive(Dummy, Eq, Clone))]
 pub struct Artists<'a> {
-    #[cfg_attr(test, dummy(expr = "fake::vec![Artist; 1..5].into_iter().collect()"))]
+    #[cfg_attr(test, dummy(expr = "Artist::fake_set(1..5)"))]
     pub song: IndexSet<Artist<'a>>,
-    #[cfg_attr(test, dummy(expr = "fake::vec![Artist; 0..3].into_iter().collect()"))]
+    #[cfg_attr(test, dummy(expr = "Artist::fake_set(0..3)"))]
     pub album: IndexSet<Artist<'a>>,
     pub compilation: bool,
 }
@@ -43,34 +46,54 @@ impl<'a> Artist<'a> {
     pub fn try_collect(
         names: impl Iterator<Item = &'a str>,
         mbz_ids: impl Iterator<Item = &'a str>,
+        sort_names: impl Iterator<Item = &'a str>,
     ) -> Result<IndexSet<Self>, Error> {
+        // Sort names are matched with names by position and extra sort names are ignored.
+        let mut sort_names = sort_names.fuse();
         names
             .zip_longest(mbz_ids)
-            .map(|iter| match iter {
-                itertools::EitherOrBoth::Both(name, mbz_id) => Ok(Self {
-                    name: name.into(),
-                    mbz_id: {
-                        let mbz_id = Uuid::from_str(mbz_id)
-                            .map_err(|_| error::Kind::InvalidMbzIdTagFormat(mbz_id.to_owned()))?;
-                        if mbz_id.is_nil() { None } else { Some(mbz_id) }
-                    },
-                }),
-                itertools::EitherOrBoth::Left(name) => Ok(Self { name: name.into(), mbz_id: None }),
-                itertools::EitherOrBoth::Right(_) => error::Kind::InvalidMbzIdSize.into(),
+            .map(|iter| {
+                let sort_name = sort_names.next().filter(|s| !s.is_empty()).map(Cow::Borrowed);
+                match iter {
+                    itertools::EitherOrBoth::Both(name, mbz_id) => Ok(Self {
+                        name: name.into(),
+                        mbz_id: {
+                            let mbz_id = Uuid::from_str(mbz_id).map_err(|_| {
+                                error::Kind::InvalidMbzIdTagFormat(mbz_id.to_owned())
+                            })?;
+                            if mbz_id.is_nil() { None } else { Some(mbz_id) }
+                        },
+                        sort_name,
+                    }),
+                    itertools::EitherOrBoth::Left(name) => {
+                        Ok(Self { name: name.into(), mbz_id: None, sort_name })
+                    }
+                    itertools::EitherOrBoth::Right(_) => error::Kind::InvalidMbzIdSize.into(),
+                }
             })
             .try_collect()
     }
 
     pub fn index(&self, prefixes: &[impl AsRef<str>]) -> Result<char, Error> {
+        // A sort name is already written in its sorting form, e.g. `Beatles, The`, so the
+        // prefix heuristic is only used as a fallback.
+        if let Some(ref sort_name) = self.sort_name {
+            Self::name_index(sort_name, &[""; 0])
+        } else {
+            Self::name_index(&self.name, prefixes)
+        }
+    }
+
+    pub fn name_index(name: &str, prefixes: &[impl AsRef<str>]) -> Result<char, Error> {
         let mut iter = prefixes.iter();
         let name = loop {
             match iter.next() {
                 Some(prefix) => {
-                    if let Some(name) = self.name.strip_prefix(prefix.as_ref()) {
+                    if let Some(name) = name.strip_prefix(prefix.as_ref()) {
                         break name;
                     }
                 }
-                None => break self.name.as_ref(),
+                None => break name,
             }
         };
         name.nfkd().next().ok_or_else(|| error::Kind::InvalidArtistNameFormat.into()).map(|c| {
@@ -91,9 +114,19 @@ impl<'a> Artist<'a> {
         database: &Database,
         prefixes: &[impl AsRef<str>],
     ) -> Result<Uuid, Error> {
-        artists::Upsert { index: self.index(prefixes)?.to_string().into(), data: self.into() }
-            .insert(database)
-            .await
+        self.to_upsert(prefixes)?.insert(database).await
+    }
+
+    pub async fn upsert_contributor(
+        &self,
+        database: &Database,
+        prefixes: &[impl AsRef<str>],
+    ) -> Result<Uuid, Error> {
+        self.to_upsert(prefixes)?.insert_contributor(database).await
+    }
+
+    fn to_upsert(&self, prefixes: &[impl AsRef<str>]) -> Result<artists::Upsert<'_>, Error> {
+        Ok(artists::Upsert { index: self.index(prefixes)?.to_string().into(), data: self.into() })
     }
 
     async fn upserts<S: Borrow<Self> + 'a>(
@@ -233,9 +266,9 @@ impl<'a> Artists<'a> {
         Ok(())
     }
 
-    pub async fn cleanup(database: &Database) -> Result<(), Error> {
+    pub async fn cleanup(database: &Database) -> Result<usize, Error> {
         // Delete all artists which does not have any relation with an album
-        // (via songs_album_artists) or a song (via songs_artists).
+        // (via songs_album_artists) or a song (via songs_artists or songs_contributors).
         let alias_artists = diesel::alias!(artists as alias_artists);
         diesel::delete(artists::table)
             .filter(
@@ -250,12 +283,15 @@ impl<'a> Artists<'a> {
                         .filter(not(exists(songs_artists::table.filter(
                             songs_artists::artist_id.eq(alias_artists.field(artists::id)),
                         ))))
+                        .filter(not(exists(songs_contributors::table.filter(
+                            songs_contributors::artist_id.eq(alias_artists.field(artists::id)),
+                        ))))
                         .select(alias_artists.field(artists::id)),
                 ),
             )
             .execute(&mut database.get().await?)
-            .await?;
-        Ok(())
+            .await
+            .map_err(Error::from)
     }
 }
 
@@ -285,19 +321,19 @@ mod test {
 
     impl<'a> From<&'a str> for Artist<'a> {
         fn from(value: &'a str) -> Self {
-            Self { name: value.into(), mbz_id: None }
+            Self { name: value.into(), mbz_id: None, sort_name: None }
         }
     }
 
     impl From<String> for Artist<'static> {
         fn from(value: String) -> Self {
-            Self { name: value.into(), mbz_id: None }
+            Self { name: value.into(), mbz_id: None, sort_name: None }
         }
     }
 
     impl<'a> From<(&'a str, Uuid)> for Artist<'a> {
         fn from(value: (&'a str, Uuid)) -> Self {
-            Self { name: value.0.into(), mbz_id: Some(value.1) }
+            Self { name: value.0.into(), mbz_id: Some(value.1), sort_name: None }
         }
     }
 
@@ -308,6 +344,19 @@ mod test {
     }
 
     impl Artist<'static> {
+        pub fn fake_set(len: std::ops::Range<usize>) -> IndexSet<Self> {
+            // Tags can not hold an empty sort name in the middle of a list, so either all or none
+            // of the artists have a sort name.
+            let sort_name: bool = Faker.fake();
+            fake::vec![Self; len]
+                .into_iter()
+                .map(|artist| Self {
+                    sort_name: if sort_name { Some(Faker.fake::<String>().into()) } else { None },
+                    ..artist
+                })
+                .collect()
+        }
+
         pub async fn query(mock: &Mock, id: Uuid) -> Self {
             artists::table
                 .filter(artists::id.eq(id))
@@ -417,16 +466,26 @@ mod tests {
     use crate::test::{Mock, mock};
 
     #[rstest]
-    #[case("The One", &["The ", "A "], 'O')]
-    #[case("The 1", &["The ", "A "], '#')]
-    #[case("The one", &["The ", "A "], 'O')]
-    #[case("狼", &["The ", "A "], '狼')]
-    #[case("é", &["The ", "A "], 'E')]
-    #[case("ド", &["The ", "A "], 'ト')]
-    #[case("ａ", &["The ", "A "], 'A')]
-    #[case("%", &["The ", "A "], '*')]
-    fn test_index(#[case] name: &str, #[case] prefixes: &[&str], #[case] index: char) {
-        assert_eq!(Artist::from(name).index(prefixes).unwrap(), index);
+    #[case("The One", None, &["The ", "A "], 'O')]
+    #[case("The 1", None, &["The ", "A "], '#')]
+    #[case("The one", None, &["The ", "A "], 'O')]
+    #[case("狼", None, &["The ", "A "], '狼')]
+    #[case("é", None, &["The ", "A "], 'E')]
+    #[case("ド", None, &["The ", "A "], 'ト')]
+    #[case("ａ", None, &["The ", "A "], 'A')]
+    #[case("%", None, &["The ", "A "], '*')]
+    #[case("The Beatles", Some("Beatles, The"), &["The ", "A "], 'B')]
+    #[case("The The", Some("The The"), &["The ", "A "], 'T')]
+    #[case("Bob Dylan", Some("Dylan, Bob"), &["The ", "A "], 'D')]
+    #[case("坂本龍一", Some("さかもとりゅういち"), &["The ", "A "], 'さ')]
+    fn test_index(
+        #[case] name: &str,
+        #[case] sort_name: Option<&str>,
+        #[case] prefixes: &[&str],
+        #[case] index: char,
+    ) {
+        let artist = Artist { sort_name: sort_name.map(Cow::Borrowed), ..Artist::from(name) };
+        assert_eq!(artist.index(prefixes).unwrap(), index);
     }
 
     #[rstest]
@@ -479,7 +538,9 @@ mod tests {
             let database_update_artist = Artist::query(&mock, id).await;
             if mbz_id.is_some() {
                 assert_eq!(id, update_id);
-                assert_eq!(database_update_artist, update_artist);
+                // A missing sort name does not remove the existing one.
+                let sort_name = update_artist.sort_name.clone().or(artist.sort_name);
+                assert_eq!(database_update_artist, Artist { sort_name, ..update_artist });
             } else {
                 // This will always insert a new row to the database
                 // since there is nothing to identify an old artist.
@@ -499,6 +560,45 @@ mod tests {
         assert_eq!(update_id, id);
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_artist_upsert_sort_name(
+        #[future(awt)] mock: Mock,
+        #[values(true, false)] mbz_id: bool,
+    ) {
+        let mbz_id = if mbz_id { Some(Faker.fake()) } else { None };
+        let query = async |id: Uuid| {
+            artists::table
+                .filter(artists::id.eq(id))
+                .select((artists::index, artists::sort_name))
+                .get_result::<(String, Option<String>)>(&mut mock.get().await)
+                .await
+                .unwrap()
+        };
+
+        let artist = Artist { name: "Bob Dylan".into(), mbz_id, sort_name: None };
+        let id = artist.upsert_mock(&mock).await;
+        assert_eq!(query(id).await, ("B".to_owned(), None));
+
+        let artist = Artist { sort_name: Some("Dylan, Bob".into()), ..artist };
+        assert_eq!(artist.upsert_mock(&mock).await, id);
+        assert_eq!(query(id).await, ("D".to_owned(), Some("Dylan, Bob".to_owned())));
+
+        // A contributor without sort name keeps the existing sort name.
+        let artist = Artist { sort_name: None, ..artist };
+        let prefixes = &mock.config.index.ignore_prefixes;
+        assert_eq!(artist.upsert_contributor(mock.database(), prefixes).await.unwrap(), id);
+        assert_eq!(query(id).await, ("D".to_owned(), Some("Dylan, Bob".to_owned())));
+
+        // While a song or album artist without sort name removes it.
+        assert_eq!(artist.upsert_mock(&mock).await, id);
+        assert_eq!(query(id).await, ("B".to_owned(), None));
+
+        let artist = Artist { sort_name: Some("Zimmerman, Robert".into()), ..artist };
+        assert_eq!(artist.upsert_mock(&mock).await, id);
+        assert_eq!(query(id).await, ("Z".to_owned(), Some("Zimmerman, Robert".to_owned())));
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_artists_upsert(
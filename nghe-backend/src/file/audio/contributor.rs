@@ -0,0 +1,227 @@
+use std::borrow::Cow;
+
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+#[cfg(test)]
+use fake::{Dummy, Fake, Faker};
+use indexmap::IndexSet;
+use uuid::Uuid;
+
+use super::Artist;
+use crate::Error;
+use crate::database::Database;
+use crate::orm::songs_contributors;
+pub use crate::orm::songs_contributors::Role;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(test, derive(Clone))]
+pub struct Contributor<'a> {
+    pub role: Role,
+    pub sub_role: Option<Cow<'a, str>>,
+    pub artist: Artist<'a>,
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(test, derive(PartialEq, Eq, Dummy, Clone))]
+pub struct Contributors<'a> {
+    #[cfg_attr(test, dummy(expr = "fake::vec![Contributor; 0..4].into_iter().collect()"))]
+    pub value: IndexSet<Contributor<'a>>,
+}
+
+impl<'a> Contributor<'a> {
+    pub fn new(role: Role, name: &'a str, sub_role: Option<&'a str>) -> Option<Self> {
+        let name = name.trim();
+        if name.is_empty() {
+            None
+        } else {
+            Some(Self {
+                role,
+                sub_role: sub_role.map(str::trim).filter(|s| !s.is_empty()).map(Cow::Borrowed),
+                artist: Artist { name: name.into(), mbz_id: None, sort_name: None },
+            })
+        }
+    }
+
+    // Performers are usually written as `Name (instrument)` inside plain text tags.
+    pub fn parse(role: Role, value: &'a str) -> Option<Self> {
+        if role == Role::Performer
+            && let Some(value) = value.strip_suffix(')')
+            && let Some((name, sub_role)) = value.rsplit_once(" (")
+        {
+            Self::new(role, name, Some(sub_role))
+        } else {
+            Self::new(role, value, None)
+        }
+    }
+}
+
+impl<'a> FromIterator<Contributor<'a>> for Contributors<'a> {
+    fn from_iter<T: IntoIterator<Item = Contributor<'a>>>(iter: T) -> Self {
+        Self { value: iter.into_iter().collect() }
+    }
+}
+
+impl Contributors<'_> {
+    pub async fn upsert(
+        &self,
+        database: &Database,
+        prefixes: &[impl AsRef<str>],
+        song_id: Uuid,
+    ) -> Result<(), Error> {
+        for contributor in &self.value {
+            let artist_id = contributor.artist.upsert_contributor(database, prefixes).await?;
+            // Upserting one by one to maintain the upsertion order.
+            diesel::insert_into(songs_contributors::table)
+                .values(songs_contributors::Data {
+                    song_id,
+                    artist_id,
+                    role: contributor.role,
+                    sub_role: contributor.sub_role.as_deref().unwrap_or_default().into(),
+                })
+                .on_conflict((
+                    songs_contributors::song_id,
+                    songs_contributors::artist_id,
+                    songs_contributors::role,
+                    songs_contributors::sub_role,
+                ))
+                .do_update()
+                .set(songs_contributors::upserted_at.eq(crate::time::now().await))
+                .execute(&mut database.get().await?)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn cleanup_one(
+        database: &Database,
+        started_at: time::OffsetDateTime,
+        song_id: Uuid,
+    ) -> Result<(), Error> {
+        // Delete all contributors of a song which haven't been refreshed since timestamp.
+        diesel::delete(songs_contributors::table)
+            .filter(songs_contributors::song_id.eq(song_id))
+            .filter(songs_contributors::upserted_at.lt(started_at))
+            .execute(&mut database.get().await?)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod test {
+    use concat_string::concat_string;
+    use diesel::QueryDsl;
+    use fake::faker::lorem::en::Word;
+
+    use super::*;
+    use crate::orm::artists;
+    use crate::test::Mock;
+
+    impl Dummy<Faker> for Contributor<'_> {
+        fn dummy_with_rng<R: fake::rand::Rng + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+            // Only performers carry a sub role and tags do not store contributor mbz ids or sort
+            // names.
+            let role: Role = config.fake_with_rng(rng);
+            Self {
+                role,
+                sub_role: if role == Role::Performer && config.fake_with_rng(rng) {
+                    Some(Word().fake_with_rng::<String, _>(rng).into())
+                } else {
+                    None
+                },
+                artist: Artist { mbz_id: None, sort_name: None, ..config.fake_with_rng(rng) },
+            }
+        }
+    }
+
+    impl Contributor<'_> {
+        pub fn format_credit(&self) -> String {
+            if let Some(ref sub_role) = self.sub_role {
+                concat_string!(self.artist.name, " (", sub_role, ")")
+            } else {
+                self.artist.name.to_string()
+            }
+        }
+    }
+
+    impl Contributors<'static> {
+        pub async fn query(mock: &Mock, song_id: Uuid) -> Self {
+            songs_contributors::table
+                .inner_join(artists::table)
+                .filter(songs_contributors::song_id.eq(song_id))
+                .select((songs_contributors::role, songs_contributors::sub_role, artists::name))
+                .order_by(songs_contributors::upserted_at)
+                .get_results::<(Role, String, String)>(&mut mock.get().await)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(role, sub_role, name)| Contributor {
+                    role,
+                    sub_role: if sub_role.is_empty() { None } else { Some(sub_role.into()) },
+                    artist: Artist { name: name.into(), mbz_id: None, sort_name: None },
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[case(Role::Performer, "Name (violin)", Some(("Name", Some("violin"))))]
+    #[case(Role::Performer, "Name (first) (violin)", Some(("Name (first)", Some("violin"))))]
+    #[case(Role::Performer, "Name", Some(("Name", None)))]
+    #[case(Role::Performer, "Name ()", Some(("Name", None)))]
+    #[case(Role::Composer, "Name (violin)", Some(("Name (violin)", None)))]
+    #[case(Role::Composer, " ", None)]
+    fn test_parse(
+        #[case] role: Role,
+        #[case] value: &str,
+        #[case] result: Option<(&str, Option<&str>)>,
+    ) {
+        assert_eq!(
+            Contributor::parse(role, value),
+            result.and_then(|(name, sub_role)| Contributor::new(role, name, sub_role))
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_contributors_upsert(
+        #[future(awt)] mock: Mock,
+        #[values(true, false)] update_contributors: bool,
+    ) {
+        let database = mock.database();
+        let prefixes = &mock.config.index.ignore_prefixes;
+
+        let information: audio::Information = Faker.fake();
+        let album_id = information.metadata.album.upsert_mock(&mock, 0).await;
+        let song_id = information
+            .upsert_song(database, album_id.into(), Faker.fake::<String>(), None)
+            .await
+            .unwrap();
+
+        let contributors: Contributors = Faker.fake();
+        contributors.upsert(database, prefixes, song_id).await.unwrap();
+        let database_contributors = Contributors::query(&mock, song_id).await;
+        assert_eq!(database_contributors, contributors);
+
+        if update_contributors {
+            let timestamp = crate::time::now().await;
+
+            let update_contributors: Contributors = Faker.fake();
+            update_contributors.upsert(database, prefixes, song_id).await.unwrap();
+            Contributors::cleanup_one(database, timestamp, song_id).await.unwrap();
+            let database_update_contributors = Contributors::query(&mock, song_id).await;
+            assert_eq!(database_update_contributors, update_contributors);
+        }
+    }
+}
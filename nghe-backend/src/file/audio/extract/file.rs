@@ -1,11 +1,14 @@
 use lofty::file::AudioFile;
 use lofty::flac::FlacFile;
 use lofty::id3::v2::Id3v2Tag;
+use lofty::mp4::{Ilst, Mp4File};
 use lofty::mpeg::MpegFile;
-use lofty::ogg::VorbisComments;
+use lofty::ogg::{OpusFile, VorbisComments, VorbisFile};
 
 use super::{Metadata, Property};
-use crate::file::audio::{self, Album, Artists, Genres, NameDateMbz, TrackDisc};
+use crate::file::audio::{
+    self, Artists, Contributors, Genres, NameDateMbz, Release, ReplayGain, TrackDisc,
+};
 use crate::file::image::Image;
 use crate::file::lyric::Lyric;
 use crate::{Error, config, error};
@@ -20,14 +23,22 @@ default impl<'a, T: Tag<'a>> Metadata<'a> for T {
         self.tag()?.song(config)
     }
 
-    fn album(&'a self, config: &'a config::Parsing) -> Result<Album<'a>, Error> {
+    fn album(&'a self, config: &'a config::Parsing) -> Result<NameDateMbz<'a>, Error> {
         self.tag()?.album(config)
     }
 
+    fn release(&'a self, config: &'a config::Parsing) -> Result<Release<'a>, Error> {
+        self.tag()?.release(config)
+    }
+
     fn artists(&'a self, config: &'a config::Parsing) -> Result<Artists<'a>, Error> {
         self.tag()?.artists(config)
     }
 
+    fn contributors(&'a self, config: &'a config::Parsing) -> Result<Contributors<'a>, Error> {
+        self.tag()?.contributors(config)
+    }
+
     fn track_disc(&'a self, config: &'a config::Parsing) -> Result<TrackDisc, Error> {
         self.tag()?.track_disc(config)
     }
@@ -36,6 +47,10 @@ default impl<'a, T: Tag<'a>> Metadata<'a> for T {
         self.tag()?.languages(config)
     }
 
+    fn replay_gain(&'a self, config: &'a config::Parsing) -> Result<ReplayGain, Error> {
+        self.tag()?.replay_gain(config)
+    }
+
     fn genres(&'a self, config: &'a config::Parsing) -> Result<Genres<'a>, Error> {
         self.tag()?.genres(config)
     }
@@ -97,3 +112,69 @@ impl Property for MpegFile {
         })
     }
 }
+
+impl<'a> Tag<'a> for VorbisFile {
+    type Tag = VorbisComments;
+    fn tag(&'a self) -> Result<&'a VorbisComments, Error> {
+        Ok(self.vorbis_comments())
+    }
+}
+
+impl Metadata<'_> for VorbisFile {}
+
+impl Property for VorbisFile {
+    fn property(&self) -> Result<audio::Property, Error> {
+        let properties = self.properties();
+        Ok(audio::Property {
+            duration: properties.duration().try_into()?,
+            bitrate: properties.audio_bitrate(),
+            bit_depth: None,
+            sample_rate: properties.sample_rate(),
+            channel_count: properties.channels(),
+        })
+    }
+}
+
+impl<'a> Tag<'a> for OpusFile {
+    type Tag = VorbisComments;
+    fn tag(&'a self) -> Result<&'a VorbisComments, Error> {
+        Ok(self.vorbis_comments())
+    }
+}
+
+impl Metadata<'_> for OpusFile {}
+
+impl Property for OpusFile {
+    fn property(&self) -> Result<audio::Property, Error> {
+        let properties = self.properties();
+        Ok(audio::Property {
+            duration: properties.duration().try_into()?,
+            bitrate: properties.audio_bitrate(),
+            bit_depth: None,
+            sample_rate: properties.input_sample_rate(),
+            channel_count: properties.channels(),
+        })
+    }
+}
+
+impl<'a> Tag<'a> for Mp4File {
+    type Tag = Ilst;
+    fn tag(&'a self) -> Result<&'a Ilst, Error> {
+        self.ilst().ok_or_else(|| error::Kind::MissingIlstTag(audio::Format::Mp4).into())
+    }
+}
+
+impl Metadata<'_> for Mp4File {}
+
+impl Property for Mp4File {
+    fn property(&self) -> Result<audio::Property, Error> {
+        let properties = self.properties();
+        Ok(audio::Property {
+            duration: properties.duration().try_into()?,
+            bitrate: properties.audio_bitrate(),
+            bit_depth: properties.bit_depth(),
+            sample_rate: properties.sample_rate(),
+            channel_count: properties.channels(),
+        })
+    }
+}
@@ -3,17 +3,20 @@ mod tag;
 
 use isolang::Language;
 
-use super::{Album, Artists, File, Genres, NameDateMbz, TrackDisc};
+use super::{Artists, Contributors, File, Genres, NameDateMbz, Release, ReplayGain, TrackDisc};
 use crate::file::image::Image;
 use crate::file::lyric::Lyric;
 use crate::{Error, config};
 
 pub trait Metadata<'a> {
     fn song(&'a self, config: &'a config::Parsing) -> Result<NameDateMbz<'a>, Error>;
-    fn album(&'a self, config: &'a config::Parsing) -> Result<Album<'a>, Error>;
+    fn album(&'a self, config: &'a config::Parsing) -> Result<NameDateMbz<'a>, Error>;
+    fn release(&'a self, config: &'a config::Parsing) -> Result<Release<'a>, Error>;
     fn artists(&'a self, config: &'a config::Parsing) -> Result<Artists<'a>, Error>;
+    fn contributors(&'a self, config: &'a config::Parsing) -> Result<Contributors<'a>, Error>;
     fn track_disc(&'a self, config: &'a config::Parsing) -> Result<TrackDisc, Error>;
     fn languages(&'a self, config: &'a config::Parsing) -> Result<Vec<Language>, Error>;
+    fn replay_gain(&'a self, config: &'a config::Parsing) -> Result<ReplayGain, Error>;
     fn genres(&'a self, config: &'a config::Parsing) -> Result<Genres<'a>, Error>;
     fn lyrics(&'a self, config: &'a config::Parsing) -> Result<Vec<Lyric<'a>>, Error>;
     fn image(&'a self) -> Result<Option<Image<'a>>, Error>;
@@ -24,9 +27,11 @@ pub trait Metadata<'a> {
                 main: self.song(config)?,
                 track_disc: self.track_disc(config)?,
                 languages: self.languages(config)?,
+                replay_gain: self.replay_gain(config)?,
             },
-            album: self.album(config)?,
+            album: super::Album { main: self.album(config)?, release: self.release(config)? },
             artists: self.artists(config)?,
+            contributors: self.contributors(config)?,
             genres: self.genres(config)?,
             lyrics: self.lyrics(config)?,
             image: self.image()?,
@@ -43,13 +48,29 @@ impl<'a> Metadata<'a> for File {
         match self {
             File::Flac { audio, .. } => audio.song(config),
             File::Mpeg { audio, .. } => audio.song(config),
+            File::Vorbis { audio, .. } => audio.song(config),
+            File::Opus { audio, .. } => audio.song(config),
+            File::Mp4 { audio, .. } => audio.song(config),
         }
     }
 
-    fn album(&'a self, config: &'a config::Parsing) -> Result<Album<'a>, Error> {
+    fn album(&'a self, config: &'a config::Parsing) -> Result<NameDateMbz<'a>, Error> {
         match self {
             File::Flac { audio, .. } => audio.album(config),
             File::Mpeg { audio, .. } => audio.album(config),
+            File::Vorbis { audio, .. } => audio.album(config),
+            File::Opus { audio, .. } => audio.album(config),
+            File::Mp4 { audio, .. } => audio.album(config),
+        }
+    }
+
+    fn release(&'a self, config: &'a config::Parsing) -> Result<Release<'a>, Error> {
+        match self {
+            File::Flac { audio, .. } => audio.release(config),
+            File::Mpeg { audio, .. } => audio.release(config),
+            File::Vorbis { audio, .. } => audio.release(config),
+            File::Opus { audio, .. } => audio.release(config),
+            File::Mp4 { audio, .. } => audio.release(config),
         }
     }
 
@@ -57,6 +78,19 @@ impl<'a> Metadata<'a> for File {
         match self {
             File::Flac { audio, .. } => audio.artists(config),
             File::Mpeg { audio, .. } => audio.artists(config),
+            File::Vorbis { audio, .. } => audio.artists(config),
+            File::Opus { audio, .. } => audio.artists(config),
+            File::Mp4 { audio, .. } => audio.artists(config),
+        }
+    }
+
+    fn contributors(&'a self, config: &'a config::Parsing) -> Result<Contributors<'a>, Error> {
+        match self {
+            File::Flac { audio, .. } => audio.contributors(config),
+            File::Mpeg { audio, .. } => audio.contributors(config),
+            File::Vorbis { audio, .. } => audio.contributors(config),
+            File::Opus { audio, .. } => audio.contributors(config),
+            File::Mp4 { audio, .. } => audio.contributors(config),
         }
     }
 
@@ -64,6 +98,9 @@ impl<'a> Metadata<'a> for File {
         match self {
             File::Flac { audio, .. } => audio.track_disc(config),
             File::Mpeg { audio, .. } => audio.track_disc(config),
+            File::Vorbis { audio, .. } => audio.track_disc(config),
+            File::Opus { audio, .. } => audio.track_disc(config),
+            File::Mp4 { audio, .. } => audio.track_disc(config),
         }
     }
 
@@ -71,6 +108,19 @@ impl<'a> Metadata<'a> for File {
         match self {
             File::Flac { audio, .. } => audio.languages(config),
             File::Mpeg { audio, .. } => audio.languages(config),
+            File::Vorbis { audio, .. } => audio.languages(config),
+            File::Opus { audio, .. } => audio.languages(config),
+            File::Mp4 { audio, .. } => audio.languages(config),
+        }
+    }
+
+    fn replay_gain(&'a self, config: &'a config::Parsing) -> Result<ReplayGain, Error> {
+        match self {
+            File::Flac { audio, .. } => audio.replay_gain(config),
+            File::Mpeg { audio, .. } => audio.replay_gain(config),
+            File::Vorbis { audio, .. } => audio.replay_gain(config),
+            File::Opus { audio, .. } => audio.replay_gain(config),
+            File::Mp4 { audio, .. } => audio.replay_gain(config),
         }
     }
 
@@ -78,6 +128,9 @@ impl<'a> Metadata<'a> for File {
         match self {
             File::Flac { audio, .. } => audio.genres(config),
             File::Mpeg { audio, .. } => audio.genres(config),
+            File::Vorbis { audio, .. } => audio.genres(config),
+            File::Opus { audio, .. } => audio.genres(config),
+            File::Mp4 { audio, .. } => audio.genres(config),
         }
     }
 
@@ -85,6 +138,9 @@ impl<'a> Metadata<'a> for File {
         match self {
             File::Flac { audio, .. } => audio.lyrics(config),
             File::Mpeg { audio, .. } => audio.lyrics(config),
+            File::Vorbis { audio, .. } => audio.lyrics(config),
+            File::Opus { audio, .. } => audio.lyrics(config),
+            File::Mp4 { audio, .. } => audio.lyrics(config),
         }
     }
 
@@ -92,6 +148,9 @@ impl<'a> Metadata<'a> for File {
         match self {
             File::Flac { audio, .. } => audio.image(),
             File::Mpeg { audio, .. } => audio.image(),
+            File::Vorbis { audio, .. } => audio.image(),
+            File::Opus { audio, .. } => audio.image(),
+            File::Mp4 { audio, .. } => audio.image(),
         }
     }
 }
@@ -101,6 +160,9 @@ impl Property for File {
         match self {
             File::Flac { audio, .. } => audio.property(),
             File::Mpeg { audio, .. } => audio.property(),
+            File::Vorbis { audio, .. } => audio.property(),
+            File::Opus { audio, .. } => audio.property(),
+            File::Mp4 { audio, .. } => audio.property(),
         }
     }
 }
@@ -1,12 +1,19 @@
+use std::borrow::Cow;
 use std::str::FromStr;
 
 use indexmap::IndexSet;
 use isolang::Language;
-use lofty::id3::v2::{AttachedPictureFrame, Frame, Id3v2Tag, Id3v2Version, TimestampFrame};
+use itertools::Itertools;
+use lofty::id3::v2::{
+    AttachedPictureFrame, Frame, Id3v2Tag, Id3v2Version, KeyValueFrame, TimestampFrame,
+};
 use uuid::Uuid;
 
 use crate::config::parsing::id3v2::frame;
-use crate::file::audio::{Album, Artist, Artists, Date, Genres, NameDateMbz, TrackDisc, extract};
+use crate::file::audio::{
+    Artist, Artists, Contributor, Contributors, Date, Genres, NameDateMbz, Release, ReplayGain,
+    TrackDisc, contributor, extract,
+};
 use crate::file::image::Image;
 use crate::file::lyric::Lyric;
 use crate::{Error, config, error};
@@ -15,7 +22,9 @@ fn get_text<'a>(tag: &'a Id3v2Tag, frame_id: &'a frame::Id) -> Result<Option<&'a
     match frame_id {
         frame::Id::Text(frame_id) => Ok(tag.get_text(frame_id)),
         frame::Id::UserText(description) => Ok(tag.get_user_text(description)),
-        frame::Id::Time(_) => error::Kind::InvalidId3v2FrameIdConfigType.into(),
+        frame::Id::Time(_) | frame::Id::KeyValue(..) => {
+            error::Kind::InvalidId3v2FrameIdConfigType.into()
+        }
     }
 }
 
@@ -34,6 +43,42 @@ fn get_texts<'a>(
     })
 }
 
+impl<'a> Contributor<'a> {
+    fn extract_id3v2(
+        tag: &'a Id3v2Tag,
+        role: contributor::Role,
+        frame_id: &'a frame::Id,
+        separator: char,
+    ) -> Result<Vec<Self>, Error> {
+        Ok(if let frame::Id::KeyValue(frame_id, key) = frame_id {
+            if let Some(Frame::KeyValue(KeyValueFrame { key_value_pairs, .. })) = tag.get(frame_id)
+            {
+                // If there is no key to filter, the key of each pair is used as the sub role,
+                // e.g. the instrument inside `TMCL`.
+                key_value_pairs
+                    .iter()
+                    .filter_map(|(pair_key, value)| match key {
+                        Some(key) => {
+                            if pair_key == key {
+                                Self::new(role, value, None)
+                            } else {
+                                None
+                            }
+                        }
+                        None => Self::new(role, value, Some(pair_key)),
+                    })
+                    .collect()
+            } else {
+                vec![]
+            }
+        } else {
+            get_texts(tag, frame_id, separator)?
+                .map(|values| values.filter_map(|value| Self::parse(role, value)).collect())
+                .unwrap_or_default()
+        })
+    }
+}
+
 impl Date {
     fn extract_id3v2(tag: &Id3v2Tag, frame_id: Option<&frame::Id>) -> Result<Self, Error> {
         if let Some(frame_id) = frame_id {
@@ -74,6 +119,9 @@ impl<'a> NameDateMbz<'a> {
                         .map_err(|_| error::Kind::InvalidMbzIdTagFormat(mbz_id.to_owned()))
                 })
                 .transpose()?,
+            sort_name: get_text(tag, &config.sort_name)?
+                .filter(|sort_name| !sort_name.is_empty())
+                .map(Cow::Borrowed),
         })
     }
 }
@@ -86,11 +134,12 @@ impl<'a> Artist<'a> {
     ) -> Result<IndexSet<Self>, Error> {
         let names = get_texts(tag, &config.name, separator)?;
         let mbz_ids = get_texts(tag, &config.mbz_id, separator)?;
+        let sort_names = get_texts(tag, &config.sort_name, separator)?.into_iter().flatten();
         match (names, mbz_ids) {
             (None, None) => Ok(IndexSet::default()),
             (None, Some(_)) => error::Kind::InvalidMbzIdSize.into(),
-            (Some(names), None) => Self::try_collect(names, vec![].into_iter()),
-            (Some(names), Some(mbz_ids)) => Self::try_collect(names, mbz_ids),
+            (Some(names), None) => Self::try_collect(names, vec![].into_iter(), sort_names),
+            (Some(names), Some(mbz_ids)) => Self::try_collect(names, mbz_ids, sort_names),
         }
     }
 }
@@ -100,8 +149,24 @@ impl<'a> extract::Metadata<'a> for Id3v2Tag {
         NameDateMbz::extract_id3v2(self, &config.id3v2.song)
     }
 
-    fn album(&'a self, config: &'a config::Parsing) -> Result<Album<'a>, Error> {
-        Album::extract_id3v2(self, &config.id3v2.album)
+    fn album(&'a self, config: &'a config::Parsing) -> Result<NameDateMbz<'a>, Error> {
+        NameDateMbz::extract_id3v2(self, &config.id3v2.album)
+    }
+
+    fn release(&'a self, config: &'a config::Parsing) -> Result<Release<'a>, Error> {
+        let config::parsing::id3v2::Release {
+            release_types,
+            record_labels,
+            catalog_number,
+            barcode,
+        } = &config.id3v2.release;
+        let separator = config.id3v2.separator;
+        Ok(Release::parse(
+            get_texts(self, release_types, separator)?.into_iter().flatten(),
+            get_texts(self, record_labels, separator)?.into_iter().flatten(),
+            get_text(self, catalog_number)?,
+            get_text(self, barcode)?,
+        ))
     }
 
     fn artists(&'a self, config: &'a config::Parsing) -> Result<Artists<'a>, Error> {
@@ -112,6 +177,30 @@ impl<'a> extract::Metadata<'a> for Id3v2Tag {
         )
     }
 
+    fn contributors(&'a self, config: &'a config::Parsing) -> Result<Contributors<'a>, Error> {
+        let config::parsing::id3v2::Contributors {
+            composer,
+            conductor,
+            performer,
+            lyricist,
+            arranger,
+            producer,
+        } = &config.id3v2.contributors;
+        let separator = config.id3v2.separator;
+        [
+            (contributor::Role::Composer, composer),
+            (contributor::Role::Conductor, conductor),
+            (contributor::Role::Performer, performer),
+            (contributor::Role::Lyricist, lyricist),
+            (contributor::Role::Arranger, arranger),
+            (contributor::Role::Producer, producer),
+        ]
+        .into_iter()
+        .map(|(role, frame_id)| Contributor::extract_id3v2(self, role, frame_id, separator))
+        .flatten_ok()
+        .try_collect()
+    }
+
     fn track_disc(&'a self, config: &'a config::Parsing) -> Result<TrackDisc, Error> {
         let config::parsing::id3v2::TrackDisc { track_position, disc_position } =
             &config.id3v2.track_disc;
@@ -134,6 +223,17 @@ impl<'a> extract::Metadata<'a> for Id3v2Tag {
             .unwrap_or_default())
     }
 
+    fn replay_gain(&'a self, config: &'a config::Parsing) -> Result<ReplayGain, Error> {
+        let config::parsing::id3v2::ReplayGain { track_gain, track_peak, album_gain, album_peak } =
+            &config.id3v2.replay_gain;
+        Ok(ReplayGain::parse(
+            get_text(self, track_gain)?,
+            get_text(self, track_peak)?,
+            get_text(self, album_gain)?,
+            get_text(self, album_peak)?,
+        ))
+    }
+
     fn genres(&'a self, config: &'a config::Parsing) -> Result<Genres<'a>, Error> {
         Ok(get_texts(self, &config.id3v2.genres, config.id3v2.separator)?
             .map(std::iter::Iterator::collect)
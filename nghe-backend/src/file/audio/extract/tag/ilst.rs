@@ -0,0 +1,194 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use indexmap::IndexSet;
+use isolang::Language;
+use itertools::Itertools;
+use lofty::mp4::{Atom, AtomData, Ilst};
+use lofty::tag::Accessor;
+use uuid::Uuid;
+
+use crate::config::parsing::ilst::atom;
+use crate::file::audio::position::Position;
+use crate::file::audio::{
+    Artist, Artists, Contributor, Contributors, Date, Genres, NameDateMbz, Release, ReplayGain,
+    TrackDisc, contributor, extract,
+};
+use crate::file::image::Image;
+use crate::file::lyric::Lyric;
+use crate::{Error, config, error};
+
+fn get_texts<'a>(tag: &'a Ilst, atom_id: &atom::Id) -> impl Iterator<Item = &'a str> + use<'a> {
+    tag.get(&atom_id.0).into_iter().flat_map(Atom::data).filter_map(|data| match data {
+        AtomData::UTF8(text) | AtomData::UTF16(text) => Some(text.as_str()),
+        _ => None,
+    })
+}
+
+fn get_text<'a>(tag: &'a Ilst, atom_id: &atom::Id) -> Option<&'a str> {
+    get_texts(tag, atom_id).next()
+}
+
+fn get_flag(tag: &Ilst, atom_id: &atom::Id) -> bool {
+    tag.get(&atom_id.0).and_then(|atom| atom.data().next()).is_some_and(|data| match data {
+        AtomData::Bool(flag) => *flag,
+        AtomData::SignedInteger(value) => *value != 0,
+        AtomData::UnsignedInteger(value) => *value != 0,
+        AtomData::UTF8(text) | AtomData::UTF16(text) => !text.is_empty(),
+        AtomData::Picture(_) | AtomData::Unknown { .. } => false,
+    })
+}
+
+impl Date {
+    fn extract_ilst(tag: &Ilst, atom_id: Option<&atom::Id>) -> Result<Self, Error> {
+        if let Some(atom_id) = atom_id {
+            get_text(tag, atom_id).map(Self::from_str).transpose().map(Option::unwrap_or_default)
+        } else {
+            Ok(Self::default())
+        }
+    }
+}
+
+impl<'a> NameDateMbz<'a> {
+    fn extract_ilst(
+        tag: &'a Ilst,
+        config: &'a config::parsing::ilst::Common,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            name: get_text(tag, &config.name).ok_or_else(|| error::Kind::MissingMediaName)?.into(),
+            date: Date::extract_ilst(tag, config.date.as_ref())?,
+            release_date: Date::extract_ilst(tag, config.release_date.as_ref())?,
+            original_release_date: Date::extract_ilst(tag, config.original_release_date.as_ref())?,
+            mbz_id: get_text(tag, &config.mbz_id)
+                .map(|mbz_id| {
+                    Uuid::from_str(mbz_id)
+                        .map_err(|_| error::Kind::InvalidMbzIdTagFormat(mbz_id.to_owned()))
+                })
+                .transpose()?,
+            sort_name: get_text(tag, &config.sort_name)
+                .filter(|sort_name| !sort_name.is_empty())
+                .map(Cow::Borrowed),
+        })
+    }
+}
+
+impl<'a> Artist<'a> {
+    fn extract_ilst(
+        tag: &'a Ilst,
+        config: &'a config::parsing::ilst::Artist,
+    ) -> Result<IndexSet<Self>, Error> {
+        Self::try_collect(
+            get_texts(tag, &config.name),
+            get_texts(tag, &config.mbz_id),
+            get_texts(tag, &config.sort_name),
+        )
+    }
+}
+
+impl Position {
+    fn extract_ilst(number: Option<u32>, total: Option<u32>) -> Result<Self, Error> {
+        // A missing number or total is stored as zero inside `trkn` and `disk` atoms.
+        Ok(Self {
+            number: number.filter(|number| *number > 0).map(u16::try_from).transpose()?,
+            total: total.filter(|total| *total > 0).map(u16::try_from).transpose()?,
+        })
+    }
+}
+
+impl<'a> extract::Metadata<'a> for Ilst {
+    fn song(&'a self, config: &'a config::Parsing) -> Result<NameDateMbz<'a>, Error> {
+        NameDateMbz::extract_ilst(self, &config.ilst.song)
+    }
+
+    fn album(&'a self, config: &'a config::Parsing) -> Result<NameDateMbz<'a>, Error> {
+        NameDateMbz::extract_ilst(self, &config.ilst.album)
+    }
+
+    fn release(&'a self, config: &'a config::Parsing) -> Result<Release<'a>, Error> {
+        let config::parsing::ilst::Release {
+            release_types,
+            record_labels,
+            catalog_number,
+            barcode,
+        } = &config.ilst.release;
+        Ok(Release::parse(
+            get_texts(self, release_types),
+            get_texts(self, record_labels),
+            get_text(self, catalog_number),
+            get_text(self, barcode),
+        ))
+    }
+
+    fn artists(&'a self, config: &'a config::Parsing) -> Result<Artists<'a>, Error> {
+        Artists::new(
+            Artist::extract_ilst(self, &config.ilst.artists.song)?,
+            Artist::extract_ilst(self, &config.ilst.artists.album)?,
+            get_flag(self, &config.ilst.compilation),
+        )
+    }
+
+    fn contributors(&'a self, config: &'a config::Parsing) -> Result<Contributors<'a>, Error> {
+        let config::parsing::ilst::Contributors {
+            composer,
+            conductor,
+            performer,
+            lyricist,
+            arranger,
+            producer,
+        } = &config.ilst.contributors;
+        Ok([
+            (contributor::Role::Composer, composer),
+            (contributor::Role::Conductor, conductor),
+            (contributor::Role::Performer, performer),
+            (contributor::Role::Lyricist, lyricist),
+            (contributor::Role::Arranger, arranger),
+            (contributor::Role::Producer, producer),
+        ]
+        .into_iter()
+        .flat_map(|(role, atom_id)| {
+            get_texts(self, atom_id).filter_map(move |value| Contributor::parse(role, value))
+        })
+        .collect())
+    }
+
+    fn track_disc(&'a self, _: &'a config::Parsing) -> Result<TrackDisc, Error> {
+        Ok(TrackDisc {
+            track: Position::extract_ilst(self.track(), self.track_total())?,
+            disc: Position::extract_ilst(self.disk(), self.disk_total())?,
+        })
+    }
+
+    fn languages(&'a self, config: &'a config::Parsing) -> Result<Vec<isolang::Language>, Error> {
+        Ok(get_texts(self, &config.ilst.languages)
+            .map(|language| Language::from_str(language).map_err(error::Kind::from))
+            .try_collect()?)
+    }
+
+    fn replay_gain(&'a self, config: &'a config::Parsing) -> Result<ReplayGain, Error> {
+        let config::parsing::ilst::ReplayGain { track_gain, track_peak, album_gain, album_peak } =
+            &config.ilst.replay_gain;
+        Ok(ReplayGain::parse(
+            get_text(self, track_gain),
+            get_text(self, track_peak),
+            get_text(self, album_gain),
+            get_text(self, album_peak),
+        ))
+    }
+
+    fn genres(&'a self, config: &'a config::Parsing) -> Result<Genres<'a>, Error> {
+        Ok(get_texts(self, &config.ilst.genres).collect())
+    }
+
+    fn lyrics(&'a self, config: &'a config::Parsing) -> Result<Vec<Lyric<'a>>, Error> {
+        get_text(self, &config.ilst.lyric.unsync)
+            .map(|content| Ok(Lyric::from_unsync_text(content)))
+            .into_iter()
+            .chain(get_texts(self, &config.ilst.lyric.sync).map(Lyric::from_sync_text))
+            .try_collect()
+    }
+
+    fn image(&'a self) -> Result<Option<Image<'a>>, Error> {
+        // Pictures inside ilst do not have any description so we can not filter them in test.
+        self.pictures().and_then(|mut pictures| pictures.next()).map(Image::try_from).transpose()
+    }
+}
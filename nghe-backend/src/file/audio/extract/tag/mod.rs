@@ -1,2 +1,3 @@
 mod id3v2;
+mod ilst;
 mod vorbis_comments;
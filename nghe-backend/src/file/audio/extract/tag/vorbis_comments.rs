@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::str::FromStr;
 
 use indexmap::IndexSet;
@@ -6,7 +7,10 @@ use itertools::Itertools;
 use lofty::ogg::{OggPictureStorage, VorbisComments};
 use uuid::Uuid;
 
-use crate::file::audio::{Album, Artist, Artists, Date, Genres, NameDateMbz, TrackDisc, extract};
+use crate::file::audio::{
+    Artist, Artists, Contributor, Contributors, Date, Genres, NameDateMbz, Release, ReplayGain,
+    TrackDisc, contributor, extract,
+};
 use crate::file::image::Image;
 use crate::file::lyric::Lyric;
 use crate::{Error, config, error};
@@ -41,6 +45,10 @@ impl<'a> NameDateMbz<'a> {
                         .map_err(|_| error::Kind::InvalidMbzIdTagFormat(mbz_id.to_owned()))
                 })
                 .transpose()?,
+            sort_name: tag
+                .get(&config.sort_name)
+                .filter(|sort_name| !sort_name.is_empty())
+                .map(Cow::Borrowed),
         })
     }
 }
@@ -52,7 +60,8 @@ impl<'a> Artist<'a> {
     ) -> Result<IndexSet<Self>, Error> {
         let names = tag.get_all(&config.name);
         let mbz_ids = tag.get_all(&config.mbz_id);
-        Self::try_collect(names, mbz_ids)
+        let sort_names = tag.get_all(&config.sort_name);
+        Self::try_collect(names, mbz_ids, sort_names)
     }
 }
 
@@ -81,8 +90,23 @@ impl<'a> extract::Metadata<'a> for VorbisComments {
         NameDateMbz::extract_vorbis_comments(self, &config.vorbis_comments.song)
     }
 
-    fn album(&'a self, config: &'a config::Parsing) -> Result<Album<'a>, Error> {
-        Album::extract_vorbis_comments(self, &config.vorbis_comments.album)
+    fn album(&'a self, config: &'a config::Parsing) -> Result<NameDateMbz<'a>, Error> {
+        NameDateMbz::extract_vorbis_comments(self, &config.vorbis_comments.album)
+    }
+
+    fn release(&'a self, config: &'a config::Parsing) -> Result<Release<'a>, Error> {
+        let config::parsing::vorbis_comments::Release {
+            release_types,
+            record_labels,
+            catalog_number,
+            barcode,
+        } = &config.vorbis_comments.release;
+        Ok(Release::parse(
+            self.get_all(release_types),
+            self.get_all(record_labels),
+            self.get(catalog_number),
+            self.get(barcode),
+        ))
     }
 
     fn artists(&'a self, config: &'a config::Parsing) -> Result<Artists<'a>, Error> {
@@ -93,6 +117,30 @@ impl<'a> extract::Metadata<'a> for VorbisComments {
         )
     }
 
+    fn contributors(&'a self, config: &'a config::Parsing) -> Result<Contributors<'a>, Error> {
+        let config::parsing::vorbis_comments::Contributors {
+            composer,
+            conductor,
+            performer,
+            lyricist,
+            arranger,
+            producer,
+        } = &config.vorbis_comments.contributors;
+        Ok([
+            (contributor::Role::Composer, composer),
+            (contributor::Role::Conductor, conductor),
+            (contributor::Role::Performer, performer),
+            (contributor::Role::Lyricist, lyricist),
+            (contributor::Role::Arranger, arranger),
+            (contributor::Role::Producer, producer),
+        ]
+        .into_iter()
+        .flat_map(|(role, key)| {
+            self.get_all(key).filter_map(move |value| Contributor::parse(role, value))
+        })
+        .collect())
+    }
+
     fn track_disc(&'a self, config: &'a config::Parsing) -> Result<TrackDisc, Error> {
         let config::parsing::vorbis_comments::TrackDisc {
             track_number,
@@ -115,6 +163,21 @@ impl<'a> extract::Metadata<'a> for VorbisComments {
             .try_collect()?)
     }
 
+    fn replay_gain(&'a self, config: &'a config::Parsing) -> Result<ReplayGain, Error> {
+        let config::parsing::vorbis_comments::ReplayGain {
+            track_gain,
+            track_peak,
+            album_gain,
+            album_peak,
+        } = &config.vorbis_comments.replay_gain;
+        Ok(ReplayGain::parse(
+            self.get(track_gain),
+            self.get(track_peak),
+            self.get(album_gain),
+            self.get(album_peak),
+        ))
+    }
+
     fn genres(&'a self, config: &'a config::Parsing) -> Result<Genres<'a>, Error> {
         Ok(self.get_all(&config.vorbis_comments.genres).collect())
     }
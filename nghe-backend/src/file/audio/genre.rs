@@ -98,7 +98,7 @@ impl Genres<'_> {
         Ok(())
     }
 
-    pub async fn cleanup(database: &Database) -> Result<(), Error> {
+    pub async fn cleanup(database: &Database) -> Result<usize, Error> {
         // Delete all genres which do not have any song associated.
         let alias_genres = diesel::alias!(genres as alias_genres);
         diesel::delete(genres::table)
@@ -113,8 +113,8 @@ impl Genres<'_> {
                 ),
             )
             .execute(&mut database.get().await?)
-            .await?;
-        Ok(())
+            .await
+            .map_err(Error::from)
     }
 }
 
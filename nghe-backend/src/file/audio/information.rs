@@ -1,16 +1,17 @@
 use std::borrow::Cow;
 
-use diesel::ExpressionMethods;
+use diesel::{ExpressionMethods, QueryDsl};
 use diesel_async::RunQueryDsl;
 use o2o::o2o;
 use typed_path::Utf8PlatformPath;
 use uuid::Uuid;
 
-use super::{Album, Artists, Genres};
+use super::{Album, Artists, Contributors, Genres};
 use crate::database::Database;
 use crate::file::lyric::Lyric;
+use crate::filesystem::path;
 use crate::orm::upsert::Upsert as _;
-use crate::orm::{albums, lyrics, songs};
+use crate::orm::{albums, function, lyrics, scans, songs};
 use crate::scan::scanner;
 use crate::{Error, file};
 
@@ -44,6 +45,15 @@ impl Information<'_> {
         self.metadata.artists.upsert(database, prefixes, song_id).await
     }
 
+    pub async fn upsert_contributors(
+        &self,
+        database: &Database,
+        prefixes: &[impl AsRef<str>],
+        song_id: Uuid,
+    ) -> Result<(), Error> {
+        self.metadata.contributors.upsert(database, prefixes, song_id).await
+    }
+
     pub async fn upsert_genres(&self, database: &Database, song_id: Uuid) -> Result<(), Error> {
         let genre_ids = self.metadata.genres.upsert(database).await?;
         Genres::upsert_song(database, song_id, &genre_ids).await
@@ -97,6 +107,7 @@ impl Information<'_> {
 
         let song_id = self.upsert_song(database, foreign, relative_path, song_id).await?;
         self.upsert_artists(database, &config.index.ignore_prefixes, song_id).await?;
+        self.upsert_contributors(database, &config.index.ignore_prefixes, song_id).await?;
         self.upsert_genres(database, song_id).await?;
         self.upsert_lyrics(database, song_id).await?;
         Ok(song_id)
@@ -108,6 +119,7 @@ impl Information<'_> {
         song_id: Uuid,
     ) -> Result<(), Error> {
         Artists::cleanup_one(database, started_at, song_id).await?;
+        Contributors::cleanup_one(database, started_at, song_id).await?;
         Genres::cleanup_one(database, started_at, song_id).await?;
         crate::file::lyric::Lyric::cleanup_one(database, started_at, song_id).await?;
         Ok(())
@@ -116,15 +128,60 @@ impl Information<'_> {
     pub async fn cleanup(
         database: &Database,
         started_at: time::OffsetDateTime,
-    ) -> Result<(), Error> {
-        diesel::delete(songs::table)
+        music_folder_id: Uuid,
+    ) -> Result<scans::Deleted, Error> {
+        // Only delete songs of the music folder being scanned, other music folders might not be
+        // scanned at all or scanned at a different time.
+        let song = diesel::delete(songs::table)
             .filter(songs::scanned_at.lt(started_at))
+            .filter(
+                songs::album_id.eq_any(
+                    albums::table
+                        .filter(albums::music_folder_id.eq(music_folder_id))
+                        .select(albums::id),
+                ),
+            )
             .execute(&mut database.get().await?)
             .await?;
-        Album::cleanup(database).await?;
-        Artists::cleanup(database).await?;
-        Genres::cleanup(database).await?;
-        Ok(())
+        Self::cleanup_orphan(database, song).await
+    }
+
+    pub async fn cleanup_paths(
+        database: &Database,
+        music_folder_id: Uuid,
+        relative_paths: &[String],
+    ) -> Result<scans::Deleted, Error> {
+        let mut song_ids = vec![];
+        for relative_path in relative_paths {
+            // A removed path can either be a file or a directory, delete the song at that path
+            // and every song under it. Candidates are narrowed down by their string prefix and
+            // then compared by their components so removing `a/b` does not remove `a/bc`.
+            let candidates = songs::table
+                .inner_join(albums::table)
+                .filter(albums::music_folder_id.eq(music_folder_id))
+                .filter(function::starts_with(songs::relative_path, relative_path))
+                .select((songs::id, songs::relative_path))
+                .get_results::<(Uuid, String)>(&mut database.get().await?)
+                .await?;
+            song_ids.extend(candidates.into_iter().filter_map(|(song_id, song_path)| {
+                path::Local::from_str(&song_path).starts_with(relative_path).then_some(song_id)
+            }));
+        }
+
+        let song = diesel::delete(songs::table)
+            .filter(songs::id.eq_any(song_ids))
+            .execute(&mut database.get().await?)
+            .await?;
+        Self::cleanup_orphan(database, song).await
+    }
+
+    async fn cleanup_orphan(database: &Database, song: usize) -> Result<scans::Deleted, Error> {
+        Ok(scans::Deleted {
+            song: song.try_into()?,
+            album: Album::cleanup(database).await?.try_into()?,
+            artist: Artists::cleanup(database).await?.try_into()?,
+            genre: Genres::cleanup(database).await?.try_into()?,
+        })
     }
 }
 
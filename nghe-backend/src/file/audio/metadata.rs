@@ -5,7 +5,7 @@ use isolang::Language;
 use itertools::Itertools;
 use o2o::o2o;
 
-use super::{Genres, artist, name_date_mbz, position};
+use super::{Contributors, Genres, ReplayGain, artist, name_date_mbz, position};
 use crate::file::image::Image;
 use crate::file::lyric::Lyric;
 use crate::orm::songs;
@@ -32,6 +32,8 @@ pub struct Song<'a> {
                       map(Language::from_usize).collect::<Option<_>>().unwrap()")
     )]
     pub languages: Vec<Language>,
+    #[map(~.into())]
+    pub replay_gain: ReplayGain,
 }
 
 #[derive(Debug)]
@@ -40,6 +42,7 @@ pub struct Metadata<'a> {
     pub song: Song<'a>,
     pub album: name_date_mbz::Album<'a>,
     pub artists: artist::Artists<'a>,
+    pub contributors: Contributors<'a>,
     pub genres: Genres<'a>,
     #[cfg_attr(test, dummy(expr = "Lyric::fake_vec()"))]
     pub lyrics: Vec<Lyric<'a>>,
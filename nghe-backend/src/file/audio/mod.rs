@@ -1,4 +1,5 @@
 mod artist;
+pub mod contributor;
 mod date;
 pub mod duration;
 mod extract;
@@ -8,11 +9,14 @@ mod metadata;
 mod name_date_mbz;
 pub mod position;
 mod property;
+mod release;
+mod replay_gain;
 pub mod transcode;
 
-use std::io::Cursor;
+use std::io::{Cursor, Read, Seek};
 
 pub use artist::{Artist, Artists};
+pub use contributor::{Contributor, Contributors};
 pub use date::Date;
 use diesel::sql_types::Text;
 use diesel::{AsExpression, FromSqlRow};
@@ -23,12 +27,16 @@ pub use information::Information;
 use lofty::config::ParseOptions;
 use lofty::file::AudioFile;
 use lofty::flac::FlacFile;
+use lofty::mp4::Mp4File;
 use lofty::mpeg::MpegFile;
+use lofty::ogg::{OpusFile, VorbisFile};
 pub use metadata::{Metadata, Song};
 pub use name_date_mbz::{Album, NameDateMbz};
 use nghe_api::common::format;
 pub use position::TrackDisc;
 pub use property::Property;
+pub use release::Release;
+pub use replay_gain::ReplayGain;
 use strum::{EnumString, IntoStaticStr};
 
 use crate::{Error, config};
@@ -54,11 +62,19 @@ pub enum Format {
     Flac,
     #[strum(serialize = "mp3")]
     Mpeg,
+    #[strum(to_string = "ogg", serialize = "oga")]
+    Vorbis,
+    Opus,
+    #[strum(to_string = "m4a", serialize = "mp4", serialize = "m4b")]
+    Mp4,
 }
 
 pub enum File {
     Flac { audio: FlacFile, file: super::File<Format> },
     Mpeg { audio: MpegFile, file: super::File<Format> },
+    Vorbis { audio: VorbisFile, file: super::File<Format> },
+    Opus { audio: OpusFile, file: super::File<Format> },
+    Mp4 { audio: Mp4File, file: super::File<Format> },
 }
 
 impl format::Trait for Format {
@@ -66,6 +82,8 @@ impl format::Trait for Format {
         match self {
             Self::Flac => "audio/flac",
             Self::Mpeg => "audio/mpeg",
+            Self::Vorbis | Self::Opus => "audio/ogg",
+            Self::Mp4 => "audio/mp4",
         }
     }
 
@@ -74,8 +92,39 @@ impl format::Trait for Format {
     }
 }
 
+impl Format {
+    // Podcast episodes do not have the metadata required by `extract` and are read from the disk
+    // instead of being kept in memory.
+    pub fn read_property<R: Read + Seek>(
+        self,
+        reader: &mut R,
+        parse_options: ParseOptions,
+    ) -> Result<Property, Error> {
+        match self {
+            Format::Flac => FlacFile::read_from(reader, parse_options)?.property(),
+            Format::Mpeg => MpegFile::read_from(reader, parse_options)?.property(),
+            Format::Vorbis => VorbisFile::read_from(reader, parse_options)?.property(),
+            Format::Opus => OpusFile::read_from(reader, parse_options)?.property(),
+            Format::Mp4 => Mp4File::read_from(reader, parse_options)?.property(),
+        }
+    }
+}
+
 impl super::File<Format> {
-    pub fn audio(self, parse_options: ParseOptions) -> Result<File, Error> {
+    // The first packet of an ogg file starts right after the segment table of its first page.
+    fn is_opus(data: &[u8]) -> bool {
+        data.get(26)
+            .map(|n_segment| 27 + usize::from(*n_segment))
+            .and_then(|start| data.get(start..start + 8))
+            == Some(b"OpusHead")
+    }
+
+    pub fn audio(mut self, parse_options: ParseOptions) -> Result<File, Error> {
+        // The `ogg` extension is used for both vorbis and opus so the codec is probed instead.
+        if self.property.format == Format::Vorbis && Self::is_opus(&self.data) {
+            self.property.format = Format::Opus;
+        }
+
         let mut reader = Cursor::new(&self.data);
         match self.property.format {
             Format::Flac => Ok(File::Flac {
@@ -86,6 +135,17 @@ impl super::File<Format> {
                 audio: MpegFile::read_from(&mut reader, parse_options)?,
                 file: self,
             }),
+            Format::Vorbis => Ok(File::Vorbis {
+                audio: VorbisFile::read_from(&mut reader, parse_options)?,
+                file: self,
+            }),
+            Format::Opus => Ok(File::Opus {
+                audio: OpusFile::read_from(&mut reader, parse_options)?,
+                file: self,
+            }),
+            Format::Mp4 => {
+                Ok(File::Mp4 { audio: Mp4File::read_from(&mut reader, parse_options)?, file: self })
+            }
         }
     }
 }
@@ -93,7 +153,11 @@ impl super::File<Format> {
 impl File {
     pub fn file(&self) -> &super::File<Format> {
         match self {
-            Self::Flac { file, .. } | Self::Mpeg { file, .. } => file,
+            Self::Flac { file, .. }
+            | Self::Mpeg { file, .. }
+            | Self::Vorbis { file, .. }
+            | Self::Opus { file, .. }
+            | Self::Mp4 { file, .. } => file,
         }
     }
 
@@ -113,6 +177,7 @@ mod test {
 
     use lofty::config::WriteOptions;
     use lofty::id3::v2::Id3v2Tag;
+    use lofty::mp4::Ilst;
     use lofty::ogg::VorbisComments;
 
     use super::*;
@@ -126,6 +191,15 @@ mod test {
                 File::Mpeg { audio, .. } => {
                     audio.set_id3v2(Id3v2Tag::default());
                 }
+                File::Vorbis { audio, .. } => {
+                    audio.set_vorbis_comments(VorbisComments::default());
+                }
+                File::Opus { audio, .. } => {
+                    audio.set_vorbis_comments(VorbisComments::default());
+                }
+                File::Mp4 { audio, .. } => {
+                    audio.set_ilst(Ilst::default());
+                }
             }
             self
         }
@@ -138,6 +212,15 @@ mod test {
                 File::Mpeg { audio, .. } => {
                     audio.save_to(cursor, write_options).unwrap();
                 }
+                File::Vorbis { audio, .. } => {
+                    audio.save_to(cursor, write_options).unwrap();
+                }
+                File::Opus { audio, .. } => {
+                    audio.save_to(cursor, write_options).unwrap();
+                }
+                File::Mp4 { audio, .. } => {
+                    audio.save_to(cursor, write_options).unwrap();
+                }
             }
         }
     }
@@ -158,7 +241,31 @@ mod tests {
     use crate::test::{Mock, assets, mock};
 
     #[rstest]
-    fn test_media(#[values(Format::Flac, Format::Mpeg)] format: Format) {
+    #[case("m4a", Format::Mp4)]
+    #[case("mp4", Format::Mp4)]
+    #[case("m4b", Format::Mp4)]
+    #[case("ogg", Format::Vorbis)]
+    #[case("oga", Format::Vorbis)]
+    fn test_format_from_extension(#[case] extension: &str, #[case] format: Format) {
+        assert_eq!(Format::try_from(extension).unwrap(), format);
+    }
+
+    #[rstest]
+    #[case(Format::Vorbis, Format::Vorbis)]
+    #[case(Format::Opus, Format::Opus)]
+    fn test_probe_ogg(#[case] asset: Format, #[case] format: Format) {
+        let file = File::new(Format::Vorbis, std::fs::read(assets::path(asset).as_str()).unwrap())
+            .unwrap()
+            .audio(ParseOptions::default())
+            .unwrap();
+        assert_eq!(file.file().property.format, format);
+    }
+
+    #[rstest]
+    fn test_media(
+        #[values(Format::Flac, Format::Mpeg, Format::Vorbis, Format::Opus, Format::Mp4)]
+        format: Format,
+    ) {
         let file = File::new(format, std::fs::read(assets::path(format).as_str()).unwrap())
             .unwrap()
             .audio(ParseOptions::default())
@@ -186,7 +293,7 @@ mod tests {
 
         assert_eq!(song.languages, &[Language::Eng, Language::Vie]);
 
-        let album = metadata.album;
+        let album = metadata.album.main;
         assert_eq!(album.name, "Album");
         assert_eq!(
             album.date,
@@ -228,7 +335,8 @@ mod tests {
         #[with(0, 0)]
         mock: Mock,
         #[values(filesystem::Type::Local, filesystem::Type::S3)] ty: filesystem::Type,
-        #[values(Format::Flac, Format::Mpeg)] format: Format,
+        #[values(Format::Flac, Format::Mpeg, Format::Vorbis, Format::Opus, Format::Mp4)]
+        format: Format,
     ) {
         mock.add_music_folder().ty(ty).call().await;
         let mut music_folder = mock.music_folder(0).await;
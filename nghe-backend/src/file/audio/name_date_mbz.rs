@@ -8,6 +8,7 @@ use fake::{Dummy, Fake, Faker};
 use o2o::o2o;
 use uuid::Uuid;
 
+use super::Release;
 use super::date::Date;
 use crate::Error;
 use crate::database::Database;
@@ -16,9 +17,9 @@ use crate::orm::{albums, songs};
 
 #[derive(Debug, o2o)]
 #[try_map_owned(songs::name_date_mbz::NameDateMbz<'a>, Error)]
-#[try_map_owned(albums::Data<'a>, Error)]
+#[try_map_owned(albums::name_date_mbz::NameDateMbz<'a>, Error)]
 #[ref_try_into(songs::name_date_mbz::NameDateMbz<'a>, Error)]
-#[ref_try_into(albums::Data<'a>, Error)]
+#[ref_try_into(albums::name_date_mbz::NameDateMbz<'a>, Error)]
 #[cfg_attr(test, derive(PartialEq, Eq, Dummy, Clone, Default))]
 pub struct NameDateMbz<'a> {
     #[ref_into(~.as_str().into())]
@@ -31,9 +32,23 @@ pub struct NameDateMbz<'a> {
     #[map(~.try_into()?)]
     pub original_release_date: Date,
     pub mbz_id: Option<Uuid>,
+    #[ref_into(~.as_deref().map(Cow::Borrowed))]
+    #[cfg_attr(test, dummy(expr = "Faker.fake::<Option<String>>().map(Cow::Owned)"))]
+    pub sort_name: Option<Cow<'a, str>>,
 }
 
-pub type Album<'a> = NameDateMbz<'a>;
+#[derive(Debug, o2o)]
+#[try_map_owned(albums::Data<'a>, Error)]
+#[ref_try_into(albums::Data<'a>, Error)]
+#[cfg_attr(test, derive(PartialEq, Eq, Dummy, Clone, Default))]
+pub struct Album<'a> {
+    #[map_owned(~.try_into()?)]
+    #[ref_into((&~).try_into()?)]
+    pub main: NameDateMbz<'a>,
+    #[map_owned(~.into())]
+    #[ref_into((&~).into())]
+    pub release: Release<'a>,
+}
 
 impl Album<'_> {
     pub async fn upsert(
@@ -44,7 +59,7 @@ impl Album<'_> {
         albums::Upsert { foreign, data: self.try_into()? }.insert(database).await
     }
 
-    pub async fn cleanup(database: &Database) -> Result<(), Error> {
+    pub async fn cleanup(database: &Database) -> Result<usize, Error> {
         // Delete all albums which do not have any song associated.
         let alias_albums = diesel::alias!(albums as alias);
         diesel::delete(albums::table)
@@ -58,8 +73,8 @@ impl Album<'_> {
                 ),
             )
             .execute(&mut database.get().await?)
-            .await?;
-        Ok(())
+            .await
+            .map_err(Error::from)
     }
 }
 
@@ -73,12 +88,18 @@ mod test {
     use super::*;
     use crate::test::Mock;
 
-    impl<'a, S: Into<Cow<'a, str>>> From<S> for Album<'a> {
+    impl<'a, S: Into<Cow<'a, str>>> From<S> for NameDateMbz<'a> {
         fn from(value: S) -> Self {
             Self { name: value.into(), ..Self::default() }
         }
     }
 
+    impl<'a, S: Into<Cow<'a, str>>> From<S> for Album<'a> {
+        fn from(value: S) -> Self {
+            Self { main: NameDateMbz::from(value), release: Release::default() }
+        }
+    }
+
     impl Album<'_> {
         pub async fn upsert_mock(&self, mock: &Mock, index: usize) -> Uuid {
             self.upsert(mock.database(), mock.music_folder_id(index).await.into()).await.unwrap()
@@ -147,7 +168,9 @@ mod tests {
         let mbz_id = if mbz_id { Some(Faker.fake()) } else { None };
         let album = albums::Upsert {
             foreign: albums::Foreign { music_folder_id, cover_art_id },
-            data: Album { mbz_id, ..Faker.fake() }.try_into().unwrap(),
+            data: Album { main: NameDateMbz { mbz_id, ..Faker.fake() }, ..Faker.fake() }
+                .try_into()
+                .unwrap(),
         };
         let id = album.upsert_mock(&mock).await;
         let database_album = Album::query_upsert(&mock, id).await;
@@ -162,7 +185,9 @@ mod tests {
 
             let update_album = albums::Upsert {
                 foreign: albums::Foreign { music_folder_id, cover_art_id: update_cover_art_id },
-                data: Album { mbz_id, ..Faker.fake() }.try_into().unwrap(),
+                data: Album { main: NameDateMbz { mbz_id, ..Faker.fake() }, ..Faker.fake() }
+                    .try_into()
+                    .unwrap(),
             };
             let update_id = update_album.upsert_mock(&mock).await;
             let database_update_album = Album::query_upsert(&mock, id).await;
@@ -182,10 +207,16 @@ mod tests {
     async fn test_album_upsert_no_mbz_id(#[future(awt)] mock: Mock) {
         // We want to make sure that insert the same album with no mbz_id
         // twice does not result in any error.
-        let album = Album { mbz_id: None, ..Faker.fake() };
+        let album = Album { main: NameDateMbz { mbz_id: None, ..Faker.fake() }, ..Faker.fake() };
         let id = album.upsert_mock(&mock, 0).await;
         let update_id = album.upsert_mock(&mock, 0).await;
         assert_eq!(update_id, id);
+
+        // The release information is not a part of the identity so it is updated in place.
+        let update_album = Album { release: Faker.fake(), ..album };
+        let update_id = update_album.upsert_mock(&mock, 0).await;
+        assert_eq!(update_id, id);
+        assert_eq!(Album::query(&mock, id).await, update_album);
     }
 
     #[rstest]
@@ -37,6 +37,27 @@ mod test {
                     sample_rate: 44100,
                     channel_count: 2,
                 },
+                audio::Format::Vorbis => Self {
+                    duration: Duration::default(),
+                    bitrate: 150,
+                    bit_depth: None,
+                    sample_rate: 32000,
+                    channel_count: 2,
+                },
+                audio::Format::Opus => Self {
+                    duration: Duration::default(),
+                    bitrate: 65,
+                    bit_depth: None,
+                    sample_rate: 32000,
+                    channel_count: 2,
+                },
+                audio::Format::Mp4 => Self {
+                    duration: Duration::default(),
+                    bitrate: 128,
+                    bit_depth: None,
+                    sample_rate: 32000,
+                    channel_count: 2,
+                },
             }
         }
     }
@@ -0,0 +1,110 @@
+use std::borrow::Cow;
+
+#[cfg(test)]
+use fake::{Dummy, Fake, Faker};
+use itertools::Itertools;
+use o2o::o2o;
+
+use crate::orm::albums;
+
+#[allow(clippy::struct_field_names)]
+#[derive(Debug, Default, o2o)]
+#[map_owned(albums::release::Release<'a>)]
+#[ref_into(albums::release::Release<'a>)]
+#[cfg_attr(test, derive(PartialEq, Eq, Dummy, Clone))]
+pub struct Release<'a> {
+    #[ref_into(~.iter().map(|release_type| release_type.as_ref().into()).collect())]
+    #[cfg_attr(test, dummy(expr = "Release::fake_release_types()"))]
+    pub release_types: Vec<Cow<'a, str>>,
+    #[ref_into(~.iter().map(|record_label| record_label.as_ref().into()).collect())]
+    #[cfg_attr(
+        test,
+        dummy(expr = "fake::vec![String; 0..=2].into_iter().map(Cow::Owned).collect()")
+    )]
+    pub record_labels: Vec<Cow<'a, str>>,
+    #[ref_into(~.as_deref().map(Cow::Borrowed))]
+    #[cfg_attr(test, dummy(expr = "Faker.fake::<Option<String>>().map(Cow::Owned)"))]
+    pub catalog_number: Option<Cow<'a, str>>,
+    #[ref_into(~.as_deref().map(Cow::Borrowed))]
+    #[cfg_attr(test, dummy(expr = "Faker.fake::<Option<String>>().map(Cow::Owned)"))]
+    pub barcode: Option<Cow<'a, str>>,
+}
+
+impl<'a> Release<'a> {
+    fn parse_value(value: &'a str) -> Option<&'a str> {
+        Some(value.trim()).filter(|value| !value.is_empty())
+    }
+
+    pub fn parse(
+        release_types: impl IntoIterator<Item = &'a str>,
+        record_labels: impl IntoIterator<Item = &'a str>,
+        catalog_number: Option<&'a str>,
+        barcode: Option<&'a str>,
+    ) -> Self {
+        Self {
+            // Release types are compared case-insensitively, e.g. `EP` and `ep` are the same.
+            release_types: release_types
+                .into_iter()
+                .filter_map(Self::parse_value)
+                .map(|release_type| {
+                    if release_type.chars().any(char::is_uppercase) {
+                        release_type.to_lowercase().into()
+                    } else {
+                        release_type.into()
+                    }
+                })
+                .unique()
+                .collect(),
+            record_labels: record_labels
+                .into_iter()
+                .filter_map(Self::parse_value)
+                .unique()
+                .map(Cow::Borrowed)
+                .collect(),
+            catalog_number: catalog_number.and_then(Self::parse_value).map(Cow::Borrowed),
+            barcode: barcode.and_then(Self::parse_value).map(Cow::Borrowed),
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod test {
+    use super::*;
+
+    impl Release<'_> {
+        pub fn fake_release_types() -> Vec<Cow<'static, str>> {
+            ["album", "ep", "single", "live", "compilation", "soundtrack"]
+                .into_iter()
+                .filter(|_| (0..3).fake::<u8>() == 0)
+                .map(Cow::Borrowed)
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(&[], &[])]
+    #[case(&["album"], &["album"])]
+    #[case(&["Album", " live ", ""], &["album", "live"])]
+    #[case(&["EP", "ep"], &["ep"])]
+    fn test_parse_release_types(#[case] values: &[&str], #[case] expected: &[&str]) {
+        assert_eq!(Release::parse(values.iter().copied(), [], None, None).release_types, expected);
+    }
+
+    #[test]
+    fn test_parse_record_labels() {
+        let release =
+            Release::parse([], ["Label", " Label ", "Other", ""], Some(" "), Some("0123"));
+        assert_eq!(release.record_labels, ["Label", "Other"]);
+        assert_eq!(release.catalog_number, None);
+        assert_eq!(release.barcode.as_deref(), Some("0123"));
+    }
+}
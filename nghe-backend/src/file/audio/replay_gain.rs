@@ -0,0 +1,215 @@
+use nghe_api::id3;
+use nghe_api::media_retrieval::stream;
+use o2o::o2o;
+
+use super::transcode;
+use crate::orm::songs;
+
+#[derive(Debug, Default, Clone, Copy, o2o)]
+#[map_owned(songs::replay_gain::ReplayGain)]
+#[cfg_attr(test, derive(educe::Educe, fake::Dummy))]
+#[cfg_attr(test, educe(PartialEq, Eq))]
+pub struct ReplayGain {
+    #[cfg_attr(test, dummy(expr = "ReplayGain::fake_gain()"))]
+    pub track_gain: Option<f32>,
+    #[cfg_attr(test, dummy(expr = "ReplayGain::fake_peak()"))]
+    pub track_peak: Option<f32>,
+    #[cfg_attr(test, dummy(expr = "ReplayGain::fake_gain()"))]
+    pub album_gain: Option<f32>,
+    #[cfg_attr(test, dummy(expr = "ReplayGain::fake_peak()"))]
+    pub album_peak: Option<f32>,
+}
+
+impl ReplayGain {
+    fn parse_value(value: Option<&str>) -> Option<f32> {
+        value.and_then(|value| {
+            // Gain values are usually written as `-6.54 dB` while peak values have no unit.
+            let number = value.trim();
+            let number = ["dB", "db", "DB"]
+                .into_iter()
+                .find_map(|unit| number.strip_suffix(unit))
+                .unwrap_or(number)
+                .trim_end();
+            let number = number.parse::<f32>().ok().filter(|number| number.is_finite());
+            // A malformed value should not prevent the song from being scanned, it is treated as
+            // missing and can be filled from the measured loudness instead.
+            if number.is_none() {
+                tracing::warn!(invalid_replay_gain = value);
+            }
+            number
+        })
+    }
+
+    pub fn parse(
+        track_gain: Option<&str>,
+        track_peak: Option<&str>,
+        album_gain: Option<&str>,
+        album_peak: Option<&str>,
+    ) -> Self {
+        Self {
+            track_gain: Self::parse_value(track_gain),
+            track_peak: Self::parse_value(track_peak),
+            album_gain: Self::parse_value(album_gain),
+            album_peak: Self::parse_value(album_peak),
+        }
+    }
+
+    // Missing values are filled from the loudness measured while scanning.
+    pub fn or_loudness(self, loudness: songs::loudness::Loudness) -> Self {
+        let track = loudness.track();
+        let album = loudness.album();
+        Self {
+            track_gain: self.track_gain.or_else(|| track.map(transcode::Loudness::gain)),
+            track_peak: self.track_peak.or_else(|| track.map(|track| track.peak)),
+            album_gain: self.album_gain.or_else(|| album.map(transcode::Loudness::gain)),
+            album_peak: self.album_peak.or_else(|| album.map(|album| album.peak)),
+        }
+    }
+
+    // Returns the volume adjustment in dB for the requested mode, falling back to the other gain
+    // if the requested one is missing. The adjustment is lowered if it would clip the peak.
+    pub fn volume(&self, mode: stream::ReplayGain) -> Option<f32> {
+        let track = (self.track_gain, self.track_peak);
+        let album = (self.album_gain, self.album_peak);
+        let (gain, peak) = match mode {
+            stream::ReplayGain::Track if track.0.is_some() => track,
+            stream::ReplayGain::Album if album.0.is_none() => track,
+            _ => album,
+        };
+        gain.map(|gain| {
+            if let Some(peak) = peak
+                && peak > 0.0
+            {
+                gain.min(-20.0 * peak.log10())
+            } else {
+                gain
+            }
+        })
+    }
+}
+
+impl From<ReplayGain> for Option<id3::song::ReplayGain> {
+    fn from(value: ReplayGain) -> Self {
+        let ReplayGain { track_gain, track_peak, album_gain, album_peak } = value;
+        if track_gain.is_none()
+            && track_peak.is_none()
+            && album_gain.is_none()
+            && album_peak.is_none()
+        {
+            None
+        } else {
+            Some(id3::song::ReplayGain { track_gain, album_gain, track_peak, album_peak })
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod test {
+    use fake::Fake;
+
+    use super::*;
+
+    impl ReplayGain {
+        // Keep two decimal digits so the values survive a roundtrip through text tags.
+        pub fn fake_gain() -> Option<f32> {
+            fake::Faker.fake::<bool>().then(|| f32::from((-2000_i16..1000).fake::<i16>()) / 100.0)
+        }
+
+        pub fn fake_peak() -> Option<f32> {
+            fake::Faker.fake::<bool>().then(|| f32::from((0_u16..20000).fake::<u16>()) / 10000.0)
+        }
+
+        pub fn format_gain(gain: f32) -> String {
+            format!("{gain} dB")
+        }
+
+        pub fn format_peak(peak: f32) -> String {
+            peak.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(None, None)]
+    #[case(Some("-6.54 dB"), Some(-6.54))]
+    #[case(Some("+1.20 dB"), Some(1.2))]
+    #[case(Some("  -0.5dB "), Some(-0.5))]
+    #[case(Some("3.02 db"), Some(3.02))]
+    #[case(Some("-7"), Some(-7.0))]
+    fn test_parse_gain(#[case] value: Option<&str>, #[case] expected: Option<f32>) {
+        assert_eq!(ReplayGain::parse(value, None, None, None).track_gain, expected);
+    }
+
+    #[rstest]
+    #[case("0.988")]
+    #[case("1")]
+    fn test_parse_peak(#[case] value: &str) {
+        assert_eq!(
+            ReplayGain::parse(None, Some(value), None, None).track_peak,
+            Some(value.parse().unwrap())
+        );
+    }
+
+    #[rstest]
+    #[case("loud")]
+    #[case("dB")]
+    #[case("NaN")]
+    fn test_parse_invalid(#[case] value: &str) {
+        let replay_gain = ReplayGain::parse(Some(value), None, Some("-6.54 dB"), None);
+        assert_eq!(replay_gain.track_gain, None);
+        assert_eq!(replay_gain.album_gain, Some(-6.54));
+    }
+
+    #[test]
+    fn test_or_loudness() {
+        let loudness = songs::loudness::Loudness {
+            track_integrated: Some(-10.0),
+            track_peak: Some(0.9),
+            album_integrated: Some(-12.0),
+            album_peak: None,
+        };
+        let replay_gain = ReplayGain {
+            track_gain: None,
+            track_peak: Some(0.5),
+            album_gain: None,
+            album_peak: None,
+        }
+        .or_loudness(loudness);
+        assert_eq!(replay_gain.track_gain, Some(-8.0));
+        assert_eq!(replay_gain.track_peak, Some(0.5));
+        // The album loudness is only used if both its integrated loudness and peak are measured.
+        assert_eq!(replay_gain.album_gain, None);
+        assert_eq!(replay_gain.album_peak, None);
+    }
+
+    #[rstest]
+    #[case(stream::ReplayGain::Track, Some(-6.0), None, Some(-8.0), Some(-6.0))]
+    #[case(stream::ReplayGain::Album, Some(-6.0), None, Some(-8.0), Some(-8.0))]
+    #[case(stream::ReplayGain::Track, None, None, Some(-8.0), Some(-8.0))]
+    #[case(stream::ReplayGain::Album, Some(-6.0), None, None, Some(-6.0))]
+    #[case(stream::ReplayGain::Album, None, None, None, None)]
+    // A peak of 0.5 only leaves around 6.02 dB of headroom.
+    #[case(stream::ReplayGain::Track, Some(10.0), Some(0.5), None, Some(6.0206))]
+    fn test_volume(
+        #[case] mode: stream::ReplayGain,
+        #[case] track_gain: Option<f32>,
+        #[case] track_peak: Option<f32>,
+        #[case] album_gain: Option<f32>,
+        #[case] expected: Option<f32>,
+    ) {
+        let volume =
+            ReplayGain { track_gain, track_peak, album_gain, album_peak: None }.volume(mode);
+        assert_eq!(volume.is_some(), expected.is_some());
+        if let Some(volume) = volume {
+            assert!((volume - expected.unwrap()).abs() < 1e-3);
+        }
+    }
+}
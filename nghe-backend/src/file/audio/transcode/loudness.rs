@@ -0,0 +1,186 @@
+use std::ffi::{CStr, CString};
+use std::ptr::NonNull;
+
+use rsmpeg::avfilter::{AVFilter, AVFilterContextMut, AVFilterGraph, AVFilterInOut};
+use rsmpeg::avutil::{AVDictionaryRef, AVFrame};
+use rsmpeg::error::RsmpegError;
+use tracing::instrument;
+
+use super::transcoder::Input;
+use crate::{Error, error};
+
+// The reference loudness of ReplayGain 2.0 in LUFS.
+pub const REFERENCE: f32 = -18.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Loudness {
+    // Integrated loudness in LUFS.
+    pub integrated: f32,
+    // Linear true peak.
+    pub peak: f32,
+}
+
+struct Filter<'a> {
+    source: AVFilterContextMut<'a>,
+    sink: AVFilterContextMut<'a>,
+}
+
+impl Filter<'_> {
+    fn metadata(frame: &AVFrame, key: &CStr) -> Result<Option<f32>, Error> {
+        // Safety: the metadata dictionary is owned by the frame.
+        let Some(metadata) = NonNull::new(frame.metadata)
+            .map(|metadata| unsafe { AVDictionaryRef::from_raw(metadata) })
+        else {
+            return Ok(None);
+        };
+        metadata.get(key, None, 0).map(|entry| Ok(entry.value().to_str()?.parse()?)).transpose()
+    }
+
+    fn filter(
+        &mut self,
+        frame: Option<AVFrame>,
+        loudness: &mut Option<Loudness>,
+    ) -> Result<(), Error> {
+        self.source.buffersrc_add_frame(frame, None)?;
+
+        loop {
+            let frame = match self.sink.buffersink_get_frame(None) {
+                Err(RsmpegError::BufferSinkDrainError | RsmpegError::BufferSinkEofError) => {
+                    break Ok(());
+                }
+                result => result?,
+            };
+            // Each frame carries the values measured so far, the last one is the final result.
+            if let Some(integrated) = Self::metadata(&frame, c"lavfi.r128.I")?
+                && let Some(peak) = Self::metadata(&frame, c"lavfi.r128.true_peak")?
+            {
+                *loudness = Some(Loudness { integrated, peak });
+            }
+        }
+    }
+}
+
+impl Loudness {
+    #[cfg_attr(
+        not(coverage_nightly),
+        instrument(skip_all, ret(level = "debug"), err(Debug, level = "debug"))
+    )]
+    pub fn measure(input: &CStr) -> Result<Self, Error> {
+        let mut input = Input::new(input)?;
+
+        let source_ref = AVFilter::get_by_name(c"abuffer")
+            .ok_or_else(|| error::Kind::MissingAVFilter("abuffer"))?;
+        let sink_ref = AVFilter::get_by_name(c"abuffersink")
+            .ok_or_else(|| error::Kind::MissingAVFilter("abuffersink"))?;
+
+        let graph = AVFilterGraph::new();
+        let source_arg = Input::source_arg(&input.decoder)?;
+        let mut source = graph.create_filter_context(&source_ref, c"in", Some(&source_arg))?;
+        let mut sink = graph.create_filter_context(&sink_ref, c"out", None)?;
+
+        // Yes. The output name is in.
+        let outputs = AVFilterInOut::new(c"in", &mut source, 0);
+        let inputs = AVFilterInOut::new(c"out", &mut sink, 0);
+        graph.parse_ptr(
+            &CString::new("ebur128=metadata=1:peak=true:framelog=quiet")?,
+            Some(inputs),
+            Some(outputs),
+        )?;
+        graph.config()?;
+
+        let mut filter = Filter { source, sink };
+        let mut loudness = None;
+
+        loop {
+            let packet = input.context.read_packet()?;
+
+            // Ignore non audio stream packets.
+            if packet.as_ref().is_some_and(|p| p.stream_index != input.index) {
+                continue;
+            }
+
+            input.decoder.send_packet(packet.as_ref())?;
+
+            // If packet is none, we are at input EOF.
+            if packet.is_none() {
+                break;
+            }
+
+            loop {
+                let frame = match input.decoder.receive_frame() {
+                    Err(RsmpegError::DecoderDrainError | RsmpegError::DecoderFlushedError) => {
+                        break;
+                    }
+                    result => result?,
+                };
+                filter.filter(Some(frame), &mut loudness)?;
+            }
+        }
+
+        // Flush the filter graph by pushing none packet to its source.
+        filter.filter(None, &mut loudness)?;
+
+        Ok(loudness.ok_or_else(|| error::Kind::MissingLoudnessMeasurement)?)
+    }
+
+    // The loudness of an album is approximated by the energy average of its songs weighted by
+    // their durations, its peak is the highest peak of its songs.
+    pub fn album(songs: impl IntoIterator<Item = (f32, Self)>) -> Option<Self> {
+        let (duration, energy, peak) = songs.into_iter().fold(
+            (0.0_f64, 0.0_f64, 0.0_f32),
+            |(duration, energy, peak), (song_duration, loudness)| {
+                let song_duration = f64::from(song_duration);
+                (
+                    duration + song_duration,
+                    energy + song_duration * 10_f64.powf(f64::from(loudness.integrated) / 10.0),
+                    peak.max(loudness.peak),
+                )
+            },
+        );
+        if duration > 0.0 && energy > 0.0 {
+            #[allow(clippy::cast_possible_truncation)]
+            let integrated = (10.0 * (energy / duration).log10()) as f32;
+            Some(Self { integrated, peak })
+        } else {
+            None
+        }
+    }
+
+    pub fn gain(self) -> f32 {
+        REFERENCE - self.integrated
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&[(10.0, -10.0, 0.5)], Some((-10.0, 0.5)))]
+    #[case(&[(10.0, -10.0, 0.5), (10.0, -10.0, 0.8)], Some((-10.0, 0.8)))]
+    // Equal energy contribution: 10^-1 and 10^-2 averaged gives around -12.6 LUFS.
+    #[case(&[(10.0, -10.0, 0.5), (10.0, -20.0, 0.2)], Some((-12.596, 0.5)))]
+    // A longer song weights more.
+    #[case(&[(30.0, -10.0, 0.5), (10.0, -20.0, 0.2)], Some((-11.107, 0.5)))]
+    #[case(&[(0.0, -10.0, 0.5)], None)]
+    fn test_album(#[case] songs: &[(f32, f32, f32)], #[case] expected: Option<(f32, f32)>) {
+        let album = Loudness::album(songs.iter().map(|(duration, integrated, peak)| {
+            (*duration, Loudness { integrated: *integrated, peak: *peak })
+        }));
+        assert_eq!(album.is_some(), expected.is_some());
+        if let Some(album) = album {
+            let (integrated, peak) = expected.unwrap();
+            assert!((album.integrated - integrated).abs() < 1e-3);
+            assert!((album.peak - peak).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_gain() {
+        assert!((Loudness { integrated: -9.5, peak: 1.0 }.gain() - -8.5).abs() < f32::EPSILON);
+    }
+}
@@ -1,7 +1,9 @@
 mod format;
+mod loudness;
 mod sink;
 mod transcoder;
 
+pub use loudness::Loudness;
 pub use sink::Sink;
 pub use transcoder::Transcoder;
 use typed_path::Utf8PlatformPathBuf;
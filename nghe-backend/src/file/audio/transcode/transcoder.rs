@@ -15,10 +15,10 @@ use tracing::instrument;
 use super::{Path, Sink};
 use crate::{Error, config, error};
 
-struct Input {
-    context: AVFormatContextInput,
-    decoder: AVCodecContext,
-    index: i32,
+pub(super) struct Input {
+    pub context: AVFormatContextInput,
+    pub decoder: AVCodecContext,
+    pub index: i32,
 }
 
 struct Output {
@@ -43,7 +43,7 @@ pub struct Transcoder {
 }
 
 impl Input {
-    fn new(input: &CStr) -> Result<Self, Error> {
+    pub fn new(input: &CStr) -> Result<Self, Error> {
         let context = AVFormatContextInput::builder().url(input).open()?;
         let (index, codec) = context
             .find_best_stream(ffi::AVMEDIA_TYPE_AUDIO)?
@@ -58,6 +58,25 @@ impl Input {
 
         Ok(Self { context, decoder, index: index.try_into()? })
     }
+
+    // Arguments of the `abuffer` filter that receives decoded frames.
+    pub fn source_arg(decoder: &AVCodecContext) -> Result<CString, Error> {
+        let source_arg = concat_string!(
+            "time_base=",
+            decoder.pkt_timebase.num.to_string(),
+            "/",
+            decoder.pkt_timebase.den.to_string(),
+            ":sample_rate=",
+            decoder.sample_rate.to_string(),
+            ":sample_fmt=",
+            avutil::get_sample_fmt_name(decoder.sample_fmt)
+                .ok_or_else(|| error::Kind::MissingSampleFmtName(decoder.sample_fmt))?
+                .to_str()?,
+            ":channel_layout=",
+            decoder.ch_layout().describe()?.to_str()?
+        );
+        Ok(CString::new(source_arg)?)
+    }
 }
 
 impl Output {
@@ -138,11 +157,19 @@ impl Output {
 }
 
 impl Graph {
-    fn new(decoder: &AVCodecContext, encoder: &AVCodecContext, offset: u32) -> Result<Self, Error> {
+    fn new(
+        decoder: &AVCodecContext,
+        encoder: &AVCodecContext,
+        offset: u32,
+        volume: Option<f32>,
+    ) -> Result<Self, Error> {
         let mut specs: Vec<Cow<'static, str>> = vec![];
         if offset > 0 {
             specs.push(concat_string!("atrim=start=", offset.to_string()).into());
         }
+        if let Some(volume) = volume {
+            specs.push(concat_string!("volume=volume=", volume.to_string(), "dB").into());
+        }
         if decoder.sample_rate != encoder.sample_rate {
             specs.push("aresample=resampler=soxr".into());
         }
@@ -170,21 +197,7 @@ impl<'graph> Filter<'graph> {
         let sink_ref = AVFilter::get_by_name(c"abuffersink")
             .ok_or_else(|| error::Kind::MissingAVFilter("abuffersink"))?;
 
-        let source_arg = concat_string!(
-            "time_base=",
-            decoder.pkt_timebase.num.to_string(),
-            "/",
-            decoder.pkt_timebase.den.to_string(),
-            ":sample_rate=",
-            decoder.sample_rate.to_string(),
-            ":sample_fmt=",
-            avutil::get_sample_fmt_name(decoder.sample_fmt)
-                .ok_or_else(|| error::Kind::MissingSampleFmtName(decoder.sample_fmt))?
-                .to_str()?,
-            ":channel_layout=",
-            decoder.ch_layout().describe()?.to_str()?
-        );
-        let source_arg = CString::new(source_arg)?;
+        let source_arg = Input::source_arg(decoder)?;
         let mut source =
             graph.filter.create_filter_context(&source_ref, c"in", Some(&source_arg))?;
 
@@ -236,6 +249,7 @@ impl Transcoder {
         format: nghe_api::common::format::Transcode,
         bitrate: u32,
         offset: u32,
+        volume: Option<f32>,
     ) -> (Receiver<Vec<u8>>, tokio::task::JoinHandle<Result<(), Error>>) {
         let (tx, rx) = crate::sync::channel(config.channel_size);
         let buffer_size = config.buffer_size;
@@ -248,7 +262,8 @@ impl Transcoder {
             let file = atomic_file.as_ref().map(|file| file.as_file().try_clone()).transpose()?;
             let sink = Sink { tx, buffer_size, format, file };
 
-            let mut transcoder = Self::new(&CString::new(path.input)?, sink, bitrate, offset)?;
+            let mut transcoder =
+                Self::new(&CString::new(path.input)?, sink, bitrate, offset, volume)?;
             transcoder.transcode()?;
             atomic_file.map(AtomicWriteFile::commit).transpose()?;
             Ok(())
@@ -257,10 +272,16 @@ impl Transcoder {
         (rx, handle)
     }
 
-    fn new(input: &CStr, sink: Sink, bitrate: u32, offset: u32) -> Result<Self, Error> {
+    fn new(
+        input: &CStr,
+        sink: Sink,
+        bitrate: u32,
+        offset: u32,
+        volume: Option<f32>,
+    ) -> Result<Self, Error> {
         let input = Input::new(input)?;
         let output = Output::new(sink, bitrate, &input.decoder)?;
-        let graph = Graph::new(&input.decoder, &output.encoder, offset)?;
+        let graph = Graph::new(&input.decoder, &output.encoder, offset, volume)?;
         Ok(Self { input, output, graph })
     }
 
@@ -324,6 +345,7 @@ mod test {
             format: format::Transcode,
             bitrate: u32,
             offset: u32,
+            volume: Option<f32>,
         ) -> Vec<u8> {
             let (rx, handle) = Transcoder::spawn(
                 config,
@@ -331,6 +353,7 @@ mod test {
                 format,
                 bitrate,
                 offset,
+                volume,
             );
             let data = rx.into_stream().map(stream::iter).flatten().collect().await;
             handle.await.unwrap().unwrap();
@@ -360,7 +383,7 @@ mod tests {
     ) {
         let input = env!("NGHE_HEARING_TEST_INPUT");
         let config = config::Transcode::default();
-        let data = Transcoder::spawn_collect(&config, input, format, bitrate, offset).await;
+        let data = Transcoder::spawn_collect(&config, input, format, bitrate, offset, None).await;
 
         tokio::fs::write(
             Utf8PlatformPath::new(env!("NGHE_HEARING_TEST_OUTPUT"))
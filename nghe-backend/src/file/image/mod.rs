@@ -72,6 +72,20 @@ impl TryFrom<&MimeType> for Format {
     }
 }
 
+impl Format {
+    fn guess(data: &[u8]) -> Result<Self, Error> {
+        match image::guess_format(data) {
+            Ok(image::ImageFormat::Png) => Ok(Self::Png),
+            Ok(image::ImageFormat::Jpeg) => Ok(Self::Jpeg),
+            Ok(image::ImageFormat::WebP) => Ok(Self::WebP),
+            Ok(format) => {
+                error::Kind::UnsupportedImageFormat(format.to_mime_type().to_owned()).into()
+            }
+            Err(_) => error::Kind::MissingImageFormat.into(),
+        }
+    }
+}
+
 impl format::Trait for Format {
     const CACHE_CONTROL: format::CacheControl =
         format::CacheControl { duration: std::time::Duration::from_days(365), immutable: true };
@@ -108,10 +122,14 @@ impl<'d> TryFrom<&'d LoftyPicture> for Image<'d> {
     type Error = Error;
 
     fn try_from(value: &'d LoftyPicture) -> Result<Self, Self::Error> {
-        Image::new(
-            value.mime_type().ok_or_else(|| error::Kind::MissingImageFormat)?.try_into()?,
-            value.data(),
-        )
+        let format = if let Some(mime_type) = value.mime_type() {
+            mime_type.try_into()?
+        } else {
+            // Pictures without any mime type (e.g. implicit `covr` atoms inside MP4 files) need to
+            // be guessed from their content.
+            Format::guess(value.data())?
+        };
+        Image::new(format, value.data())
     }
 }
 
@@ -1,6 +1,7 @@
 pub mod audio;
 pub mod image;
 pub mod lyric;
+pub mod playlist;
 
 use std::num::{NonZero, NonZeroU32, NonZeroU64};
 
@@ -0,0 +1,103 @@
+use concat_string::concat_string;
+
+use super::{Entry, Track};
+
+const HEADER: &str = "#EXTM3U";
+const EXTINF: &str = "#EXTINF:";
+const PLAYLIST: &str = "#PLAYLIST:";
+
+pub fn parse(content: &str) -> Vec<Entry<'_>> {
+    let mut entries = vec![];
+    let mut name = None;
+    for line in content.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if let Some(extinf) = line.strip_prefix(EXTINF) {
+            // `#EXTINF:<duration>,<artist> - <title>`.
+            name = extinf.split_once(',').map(|(_, name)| name);
+        } else if !line.starts_with('#') {
+            entries.push(if name.is_some() {
+                Entry::new(line, name.take())
+            } else {
+                Entry::from_file_name(line)
+            });
+        }
+    }
+    entries
+}
+
+pub fn render(name: &str, tracks: &[Track<'_>]) -> String {
+    let mut content = concat_string!(HEADER, "\n", PLAYLIST, name, "\n");
+    for track in tracks {
+        content.push_str(&concat_string!(
+            EXTINF,
+            track.duration.whole_seconds().to_string(),
+            ",",
+            track.artist,
+            " - ",
+            track.title,
+            "\n",
+            track.path,
+            "\n"
+        ));
+    }
+    content
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let tracks = [
+            Track {
+                path: "artist1/album1/song1.flac",
+                title: "title1",
+                artist: "artist1",
+                album: "album1",
+                duration: time::Duration::seconds(100),
+            },
+            Track {
+                path: "artist2/album2/song2.mp3",
+                title: "title2",
+                artist: "artist2",
+                album: "album2",
+                duration: time::Duration::seconds(200),
+            },
+        ];
+        let content = render("playlist", &tracks);
+        assert_eq!(
+            content,
+            "#EXTM3U\n#PLAYLIST:playlist\n#EXTINF:100,artist1 - \
+             title1\nartist1/album1/song1.flac\n#EXTINF:200,artist2 - \
+             title2\nartist2/album2/song2.mp3\n"
+        );
+        assert_eq!(
+            parse(&content),
+            vec![
+                Entry {
+                    location: "artist1/album1/song1.flac".into(),
+                    artist: Some("artist1".into()),
+                    title: Some("title1".into()),
+                },
+                Entry {
+                    location: "artist2/album2/song2.mp3".into(),
+                    artist: Some("artist2".into()),
+                    title: Some("title2".into()),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_without_extinf() {
+        assert_eq!(
+            parse("# comment\r\n\r\nartist/album/artist - title.flac\r\n"),
+            vec![Entry {
+                location: "artist/album/artist - title.flac".into(),
+                artist: Some("artist".into()),
+                title: Some("title".into()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,156 @@
+mod m3u;
+mod xspf;
+
+use std::borrow::Cow;
+
+use nghe_api::playlists::export_playlist::Format;
+use percent_encoding::{AsciiSet, CONTROLS, percent_decode_str};
+
+use crate::Error;
+
+pub const FILE_SCHEME: &str = "file://";
+// Characters that are not allowed inside a path segment of an uri.
+const SEGMENT: &AsciiSet =
+    &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'%').add(b'<').add(b'>').add(b'?').add(b'`');
+
+#[derive(Debug, Default)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub struct Entry<'a> {
+    pub location: Cow<'a, str>,
+    pub artist: Option<Cow<'a, str>>,
+    pub title: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug)]
+pub struct Track<'a> {
+    pub path: &'a str,
+    pub title: &'a str,
+    pub artist: &'a str,
+    pub album: &'a str,
+    pub duration: time::Duration,
+}
+
+impl<'a> Entry<'a> {
+    fn new(location: impl Into<Cow<'a, str>>, name: Option<&'a str>) -> Self {
+        let location = location.into();
+        // Fallback to the file name if there is no other information about the song.
+        let (artist, title) = match name.and_then(|name| name.split_once(" - ")) {
+            Some((artist, title)) => (Some(artist.trim().into()), Some(title.trim().into())),
+            None => (None, name.map(|name| name.trim().into())),
+        };
+        Self { location, artist, title }
+    }
+
+    fn from_file_name(location: &'a str) -> Self {
+        let name = location
+            .rsplit(['/', '\\'])
+            .next()
+            .map(|name| name.rsplit_once('.').map_or(name, |(stem, _)| stem));
+        Self::new(location, name)
+    }
+
+    // Absolute paths outside of the music folder and remote locations do not have one.
+    pub fn relative_path(&self, music_folder_path: &str) -> Option<String> {
+        let location = if let Some(location) = self.location.strip_prefix(FILE_SCHEME) {
+            percent_decode_str(location).decode_utf8().ok()?
+        } else if self.location.contains("://") {
+            return None;
+        } else {
+            self.location.as_ref().into()
+        };
+        let location = location.replace('\\', "/");
+        let music_folder_path = music_folder_path.replace('\\', "/");
+
+        let location = if let Some(location) =
+            location.strip_prefix(music_folder_path.trim_end_matches('/'))
+            && location.starts_with('/')
+        {
+            location
+        } else if location.starts_with('/') || location.chars().nth(1) == Some(':') {
+            return None;
+        } else {
+            &location
+        };
+
+        // Playlist files usually live inside the music folder so `.` and `..` are stripped.
+        let segments: Vec<_> =
+            location.split('/').filter(|segment| !matches!(*segment, "" | "." | "..")).collect();
+        if segments.is_empty() { None } else { Some(segments.join("/")) }
+    }
+}
+
+impl Track<'_> {
+    fn location(&self) -> String {
+        self.path
+            .split('/')
+            .map(|segment| percent_encoding::utf8_percent_encode(segment, SEGMENT).to_string())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+pub fn parse(content: &str) -> Result<Vec<Entry<'_>>, Error> {
+    let content = content.trim_start_matches('\u{feff}').trim_start();
+    if content.starts_with('<') { xspf::parse(content) } else { Ok(m3u::parse(content)) }
+}
+
+pub fn render(format: Format, name: &str, tracks: &[Track<'_>]) -> Result<String, Error> {
+    match format {
+        Format::M3u8 => Ok(m3u::render(name, tracks)),
+        Format::Xspf => xspf::render(name, tracks),
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("artist/album/song.flac", "/music", Some("artist/album/song.flac"))]
+    #[case("./artist/album/song.flac", "/music", Some("artist/album/song.flac"))]
+    #[case("../artist/album/song.flac", "/music", Some("artist/album/song.flac"))]
+    #[case("..\\artist\\album\\song.flac", "/music", Some("artist/album/song.flac"))]
+    #[case("/music/artist/album/song.flac", "/music/", Some("artist/album/song.flac"))]
+    #[case("file:///music/artist/album/song%20a.flac", "/music", Some("artist/album/song a.flac"))]
+    #[case("/musical/artist/album/song.flac", "/music", None)]
+    #[case("C:\\music\\song.flac", "/music", None)]
+    #[case("http://example.com/song.flac", "/music", None)]
+    fn test_relative_path(
+        #[case] location: &str,
+        #[case] music_folder_path: &str,
+        #[case] relative_path: Option<&str>,
+    ) {
+        assert_eq!(
+            Entry::from_file_name(location).relative_path(music_folder_path).as_deref(),
+            relative_path
+        );
+    }
+
+    #[rstest]
+    #[case("artist/album/artist - title.flac", Some("artist"), Some("title"))]
+    #[case("artist/album/title.flac", None, Some("title"))]
+    fn test_from_file_name(
+        #[case] location: &str,
+        #[case] artist: Option<&str>,
+        #[case] title: Option<&str>,
+    ) {
+        let entry = Entry::from_file_name(location);
+        assert_eq!(entry.artist.as_deref(), artist);
+        assert_eq!(entry.title.as_deref(), title);
+    }
+
+    #[test]
+    fn test_location() {
+        let track = Track {
+            path: "artist/album #1/song?.flac",
+            title: "",
+            artist: "",
+            album: "",
+            duration: time::Duration::ZERO,
+        };
+        assert_eq!(track.location(), "artist/album%20%231/song%3F.flac");
+    }
+}
@@ -0,0 +1,200 @@
+use concat_string::concat_string;
+use percent_encoding::percent_decode_str;
+use serde::{Deserialize, Serialize};
+
+use super::{Entry, Track};
+use crate::{Error, error};
+
+const DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8"?>"#;
+const NAMESPACE: &str = "http://xspf.org/ns/0/";
+const ROOT: &str = "playlist";
+
+#[derive(Deserialize)]
+struct InputTrack {
+    location: Option<String>,
+    creator: Option<String>,
+    title: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+struct InputTrackList {
+    #[serde(default)]
+    track: Vec<InputTrack>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Input {
+    #[serde(default)]
+    track_list: InputTrackList,
+}
+
+#[derive(Serialize)]
+struct OutputTrack<'a> {
+    location: String,
+    title: &'a str,
+    creator: &'a str,
+    album: &'a str,
+    duration: i64,
+}
+
+#[derive(Serialize)]
+struct OutputTrackList<'a> {
+    track: Vec<OutputTrack<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Playlist<'a> {
+    #[serde(rename = "@version")]
+    version: u8,
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    title: &'a str,
+    track_list: OutputTrackList<'a>,
+}
+
+pub fn parse(content: &str) -> Result<Vec<Entry<'static>>, Error> {
+    let input: Input =
+        quick_xml::de::from_str(content).map_err(|_| error::Kind::InvalidPlaylistContent)?;
+    Ok(input
+        .track_list
+        .track
+        .into_iter()
+        .filter_map(|track| {
+            // Track `location` values are URIs so they need to be decoded, except the `file` ones
+            // which are handled later together with the absolute paths.
+            let location = track.location.map(|location| {
+                let location = location.trim();
+                if location.starts_with(super::FILE_SCHEME) {
+                    location.to_owned()
+                } else {
+                    percent_decode_str(location).decode_utf8_lossy().into_owned()
+                }
+            });
+
+            if let Some(title) = track.title {
+                Some(Entry {
+                    location: location
+                        .unwrap_or_else(|| {
+                            track.creator.as_ref().map_or_else(
+                                || title.clone(),
+                                |creator| concat_string!(creator, " - ", title),
+                            )
+                        })
+                        .into(),
+                    artist: track.creator.map(Into::into),
+                    title: Some(title.into()),
+                })
+            } else {
+                location.map(|location| {
+                    let (artist, title) = {
+                        let name = percent_decode_str(&location).decode_utf8_lossy();
+                        let entry = Entry::from_file_name(&name);
+                        (
+                            entry.artist.map(|artist| artist.into_owned().into()),
+                            entry.title.map(|title| title.into_owned().into()),
+                        )
+                    };
+                    Entry { location: location.into(), artist, title }
+                })
+            }
+        })
+        .collect())
+}
+
+pub fn render(name: &str, tracks: &[Track<'_>]) -> Result<String, Error> {
+    let playlist = Playlist {
+        version: 1,
+        xmlns: NAMESPACE,
+        title: name,
+        track_list: OutputTrackList {
+            track: tracks
+                .iter()
+                .map(|track| {
+                    Ok::<_, Error>(OutputTrack {
+                        location: track.location(),
+                        title: track.title,
+                        creator: track.artist,
+                        album: track.album,
+                        duration: track.duration.whole_milliseconds().try_into()?,
+                    })
+                })
+                .try_collect()?,
+        },
+    };
+    let content = quick_xml::se::to_string_with_root(ROOT, &playlist)
+        .map_err(|_| error::Kind::InvalidPlaylistContent)?;
+    Ok(concat_string!(DECLARATION, "\n", content))
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let tracks = [
+            Track {
+                path: "artist1/album1/song 1.flac",
+                title: "title1 & more",
+                artist: "artist1",
+                album: "album1",
+                duration: time::Duration::seconds(100),
+            },
+            Track {
+                path: "artist2/album2/song2.mp3",
+                title: "title2",
+                artist: "artist2",
+                album: "album2",
+                duration: time::Duration::seconds(200),
+            },
+        ];
+        let content = render("playlist", &tracks).unwrap();
+        assert!(content.starts_with(DECLARATION));
+        assert_eq!(
+            parse(&content).unwrap(),
+            vec![
+                Entry {
+                    location: "artist1/album1/song 1.flac".into(),
+                    artist: Some("artist1".into()),
+                    title: Some("title1 & more".into()),
+                },
+                Entry {
+                    location: "artist2/album2/song2.mp3".into(),
+                    artist: Some("artist2".into()),
+                    title: Some("title2".into()),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<playlist version="1" xmlns="http://xspf.org/ns/0/">
+  <trackList>
+    <track><location>file:///music/artist/artist%20-%20title.flac</location></track>
+    <track><creator>artist</creator><title>title</title></track>
+    <track><album>album</album></track>
+  </trackList>
+</playlist>"#;
+        assert_eq!(
+            parse(content).unwrap(),
+            vec![
+                Entry {
+                    location: "file:///music/artist/artist%20-%20title.flac".into(),
+                    artist: Some("artist".into()),
+                    title: Some("title".into()),
+                },
+                Entry {
+                    location: "artist - title".into(),
+                    artist: Some("artist".into()),
+                    title: Some("title".into()),
+                }
+            ]
+        );
+        assert!(parse("<playlist><trackList>").is_err());
+    }
+}
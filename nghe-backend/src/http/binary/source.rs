@@ -1,3 +1,4 @@
+use diesel::OptionalExtension;
 use diesel_async::RunQueryDsl;
 use typed_path::Utf8TypedPathBuf;
 use uuid::Uuid;
@@ -7,7 +8,7 @@ use crate::Error;
 use crate::database::Database;
 use crate::file::{self, audio};
 use crate::filesystem::{self, Filesystem};
-use crate::orm::binary;
+use crate::orm::{binary, songs};
 
 pub struct Source<P: property::Trait> {
     pub path: Utf8TypedPathBuf,
@@ -23,7 +24,11 @@ impl Source<file::Property<audio::Format>> {
     ) -> Result<(filesystem::Impl<'fs>, Self), Error> {
         let audio = binary::source::audio::query(user_id, song_id)
             .get_result(&mut database.get().await?)
-            .await?;
+            .await
+            .optional()?;
+        let Some(audio) = audio else {
+            return Self::episode(database, filesystem, song_id).await;
+        };
         let filesystem = filesystem.to_impl(audio.music_folder.ty.into())?;
         let path = filesystem
             .path()
@@ -31,4 +36,23 @@ impl Source<file::Property<audio::Format>> {
             .join(audio.relative_path);
         Ok((filesystem, Self { path, property: audio.property.try_into()? }))
     }
+
+    // Downloaded podcast episodes are always stored on the local filesystem.
+    async fn episode<'fs>(
+        database: &Database,
+        filesystem: &'fs Filesystem,
+        episode_id: Uuid,
+    ) -> Result<(filesystem::Impl<'fs>, Self), Error> {
+        let episode = binary::source::episode::query(episode_id)
+            .get_result(&mut database.get().await?)
+            .await?;
+        let filesystem = filesystem.to_impl(nghe_api::common::filesystem::Type::Local)?;
+        let path = filesystem.path().from_string(episode.path.into_owned());
+        let property = songs::property::File {
+            hash: episode.file_hash,
+            size: episode.file_size,
+            format: episode.format,
+        };
+        Ok((filesystem, Self { path, property: property.try_into()? }))
+    }
 }
@@ -10,6 +10,7 @@ use crate::{Error, error};
 
 pub struct Form<R> {
     pub user: users::Authenticated,
+    pub client: Option<String>,
     pub request: R,
 }
 
@@ -34,8 +35,14 @@ where
         let axum::extract::RawForm(bytes) =
             axum::extract::RawForm::from_request(request, &()).await.map_err(error::Kind::from)?;
         let form: R::AuthForm = serde_html_form::from_bytes(&bytes).map_err(error::Kind::from)?;
+        let auth = form.auth();
+        let client = match auth {
+            auth::Form::Username(username) => Some(username.client.to_string()),
+            auth::Form::ApiKey(_) => None,
+        };
         Ok(Self {
-            user: form.auth().authenticated(&Database::from_ref(state)).await?,
+            user: auth.authenticated(&Database::from_ref(state)).await?,
+            client,
             request: form.request(),
         })
     }
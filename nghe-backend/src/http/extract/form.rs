@@ -0,0 +1,59 @@
+use axum::extract::{FromRequest, Request};
+use serde::de::DeserializeOwned;
+
+use crate::{Error, error};
+
+// Form request without any authentication, used by public endpoints.
+pub struct Form<R>(pub R);
+
+impl<S, R> FromRequest<S> for Form<R>
+where
+    S: Send + Sync,
+    R: DeserializeOwned + Send,
+{
+    type Rejection = Error;
+
+    async fn from_request(request: Request, _: &S) -> Result<Self, Self::Rejection> {
+        let axum::extract::RawForm(bytes) =
+            axum::extract::RawForm::from_request(request, &()).await.map_err(error::Kind::from)?;
+        Ok(Self(serde_html_form::from_bytes(&bytes).map_err(error::Kind::from)?))
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    #![allow(unexpected_cfgs)]
+
+    use axum::body::Body;
+    use axum::http;
+    use concat_string::concat_string;
+    use fake::{Fake, Faker};
+    use nghe_proc_macro::api_derive;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_from_request(#[future(awt)] mock: Mock) {
+        #[api_derive(fake = true)]
+        #[endpoint(path = "test", url_only = true, same_crate = false)]
+        #[derive(Clone, Copy, PartialEq)]
+        struct Request {
+            param_one: i32,
+            param_two: u32,
+        }
+
+        let request: Request = Faker.fake();
+        let http_request = http::Request::builder()
+            .method(http::Method::GET)
+            .uri(concat_string!("/test?", serde_html_form::to_string(request).unwrap()))
+            .body(Body::empty())
+            .unwrap();
+
+        let form_request = Form::<Request>::from_request(http_request, mock.state()).await;
+        assert_eq!(form_request.unwrap().0, request);
+    }
+}
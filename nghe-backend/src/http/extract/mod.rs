@@ -1 +1,4 @@
 pub mod auth;
+mod form;
+
+pub use form::Form;
@@ -1,3 +1,4 @@
 pub mod binary;
 pub mod extract;
 pub mod header;
+pub mod xml;
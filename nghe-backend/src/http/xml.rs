@@ -0,0 +1,284 @@
+use axum::body::{Body, Bytes, to_bytes};
+use axum::extract::{FromRequest, Request};
+use axum::http::{Method, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use nghe_api::common::SubsonicResponse;
+use quick_xml::Writer;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::OpensubsonicCode;
+
+const NAMESPACE: &str = "http://subsonic.org/restapi";
+const ROOT: &str = "subsonic-response";
+// Like the OpenSubsonic JSON format, this key holds the text content of an element.
+const TEXT: &str = "value";
+// Responses are fully buffered for the conversion, so they are bounded like request bodies.
+const RESPONSE_LIMIT: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Xml,
+    #[default]
+    #[serde(other)]
+    Json,
+}
+
+#[derive(Deserialize)]
+struct Param {
+    f: Option<Format>,
+}
+
+#[derive(Serialize)]
+struct Error<'a> {
+    code: u8,
+    message: &'a str,
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: Error<'a>,
+}
+
+impl Format {
+    fn from_form(form: &[u8]) -> Option<Self> {
+        serde_html_form::from_bytes::<Param>(form).ok().and_then(|param| param.f)
+    }
+
+    // Reads the format from the same form as the handlers, which is either the query or the
+    // body of a POST request, and gives back a request with the body still intact.
+    async fn extract(request: Request) -> Result<(Self, Request), Response> {
+        if let Some(format) =
+            request.uri().query().and_then(|query| Self::from_form(query.as_bytes()))
+        {
+            return Ok((format, request));
+        }
+
+        let is_form = request.method() == Method::POST
+            && request.headers().get(header::CONTENT_TYPE).is_some_and(|content_type| {
+                content_type.as_bytes().starts_with(b"application/x-www-form-urlencoded")
+            });
+        if !is_form {
+            return Ok((Self::default(), request));
+        }
+
+        // The body is read with the same limit as the `Form` extractor.
+        let (parts, body) = request.into_parts();
+        let bytes = Bytes::from_request(Request::from_parts(parts.clone(), body), &())
+            .await
+            .map_err(IntoResponse::into_response)?;
+        let format = Self::from_form(&bytes).unwrap_or_default();
+        Ok((format, Request::from_parts(parts, Body::from(bytes))))
+    }
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Null | Value::Array(_) | Value::Object(_))
+}
+
+fn to_text(value: &Value) -> String {
+    if let Value::String(value) = value { value.clone() } else { value.to_string() }
+}
+
+fn write_element(writer: &mut Writer<Vec<u8>>, name: &str, value: &Value) -> std::io::Result<()> {
+    let mut start = BytesStart::new(name);
+    if name == ROOT {
+        start.push_attribute(("xmlns", NAMESPACE));
+    }
+
+    match value {
+        Value::Null => Ok(()),
+        Value::Array(values) => {
+            values.iter().try_for_each(|value| write_element(writer, name, value))
+        }
+        Value::Object(map) => {
+            let mut text = None;
+            let mut children = vec![];
+            for (key, value) in map {
+                if key == TEXT && is_scalar(value) {
+                    text = Some(to_text(value));
+                } else if is_scalar(value) {
+                    start.push_attribute((key.as_str(), to_text(value).as_str()));
+                } else if !value.is_null() {
+                    children.push((key, value));
+                }
+            }
+
+            if text.is_none() && children.is_empty() {
+                writer.write_event(Event::Empty(start))
+            } else {
+                writer.write_event(Event::Start(start))?;
+                if let Some(text) = text {
+                    writer.write_event(Event::Text(BytesText::new(&text)))?;
+                }
+                for (key, value) in children {
+                    write_element(writer, key, value)?;
+                }
+                writer.write_event(Event::End(BytesEnd::new(name)))
+            }
+        }
+        _ => {
+            writer.write_event(Event::Start(start))?;
+            writer.write_event(Event::Text(BytesText::new(&to_text(value))))?;
+            writer.write_event(Event::End(BytesEnd::new(name)))
+        }
+    }
+}
+
+// Subsonic XML puts scalar fields into attributes, objects into child elements and arrays into
+// repeated child elements, which maps one to one from the JSON format.
+fn to_xml(value: &Value) -> std::io::Result<Vec<u8>> {
+    let mut writer = Writer::new(vec![]);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    if let Value::Object(map) = value {
+        for (key, value) in map {
+            write_element(&mut writer, key, value)?;
+        }
+    }
+    Ok(writer.into_inner())
+}
+
+fn xml_response(value: &Value) -> Response {
+    match to_xml(value) {
+        Ok(xml) => ([(header::CONTENT_TYPE, "application/xml")], xml).into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+fn error_response(status: StatusCode, code: OpensubsonicCode, message: &str) -> Response {
+    let mut value = serde_json::to_value(SubsonicResponse::new(ErrorBody {
+        error: Error { code: code as u8, message },
+    }))
+    .unwrap_or_default();
+    value[ROOT]["status"] = "failed".into();
+    let mut response = xml_response(&value);
+    if response.status().is_success() {
+        *response.status_mut() = status;
+    }
+    response
+}
+
+pub async fn layer(request: Request, next: Next) -> Response {
+    let (format, request) = match Format::extract(request).await {
+        Ok(result) => result,
+        Err(response) => return response,
+    };
+    let response = next.run(request).await;
+    if format == Format::Json {
+        return response;
+    }
+
+    let code = response.extensions().get::<OpensubsonicCode>().copied();
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .is_some_and(|content_type| content_type.as_bytes().starts_with(b"application/json"));
+    if code.is_none() && !is_json {
+        // Binary responses are left untouched.
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, RESPONSE_LIMIT).await {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response();
+        }
+    };
+
+    if let Some(code) = code {
+        error_response(parts.status, code, &String::from_utf8_lossy(&bytes))
+    } else {
+        match serde_json::from_slice::<Value>(&bytes) {
+            Ok(value) => xml_response(&value),
+            Err(_) => Response::from_parts(parts, Body::from(bytes)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_format_from_form() {
+        assert_eq!(Format::from_form(b""), None);
+        assert_eq!(Format::from_form(b"u=user&f=xml"), Some(Format::Xml));
+        assert_eq!(Format::from_form(b"u=user&f=json"), Some(Format::Json));
+        assert_eq!(Format::from_form(b"u=user&f=jsonp"), Some(Format::Json));
+    }
+
+    #[tokio::test]
+    async fn test_format_extract() {
+        let request = Request::builder()
+            .method(axum::http::Method::POST)
+            .uri("/rest/ping")
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from("u=user&f=xml"))
+            .unwrap();
+        let (format, request) = Format::extract(request).await.unwrap();
+        assert_eq!(format, Format::Xml);
+        let body = to_bytes(request.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body.as_ref(), b"u=user&f=xml");
+
+        let request = Request::builder().uri("/rest/ping?f=xml").body(Body::empty()).unwrap();
+        assert_eq!(Format::extract(request).await.unwrap().0, Format::Xml);
+
+        // Only form bodies of POST requests are read.
+        let request = Request::builder()
+            .method(axum::http::Method::PUT)
+            .uri("/rest/ping")
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from("u=user&f=xml"))
+            .unwrap();
+        assert_eq!(Format::extract(request).await.unwrap().0, Format::Json);
+    }
+
+    #[test]
+    fn test_to_xml() {
+        let value = json!({
+            ROOT: {
+                "status": "ok",
+                "version": "1.16.1",
+                "empty": null,
+                "genres": {
+                    "genre": [
+                        { "songCount": 1, "value": "Rock & Roll" },
+                        { "songCount": 2, "value": "Pop" }
+                    ]
+                },
+                "song": { "id": "id", "starred": true }
+            }
+        });
+        assert_eq!(
+            String::from_utf8(to_xml(&value).unwrap()).unwrap(),
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+                r#"<subsonic-response xmlns="http://subsonic.org/restapi" status="ok" "#,
+                r#"version="1.16.1"><genres><genre songCount="1">Rock &amp; Roll</genre>"#,
+                r#"<genre songCount="2">Pop</genre></genres>"#,
+                r#"<song id="id" starred="true"/></subsonic-response>"#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_response() {
+        let response = error_response(
+            StatusCode::NOT_FOUND,
+            OpensubsonicCode::TheRequestedDataWasNotFound,
+            "<missing>",
+        );
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains(r#"status="failed""#));
+        assert!(body.contains(r#"<error code="70" message="&lt;missing&gt;"/>"#));
+    }
+}
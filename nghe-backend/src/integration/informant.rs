@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 
 use diesel::dsl::{exists, not};
-use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, SelectableHelper};
 use diesel_async::RunQueryDsl;
 use nghe_api::media_annotation::update_artist_information::Request;
 use rspotify::model::Id;
+use tokio_util::sync::CancellationToken;
 use typed_path::Utf8PlatformPath;
 use uuid::Uuid;
 
@@ -12,10 +13,14 @@ use super::{lastfm, spotify};
 use crate::database::Database;
 use crate::file::image;
 use crate::orm::upsert::Update;
-use crate::orm::{artist_informations, artists};
+use crate::orm::{
+    albums, artist_informations, artists, similar_artists, similar_songs, songs, songs_artists,
+};
 use crate::{Error, config};
 
 const MAX_ITEM_PER_QUERY: i64 = 100;
+const MAX_SIMILAR_ARTIST: u32 = 50;
+const MAX_SIMILAR_SONG: u32 = 50;
 
 #[derive(Clone)]
 pub struct Informant {
@@ -84,14 +89,43 @@ impl Informant {
         } else {
             artist_informations::Spotify::default()
         };
-        let lastfm = lastfm
+        let information = lastfm
             .map(|lastfm| artist_informations::Lastfm {
                 url: Some(lastfm.short.url.as_str().into()),
                 mbz_id: lastfm.short.mbid,
                 biography: lastfm.bio.summary.as_deref().map(Cow::Borrowed),
             })
             .unwrap_or_default();
-        artist_informations::Data { spotify, lastfm }.update(database, id).await
+        artist_informations::Data { spotify, lastfm: information }.update(database, id).await?;
+
+        // Similar artists are only additional information, failing to fetch them should not
+        // discard the information above.
+        if let Some(ref client) = self.lastfm
+            && let Some(lastfm) = lastfm
+        {
+            match client
+                .fetch_similar_artists(
+                    &lastfm.short.name,
+                    lastfm.short.mbid,
+                    Some(MAX_SIMILAR_ARTIST),
+                )
+                .await
+            {
+                Ok(similars) => {
+                    let data: Vec<_> = similars
+                        .iter()
+                        .map(|similar| similar_artists::Data {
+                            name: similar.short.name.as_str().into(),
+                            mbz_id: similar.short.mbid,
+                            score: similar.score,
+                        })
+                        .collect();
+                    similar_artists::Data::replace(database, id, &data).await?;
+                }
+                Err(error) => tracing::warn!(similar_artists_error = ?error),
+            }
+        }
+        Ok(())
     }
 
     #[cfg_attr(not(coverage_nightly), tracing::instrument(skip(self, database, config)))]
@@ -180,6 +214,78 @@ impl Informant {
         }
         Ok(())
     }
+
+    #[cfg_attr(not(coverage_nightly), tracing::instrument(skip(self, database, client)))]
+    async fn fetch_and_upsert_similar_songs(
+        &self,
+        database: &Database,
+        client: &lastfm::Client,
+        id: Uuid,
+        title: &str,
+        mbz_id: Option<Uuid>,
+        artist: &str,
+    ) -> Result<(), Error> {
+        let tracks =
+            client.fetch_similar_tracks(artist, title, mbz_id, Some(MAX_SIMILAR_SONG)).await?;
+        let data: Vec<_> = tracks
+            .iter()
+            .map(|track| similar_songs::Data {
+                name: track.name.as_str().into(),
+                artist_name: track.artist.name.as_str().into(),
+                score: track.score,
+            })
+            .collect();
+        similar_songs::Data::replace(database, id, &data).await
+    }
+
+    pub async fn search_and_upsert_similar_songs(
+        &self,
+        database: &Database,
+        music_folder_id: Uuid,
+        full: bool,
+        token: &CancellationToken,
+    ) -> Result<(), Error> {
+        if let Some(ref client) = self.lastfm {
+            // Songs are paginated by their id so songs that could not be fetched are not fetched
+            // again in the same scan.
+            let mut after = Uuid::nil();
+            loop {
+                let songs: Vec<(Uuid, String, Option<Uuid>, String)> = if full {
+                    query::songs_similar(music_folder_id, after)
+                        .get_results(&mut database.get().await?)
+                        .await?
+                } else {
+                    query::songs_similar(music_folder_id, after)
+                        .filter(songs::similar_songs_fetched_at.is_null())
+                        .get_results(&mut database.get().await?)
+                        .await?
+                };
+
+                let Some((id, ..)) = songs.last() else {
+                    break;
+                };
+                after = *id;
+
+                for (id, title, mbz_id, artist) in songs {
+                    if token.is_cancelled() {
+                        return Ok(());
+                    }
+                    // Similar songs are only additional information, a failed song is marked as
+                    // fetched as well so it is only fetched again by a full scan.
+                    if let Err(error) = self
+                        .fetch_and_upsert_similar_songs(
+                            database, client, id, &title, mbz_id, &artist,
+                        )
+                        .await
+                    {
+                        tracing::warn!(similar_songs_error = ?error);
+                        similar_songs::Data::mark_fetched(database, id).await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 mod query {
@@ -207,6 +313,22 @@ mod query {
             .select(artist)
             .limit(limit)
     }
+
+    #[auto_type]
+    pub fn songs_similar(music_folder_id: Uuid, after: Uuid) -> _ {
+        // The first artist of each song is used to search for similar songs.
+        let limit: i64 = MAX_ITEM_PER_QUERY;
+        songs::table
+            .inner_join(albums::table)
+            .inner_join(songs_artists::table)
+            .inner_join(artists::table.on(artists::id.eq(songs_artists::artist_id)))
+            .filter(albums::music_folder_id.eq(music_folder_id))
+            .filter(songs::id.gt(after))
+            .order_by((songs::id, songs_artists::upserted_at))
+            .distinct_on(songs::id)
+            .select((songs::id, songs::title, songs::mbz_id, artists::name))
+            .limit(limit)
+    }
 }
 
 #[cfg(all(test, spotify_env, lastfm_env))]
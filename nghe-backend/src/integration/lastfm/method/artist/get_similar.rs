@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Error;
+use crate::integration::lastfm;
+use crate::integration::lastfm::model::artist;
+
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")]
+)]
+#[derive(Debug, Serialize)]
+struct Request<'a> {
+    artist: Option<&'a str>,
+    mbid: Option<Uuid>,
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SimilarArtists {
+    artist: Vec<artist::Similar>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    #[serde(rename = "similarartists")]
+    similar_artists: SimilarArtists,
+}
+
+impl lastfm::Request for Request<'_> {
+    type Response = Response;
+    const NAME: &'static str = "artist.getsimilar";
+}
+
+impl lastfm::Client {
+    pub async fn fetch_similar_artists(
+        &self,
+        artist: impl AsRef<str>,
+        mbid: Option<Uuid>,
+        limit: Option<u32>,
+    ) -> Result<Vec<artist::Similar>, Error> {
+        self.send(&Request {
+            artist: if mbid.is_none() { Some(artist.as_ref()) } else { None },
+            mbid,
+            limit,
+        })
+        .await
+        .map(|response| response.similar_artists.artist)
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let response: Response = serde_json::from_str(
+            r#"{"similarartists":{"artist":[
+                {"name":"Madonna","mbid":"79239441-bfd5-4981-a70c-55c3f15c1287","match":"1",
+                    "url":"https://www.last.fm/music/Madonna"},
+                {"name":"Sonny & Cher","match":"0.5","url":"https://www.last.fm/music/Sonny+&+Cher"}
+            ],"@attr":{"artist":"Cher"}}}"#,
+        )
+        .unwrap();
+        let artists = response.similar_artists.artist;
+        assert_eq!(artists.len(), 2);
+        assert_eq!(artists[0].short.name, "Madonna");
+        assert!(artists[0].short.mbid.is_some());
+        assert!((artists[0].score - 1.0).abs() < f32::EPSILON);
+        assert!(artists[1].short.mbid.is_none());
+        assert!((artists[1].score - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[cfg(lastfm_env)]
+    #[tokio::test]
+    async fn test_fetch_similar_artists() {
+        let client = lastfm::Client::new(
+            reqwest::Client::default(),
+            crate::config::integration::Lastfm::from_env(),
+        )
+        .unwrap();
+        let artists = client
+            .fetch_similar_artists(
+                "Cher",
+                Some(uuid::uuid!("bfcc6d75-a6a5-4bc6-8282-47aec8531818")),
+                Some(5),
+            )
+            .await
+            .unwrap();
+        assert!(!artists.is_empty());
+        assert!(artists.len() <= 5);
+    }
+}
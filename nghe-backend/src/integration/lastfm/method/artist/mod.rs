@@ -1,2 +1,3 @@
 mod get_info;
+mod get_similar;
 mod search;
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+use crate::integration::lastfm;
+use crate::integration::lastfm::model::auth;
+
+#[derive(Debug, Serialize)]
+struct Request<'a> {
+    token: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    session: auth::Session,
+}
+
+impl lastfm::SignedRequest for Request<'_> {
+    type Response = Response;
+    const NAME: &'static str = "auth.getSession";
+}
+
+impl lastfm::Client {
+    pub async fn get_session(&self, token: &str) -> Result<auth::Session, Error> {
+        self.send_signed(&Request { token }).await.map(|response| response.session)
+    }
+}
@@ -1 +1,3 @@
 mod artist;
+mod auth;
+mod track;
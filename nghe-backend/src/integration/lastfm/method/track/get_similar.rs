@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Error;
+use crate::integration::lastfm;
+use crate::integration::lastfm::model::track;
+
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")]
+)]
+#[derive(Debug, Serialize)]
+struct Request<'a> {
+    artist: Option<&'a str>,
+    track: Option<&'a str>,
+    mbid: Option<Uuid>,
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SimilarTracks {
+    track: Vec<track::Similar>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    #[serde(rename = "similartracks")]
+    similar_tracks: SimilarTracks,
+}
+
+impl lastfm::Request for Request<'_> {
+    type Response = Response;
+    const NAME: &'static str = "track.getsimilar";
+}
+
+impl lastfm::Client {
+    pub async fn fetch_similar_tracks(
+        &self,
+        artist: impl AsRef<str>,
+        track: impl AsRef<str>,
+        mbid: Option<Uuid>,
+        limit: Option<u32>,
+    ) -> Result<Vec<track::Similar>, Error> {
+        let (artist, track) = if mbid.is_none() {
+            (Some(artist.as_ref()), Some(track.as_ref()))
+        } else {
+            (None, None)
+        };
+        self.send(&Request { artist, track, mbid, limit })
+            .await
+            .map(|response| response.similar_tracks.track)
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let response: Response = serde_json::from_str(
+            r#"{"similartracks":{"track":[
+                {"name":"Strong Enough","playcount":123,"match":1.0,
+                    "url":"https://www.last.fm/music/Cher/_/Strong+Enough",
+                    "artist":{"name":"Cher","mbid":"bfcc6d75-a6a5-4bc6-8282-47aec8531818",
+                        "url":"https://www.last.fm/music/Cher"}},
+                {"name":"Vogue","mbid":"","match":0.25,
+                    "url":"https://www.last.fm/music/Madonna/_/Vogue",
+                    "artist":{"name":"Madonna","url":"https://www.last.fm/music/Madonna"}}
+            ],"@attr":{"artist":"Cher"}}}"#,
+        )
+        .unwrap();
+        let tracks = response.similar_tracks.track;
+        assert_eq!(tracks.len(), 2);
+        assert_eq!(tracks[0].name, "Strong Enough");
+        assert_eq!(tracks[0].artist.name, "Cher");
+        assert_eq!(tracks[1].name, "Vogue");
+        assert!(tracks[1].artist.mbid.is_none());
+    }
+
+    #[cfg(lastfm_env)]
+    #[tokio::test]
+    async fn test_fetch_similar_tracks() {
+        let client = lastfm::Client::new(
+            reqwest::Client::default(),
+            crate::config::integration::Lastfm::from_env(),
+        )
+        .unwrap();
+        let tracks = client.fetch_similar_tracks("Cher", "Believe", None, Some(5)).await.unwrap();
+        assert!(!tracks.is_empty());
+        assert!(tracks.len() <= 5);
+    }
+}
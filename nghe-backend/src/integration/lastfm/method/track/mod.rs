@@ -0,0 +1,3 @@
+mod get_similar;
+mod scrobble;
+mod update_now_playing;
@@ -0,0 +1,40 @@
+use concat_string::concat_string;
+use serde::Serialize;
+use serde::de::IgnoredAny;
+
+use crate::Error;
+use crate::integration::lastfm;
+use crate::integration::scrobbler::Listen;
+
+// Batch parameters are suffixed by their index, e.g. `artist[0]`, so they are built manually.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+struct Request(Vec<(String, String)>);
+
+impl lastfm::SignedRequest for Request {
+    type Response = IgnoredAny;
+    const NAME: &'static str = "track.scrobble";
+}
+
+impl lastfm::Client {
+    pub const MAX_SCROBBLE_PER_REQUEST: usize = 50;
+
+    // The caller is responsible for splitting listens into chunks of `MAX_SCROBBLE_PER_REQUEST`.
+    pub async fn scrobble(&self, session: &str, listens: &[Listen]) -> Result<(), Error> {
+        let mut params = vec![("sk".to_owned(), session.to_owned())];
+        for (index, listen) in listens.iter().enumerate() {
+            let param = |name: &str| concat_string!(name, "[", index.to_string(), "]");
+            let track = &listen.track;
+            params.push((param("artist"), track.artist.clone()));
+            params.push((param("track"), track.title.clone()));
+            params.push((param("album"), track.album.clone()));
+            params.push((param("duration"), track.duration.to_string()));
+            params.push((param("timestamp"), listen.played_at.unix_timestamp().to_string()));
+            if let Some(mbid) = track.mbid {
+                params.push((param("mbid"), mbid.to_string()));
+            }
+        }
+        self.send_signed(&Request(params)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use serde::Serialize;
+use serde::de::IgnoredAny;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::integration::lastfm;
+use crate::integration::scrobbler::Track;
+
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")]
+)]
+#[derive(Debug, Serialize)]
+struct Request<'a> {
+    artist: &'a str,
+    track: &'a str,
+    album: &'a str,
+    duration: u32,
+    mbid: Option<Uuid>,
+    sk: &'a str,
+}
+
+impl lastfm::SignedRequest for Request<'_> {
+    type Response = IgnoredAny;
+    const NAME: &'static str = "track.updateNowPlaying";
+}
+
+impl lastfm::Client {
+    pub async fn update_now_playing(&self, session: &str, track: &Track) -> Result<(), Error> {
+        self.send_signed(&Request {
+            artist: &track.artist,
+            track: &track.title,
+            album: &track.album,
+            duration: track.duration,
+            mbid: track.mbid,
+            sk: session,
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -11,6 +11,7 @@ use crate::{Error, config, error};
 pub struct Client {
     http: reqwest::Client,
     key: String,
+    secret: Option<String>,
 }
 
 trait Request: Serialize {
@@ -18,16 +19,26 @@ trait Request: Serialize {
     const NAME: &'static str;
 }
 
+// Requests that need to be signed with the shared secret and sent with a `POST` method.
+trait SignedRequest: Serialize {
+    type Response: DeserializeOwned;
+    const NAME: &'static str;
+}
+
 impl Client {
     const LASTFM_ROOT_URL: &'static str = "https://ws.audioscrobbler.com/2.0/?";
 
     pub fn new(http: reqwest::Client, config: config::integration::Lastfm) -> Option<Self> {
         config.key.map(|key| {
             tracing::info!("lastfm integration enabled");
-            Self { http, key }
+            Self { http, key, secret: config.secret }
         })
     }
 
+    pub fn is_signable(&self) -> bool {
+        self.secret.is_some()
+    }
+
     fn build_url<R: Request>(&self, request: &R) -> Result<String, Error> {
         serde_html_form::to_string(request)
             .map(|form| {
@@ -56,4 +67,73 @@ impl Client {
             .await
             .map_err(Error::from)
     }
+
+    fn build_signed_body<R: SignedRequest>(&self, request: &R) -> Result<String, Error> {
+        let secret =
+            self.secret.as_ref().ok_or_else(|| Error::from(error::Kind::LastFMSecretMissing))?;
+        let form = serde_html_form::to_string(request)
+            .map_err(|_| Error::from(error::Kind::BuildLastFMRequestURLFailed))?;
+        let mut params: Vec<(String, String)> = serde_html_form::from_str(&form)
+            .map_err(|_| Error::from(error::Kind::BuildLastFMRequestURLFailed))?;
+        params.push(("api_key".to_owned(), self.key.clone()));
+        params.push(("method".to_owned(), R::NAME.to_owned()));
+        params.sort();
+
+        let signature = md5::compute(
+            params
+                .iter()
+                .flat_map(|(key, value)| [key.as_str(), value.as_str()])
+                .chain(std::iter::once(secret.as_str()))
+                .collect::<String>(),
+        );
+        params.push(("api_sig".to_owned(), format!("{signature:x}")));
+        params.push(("format".to_owned(), "json".to_owned()));
+        serde_html_form::to_string(params)
+            .map_err(|_| error::Kind::BuildLastFMRequestURLFailed.into())
+    }
+
+    async fn send_signed<R: SignedRequest>(&self, request: &R) -> Result<R::Response, Error> {
+        self.http
+            .post(Self::LASTFM_ROOT_URL)
+            .header(reqwest::header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(self.build_signed_body(request)?)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<R::Response>()
+            .await
+            .map_err(Error::from)
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Request<'a> {
+        token: &'a str,
+    }
+
+    impl SignedRequest for Request<'_> {
+        type Response = ();
+        const NAME: &'static str = "auth.getSession";
+    }
+
+    #[test]
+    fn test_build_signed_body() {
+        let client = Client::new(
+            reqwest::Client::default(),
+            config::integration::Lastfm { key: Some("key".into()), secret: Some("secret".into()) },
+        )
+        .unwrap();
+        let signature = md5::compute("api_keykeymethodauth.getSessiontokentokensecret");
+        assert_eq!(
+            client.build_signed_body(&Request { token: "token" }).unwrap(),
+            format!(
+                "api_key=key&method=auth.getSession&token=token&api_sig={signature:x}&format=json"
+            )
+        );
+    }
 }
@@ -24,3 +24,13 @@ pub struct Full {
     pub short: Short,
     pub bio: Bio,
 }
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+pub struct Similar {
+    #[serde(flatten)]
+    pub short: Short,
+    #[serde_as(as = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>")]
+    #[serde(rename = "match")]
+    pub score: f32,
+}
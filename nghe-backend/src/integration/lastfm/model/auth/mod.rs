@@ -0,0 +1,7 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Session {
+    pub name: String,
+    pub key: String,
+}
@@ -1 +1,3 @@
 pub mod artist;
+pub mod auth;
+pub mod track;
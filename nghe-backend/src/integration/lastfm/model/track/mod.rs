@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use serde_with::serde_as;
+
+use super::artist;
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+pub struct Similar {
+    pub name: String,
+    pub artist: artist::Short,
+    #[serde_as(as = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>")]
+    #[serde(rename = "match")]
+    pub score: f32,
+}
@@ -0,0 +1,158 @@
+use concat_string::concat_string;
+use nghe_api::constant;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::scrobbler::{Listen, Track};
+use crate::{Error, config};
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ListenType {
+    Single,
+    Import,
+    PlayingNow,
+}
+
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")]
+)]
+#[derive(Debug, Serialize)]
+struct AdditionalInfo<'a> {
+    duration_ms: u64,
+    recording_mbid: Option<Uuid>,
+    media_player: Option<&'a str>,
+    submission_client: &'static str,
+    submission_client_version: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct TrackMetadata<'a> {
+    artist_name: &'a str,
+    track_name: &'a str,
+    release_name: &'a str,
+    additional_info: AdditionalInfo<'a>,
+}
+
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")]
+)]
+#[derive(Debug, Serialize)]
+struct Payload<'a> {
+    listened_at: Option<i64>,
+    track_metadata: TrackMetadata<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct Submission<'a> {
+    listen_type: ListenType,
+    payload: Vec<Payload<'a>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Validation {
+    valid: bool,
+    user_name: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl<'a> TrackMetadata<'a> {
+    fn new(track: &'a Track, client: Option<&'a str>) -> Self {
+        Self {
+            artist_name: &track.artist,
+            track_name: &track.title,
+            release_name: &track.album,
+            additional_info: AdditionalInfo {
+                duration_ms: u64::from(track.duration) * 1000,
+                recording_mbid: track.mbid,
+                media_player: client,
+                submission_client: constant::SERVER_NAME,
+                submission_client_version: constant::SERVER_VERSION,
+            },
+        }
+    }
+}
+
+impl Client {
+    pub const MAX_LISTEN_PER_REQUEST: usize = 1000;
+
+    pub fn new(http: reqwest::Client, config: config::integration::Listenbrainz) -> Self {
+        let mut url = config.url;
+        url.truncate(url.trim_end_matches('/').len());
+        Self { http, url }
+    }
+
+    fn authorization(token: &str) -> String {
+        concat_string!("Token ", token)
+    }
+
+    pub async fn validate_token(&self, token: &str) -> Result<Option<String>, Error> {
+        let validation = self
+            .http
+            .get(concat_string!(self.url, "/1/validate-token"))
+            .header(reqwest::header::AUTHORIZATION, Self::authorization(token))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Validation>()
+            .await?;
+        Ok(if validation.valid { validation.user_name } else { None })
+    }
+
+    async fn send(&self, token: &str, submission: &Submission<'_>) -> Result<(), Error> {
+        self.http
+            .post(concat_string!(self.url, "/1/submit-listens"))
+            .header(reqwest::header::AUTHORIZATION, Self::authorization(token))
+            .json(submission)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    pub async fn playing_now(
+        &self,
+        token: &str,
+        track: &Track,
+        client: Option<&str>,
+    ) -> Result<(), Error> {
+        self.send(
+            token,
+            &Submission {
+                listen_type: ListenType::PlayingNow,
+                payload: vec![Payload {
+                    listened_at: None,
+                    track_metadata: TrackMetadata::new(track, client),
+                }],
+            },
+        )
+        .await
+    }
+
+    // The caller is responsible for splitting listens into chunks of `MAX_LISTEN_PER_REQUEST`.
+    pub async fn submit(&self, token: &str, listens: &[Listen]) -> Result<(), Error> {
+        self.send(
+            token,
+            &Submission {
+                listen_type: if listens.len() == 1 {
+                    ListenType::Single
+                } else {
+                    ListenType::Import
+                },
+                payload: listens
+                    .iter()
+                    .map(|listen| Payload {
+                        listened_at: Some(listen.played_at.unix_timestamp()),
+                        track_metadata: TrackMetadata::new(&listen.track, listen.client.as_deref()),
+                    })
+                    .collect(),
+            },
+        )
+        .await
+    }
+}
@@ -1,5 +1,10 @@
 mod informant;
 pub mod lastfm;
+mod listenbrainz;
+mod podcast;
+mod scrobbler;
 pub mod spotify;
 
 pub use informant::Informant;
+pub use podcast::Podcast;
+pub use scrobbler::Scrobbler;
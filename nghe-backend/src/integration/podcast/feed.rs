@@ -0,0 +1,298 @@
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use time::OffsetDateTime;
+use time::format_description::well_known::{Rfc2822, Rfc3339};
+
+use crate::{Error, error};
+
+#[derive(Debug, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Entry {
+    pub guid: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub published_at: Option<OffsetDateTime>,
+    pub duration: Option<time::Duration>,
+    // Url of the audio file.
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Feed {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub entries: Vec<Entry>,
+}
+
+fn attribute(element: &BytesStart<'_>, key: &[u8]) -> Option<String> {
+    element
+        .try_get_attribute(key)
+        .ok()
+        .flatten()
+        .and_then(|attribute| attribute.unescape_value().ok())
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+fn date(value: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(value, &Rfc2822).or_else(|_| OffsetDateTime::parse(value, &Rfc3339)).ok()
+}
+
+// Durations are either a number of seconds or in `[[hh:]mm:]ss` format.
+fn duration(value: &str) -> Option<time::Duration> {
+    let mut seconds = 0_i64;
+    for part in value.split(':') {
+        let part = part.trim().split('.').next()?.parse::<i64>().ok()?;
+        seconds = seconds.checked_mul(60)?.checked_add(part)?;
+    }
+    Some(time::Duration::seconds(seconds))
+}
+
+impl Entry {
+    fn element(&mut self, element: &BytesStart<'_>) {
+        match element.name().as_ref() {
+            b"enclosure" => {
+                if let Some(url) = attribute(element, b"url") {
+                    self.url = Some(url);
+                }
+            }
+            b"link" if attribute(element, b"rel").as_deref() == Some("enclosure") => {
+                if let Some(url) = attribute(element, b"href") {
+                    self.url = Some(url);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, name: &[u8], text: String) {
+        match name {
+            b"guid" | b"id" => self.guid = Some(text),
+            b"title" => self.title = Some(text),
+            b"description" | b"summary" => self.description = Some(text),
+            b"itunes:summary" | b"content" => {
+                self.description.get_or_insert(text);
+            }
+            b"pubDate" | b"published" => self.published_at = date(&text),
+            b"updated" => {
+                if self.published_at.is_none() {
+                    self.published_at = date(&text);
+                }
+            }
+            b"itunes:duration" => self.duration = duration(&text),
+            _ => {}
+        }
+    }
+}
+
+impl Feed {
+    fn element(&mut self, element: &BytesStart<'_>) {
+        if element.name().as_ref() == b"itunes:image"
+            && let Some(href) = attribute(element, b"href")
+        {
+            self.image_url = Some(href);
+        }
+    }
+
+    fn text(&mut self, name: &[u8], parent: &[u8], text: String) {
+        match (parent, name) {
+            (b"channel" | b"feed", b"title") => self.title = Some(text),
+            (b"channel", b"description") | (b"feed", b"subtitle") => {
+                self.description = Some(text);
+            }
+            (b"image", b"url") | (b"feed", b"logo" | b"icon") => {
+                self.image_url.get_or_insert(text);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, Error> {
+        // Texts are not trimmed by the reader since entity references split them into parts.
+        let mut reader = Reader::from_str(content);
+
+        let mut feed = Self::default();
+        let mut entry: Option<Entry> = None;
+        let mut names: Vec<Vec<u8>> = vec![];
+        let mut text = String::new();
+
+        loop {
+            match reader.read_event().map_err(|_| error::Kind::InvalidPodcastFeed)? {
+                Event::Start(element) => {
+                    let name = element.name().as_ref().to_vec();
+                    if matches!(name.as_slice(), b"item" | b"entry") {
+                        entry = Some(Entry::default());
+                    } else if let Some(ref mut entry) = entry {
+                        entry.element(&element);
+                    } else {
+                        feed.element(&element);
+                    }
+                    names.push(name);
+                    text.clear();
+                }
+                Event::Empty(element) => {
+                    if let Some(ref mut entry) = entry {
+                        entry.element(&element);
+                    } else {
+                        feed.element(&element);
+                    }
+                }
+                Event::Text(content) => {
+                    text.push_str(&content.decode().map_err(|_| error::Kind::InvalidPodcastFeed)?);
+                }
+                Event::GeneralRef(reference) => {
+                    if let Some(character) =
+                        reference.resolve_char_ref().map_err(|_| error::Kind::InvalidPodcastFeed)?
+                    {
+                        text.push(character);
+                    } else if let Some(entity) = quick_xml::escape::resolve_xml_entity(
+                        &reference.decode().map_err(|_| error::Kind::InvalidPodcastFeed)?,
+                    ) {
+                        text.push_str(entity);
+                    }
+                }
+                Event::CData(content) => {
+                    text.push_str(&content.decode().map_err(|_| error::Kind::InvalidPodcastFeed)?);
+                }
+                Event::End(_) => {
+                    let name = names.pop().ok_or_else(|| error::Kind::InvalidPodcastFeed)?;
+                    let value = std::mem::take(&mut text).trim().to_owned();
+                    if matches!(name.as_slice(), b"item" | b"entry") {
+                        feed.entries.extend(entry.take());
+                    } else if !value.is_empty() {
+                        if let Some(ref mut entry) = entry {
+                            entry.text(&name, value);
+                        } else {
+                            feed.text(&name, names.last().map_or(&[], Vec::as_slice), value);
+                        }
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        if names.is_empty() && (feed.title.is_some() || !feed.entries.is_empty()) {
+            Ok(feed)
+        } else {
+            error::Kind::InvalidPodcastFeed.into()
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_rss() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+  <channel>
+    <title>Podcast &amp; friends &#233;</title>
+    <description><![CDATA[A <b>great</b> podcast]]></description>
+    <image><url>https://example.com/image.png</url><title>Podcast</title></image>
+    <itunes:image href="https://example.com/itunes.png"/>
+    <item>
+      <title>Episode 2</title>
+      <itunes:title>Second episode</itunes:title>
+      <guid isPermaLink="false">episode-2</guid>
+      <pubDate>Tue, 10 Jun 2025 04:00:00 +0000</pubDate>
+      <itunes:duration>01:02:03</itunes:duration>
+      <enclosure url="https://example.com/2.mp3" type="audio/mpeg" length="100"/>
+    </item>
+    <item>
+      <title>Episode 1</title>
+      <description>First</description>
+      <itunes:summary>Summary</itunes:summary>
+      <enclosure url="https://example.com/1.m4a" length="100"/>
+    </item>
+  </channel>
+</rss>"#;
+        assert_eq!(
+            Feed::parse(content).unwrap(),
+            Feed {
+                title: Some("Podcast & friends é".to_owned()),
+                description: Some("A <b>great</b> podcast".to_owned()),
+                image_url: Some("https://example.com/itunes.png".to_owned()),
+                entries: vec![
+                    Entry {
+                        guid: Some("episode-2".to_owned()),
+                        title: Some("Episode 2".to_owned()),
+                        description: None,
+                        published_at: Some(datetime!(2025-06-10 04:00:00 UTC)),
+                        duration: Some(time::Duration::seconds(3723)),
+                        url: Some("https://example.com/2.mp3".to_owned()),
+                    },
+                    Entry {
+                        guid: None,
+                        title: Some("Episode 1".to_owned()),
+                        description: Some("First".to_owned()),
+                        published_at: None,
+                        duration: None,
+                        url: Some("https://example.com/1.m4a".to_owned()),
+                    }
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_atom() {
+        let content = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Atom podcast</title>
+  <subtitle>Subtitle</subtitle>
+  <logo>https://example.com/logo.png</logo>
+  <entry>
+    <id>urn:uuid:1</id>
+    <title>Entry</title>
+    <link rel="alternate" href="https://example.com/entry"/>
+    <link rel="enclosure" type="audio/ogg" href="https://example.com/entry.ogg"/>
+    <updated>2025-06-10T04:00:00Z</updated>
+    <summary>Summary</summary>
+  </entry>
+</feed>"#;
+        assert_eq!(
+            Feed::parse(content).unwrap(),
+            Feed {
+                title: Some("Atom podcast".to_owned()),
+                description: Some("Subtitle".to_owned()),
+                image_url: Some("https://example.com/logo.png".to_owned()),
+                entries: vec![Entry {
+                    guid: Some("urn:uuid:1".to_owned()),
+                    title: Some("Entry".to_owned()),
+                    description: Some("Summary".to_owned()),
+                    published_at: Some(datetime!(2025-06-10 04:00:00 UTC)),
+                    duration: None,
+                    url: Some("https://example.com/entry.ogg".to_owned()),
+                }]
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("not a feed")]
+    #[case("<rss><channel><title>Title</title>")]
+    fn test_parse_invalid(#[case] content: &str) {
+        assert!(Feed::parse(content).is_err());
+    }
+
+    #[rstest]
+    #[case("3723", Some(3723))]
+    #[case("62:03", Some(3723))]
+    #[case("01:02:03", Some(3723))]
+    #[case("01:02:03.500", Some(3723))]
+    #[case("invalid", None)]
+    #[case("9223372036854775807:00", None)]
+    fn test_duration(#[case] value: &str, #[case] seconds: Option<i64>) {
+        assert_eq!(duration(value), seconds.map(time::Duration::seconds));
+    }
+}
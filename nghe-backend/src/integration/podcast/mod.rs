@@ -0,0 +1,325 @@
+mod feed;
+
+use std::io::BufReader;
+use std::num::NonZeroU32;
+use std::str::FromStr;
+use std::time::Duration;
+
+use concat_string::concat_string;
+use diesel::upsert::excluded;
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use lofty::config::ParseOptions;
+use nghe_api::common::format::Trait as _;
+use tokio::io::AsyncWriteExt as _;
+use uuid::Uuid;
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::database::Database;
+use crate::file::{self, audio};
+use crate::orm::podcast_channels::{self, Status};
+use crate::orm::podcast_episodes;
+use crate::{Error, config, error};
+
+#[derive(Clone)]
+pub struct Podcast {
+    reqwest: reqwest::Client,
+    config: config::Podcast,
+}
+
+impl Podcast {
+    // Episodes can take a long time to download so the timeout only applies to the connection
+    // and to each read instead of the whole request.
+    const TIMEOUT: Duration = Duration::from_secs(30);
+
+    pub fn new(config: config::Podcast) -> Self {
+        Self {
+            reqwest: reqwest::Client::builder()
+                .connect_timeout(Self::TIMEOUT)
+                .read_timeout(Self::TIMEOUT)
+                .build()
+                .expect("Could not build podcast http client"),
+            config,
+        }
+    }
+
+    fn format(url: &str, content_type: Option<&str>) -> Result<audio::Format, Error> {
+        // The extension is more specific than the content type, e.g. `audio/ogg` is used for both
+        // vorbis and opus.
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let extension = path.rsplit('/').next().and_then(|name| name.rsplit_once('.'));
+        if let Some((_, extension)) = extension
+            && let Ok(format) = audio::Format::from_str(&extension.to_lowercase())
+        {
+            return Ok(format);
+        }
+
+        let content_type = content_type.unwrap_or_default();
+        match content_type.split(';').next().unwrap_or_default().trim() {
+            "audio/flac" | "audio/x-flac" => Ok(audio::Format::Flac),
+            "audio/mpeg" | "audio/mp3" => Ok(audio::Format::Mpeg),
+            "audio/ogg" | "audio/vorbis" => Ok(audio::Format::Vorbis),
+            "audio/opus" => Ok(audio::Format::Opus),
+            "audio/mp4" | "audio/m4a" | "audio/x-m4a" => Ok(audio::Format::Mp4),
+            _ => error::Kind::UnsupportedPodcastEpisodeFormat(content_type.to_owned()).into(),
+        }
+    }
+
+    async fn update(&self, database: &Database, channel_id: Uuid, url: &str) -> Result<(), Error> {
+        let content = self.reqwest.get(url).send().await?.error_for_status()?.text().await?;
+        let feed = feed::Feed::parse(&content)?;
+
+        diesel::update(podcast_channels::table)
+            .filter(podcast_channels::id.eq(channel_id))
+            .set(podcast_channels::Information {
+                title: feed.title.map(Into::into),
+                description: feed.description.map(Into::into),
+                image_url: feed.image_url.map(Into::into),
+            })
+            .execute(&mut database.get().await?)
+            .await?;
+
+        // Entries without an audio file can not be downloaded so they are skipped.
+        let episodes: Vec<_> = feed
+            .entries
+            .into_iter()
+            .filter_map(|entry| {
+                let url = entry.url?;
+                Some(podcast_episodes::Data {
+                    channel_id,
+                    guid: entry.guid.unwrap_or_else(|| url.clone()).into(),
+                    title: entry.title.unwrap_or_else(|| url.clone()).into(),
+                    description: entry.description.map(Into::into),
+                    url: url.into(),
+                    published_at: entry.published_at,
+                    duration: entry.duration.map(Into::into),
+                })
+            })
+            .collect();
+        podcast_episodes::Data::upsert(database, &episodes).await
+    }
+
+    #[cfg_attr(not(coverage_nightly), tracing::instrument(skip(self, database)))]
+    pub async fn refresh_channel(
+        &self,
+        database: &Database,
+        channel_id: Uuid,
+        url: &str,
+    ) -> Result<(), Error> {
+        // Errors from the feed are stored in the channel so they can be shown to the user.
+        let result = self.update(database, channel_id, url).await;
+        if let Err(ref error) = result {
+            tracing::warn!(podcast_refresh_error = ?error);
+        }
+        diesel::update(podcast_channels::table)
+            .filter(podcast_channels::id.eq(channel_id))
+            .set(podcast_channels::State::from_result(&result))
+            .execute(&mut database.get().await?)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn create_channel(&self, database: &Database, url: &str) -> Result<Uuid, Error> {
+        // Subscribing to an existing channel only refreshes it.
+        let channel_id = diesel::insert_into(podcast_channels::table)
+            .values(podcast_channels::Data { url: url.into() })
+            .on_conflict(podcast_channels::url)
+            .do_update()
+            .set(podcast_channels::url.eq(excluded(podcast_channels::url)))
+            .returning(podcast_channels::id)
+            .get_result(&mut database.get().await?)
+            .await?;
+        self.refresh_channel(database, channel_id, url).await?;
+        Ok(channel_id)
+    }
+
+    #[cfg_attr(not(coverage_nightly), tracing::instrument(skip_all, err(Debug)))]
+    pub async fn refresh(&self, database: &Database) -> Result<(), Error> {
+        let channels = podcast_channels::table
+            .select((podcast_channels::id, podcast_channels::url))
+            .get_results::<(Uuid, String)>(&mut database.get().await?)
+            .await?;
+        for (channel_id, url) in channels {
+            self.refresh_channel(database, channel_id, &url).await?;
+        }
+        Ok(())
+    }
+
+    async fn fetch(
+        &self,
+        episode: &podcast_episodes::Download,
+    ) -> Result<podcast_episodes::State<'static>, Error> {
+        let dir =
+            self.config.dir.as_ref().ok_or_else(|| error::Kind::MissingPodcastDirectoryConfig)?;
+
+        let mut response = self.reqwest.get(&episode.url).send().await?.error_for_status()?;
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .map(reqwest::header::HeaderValue::to_str)
+            .transpose()?;
+        let format = Self::format(&episode.url, content_type)?;
+
+        let dir = dir.join(episode.channel_id.to_string());
+        tokio::fs::create_dir_all(&dir).await?;
+        let path = dir.join(episode.id.to_string()).with_extension(format.extension());
+
+        // The episode is written next to its final path and only moved there once it is
+        // complete, so a failed download never leaves a partial file behind.
+        let partial = path.with_extension(concat_string!(format.extension(), ".part"));
+        let result = async {
+            let property = Self::write(&mut response, &partial, format).await?;
+
+            // Not every episode can be parsed, the duration from the feed is used in that case.
+            let audio = {
+                let partial = partial.clone();
+                tokio::task::spawn_blocking(move || {
+                    let mut reader = BufReader::new(std::fs::File::open(partial)?);
+                    format.read_property(&mut reader, ParseOptions::default())
+                })
+                .await?
+            };
+            if let Err(ref error) = audio {
+                tracing::warn!(podcast_property_error = ?error);
+            }
+
+            tokio::fs::rename(&partial, &path).await?;
+            Ok::<_, Error>((property, audio.ok()))
+        }
+        .await;
+        if result.is_err() {
+            // The partial file does not exist if it could not be created in the first place.
+            let _ = tokio::fs::remove_file(&partial).await;
+        }
+        let (property, audio) = result?;
+
+        Ok(podcast_episodes::State {
+            status: Status::Completed,
+            error_message: None,
+            path: Some(path.into_string().into()),
+            file_hash: Some(property.hash.cast_signed()),
+            file_size: Some(property.size.get().cast_signed()),
+            format: Some(format),
+            duration: audio.map(|audio| audio.duration),
+            bitrate: audio.map(|audio| audio.bitrate.cast_signed()),
+        })
+    }
+
+    // Episodes can be large so they are written to the disk chunk by chunk instead of being
+    // buffered in memory.
+    async fn write(
+        response: &mut reqwest::Response,
+        path: impl AsRef<std::path::Path>,
+        format: audio::Format,
+    ) -> Result<file::Property<audio::Format>, Error> {
+        let mut hasher = Xxh3::new();
+        let mut size = 0_usize;
+        let mut writer = tokio::fs::File::create(path).await?;
+        while let Some(chunk) = response.chunk().await? {
+            hasher.update(&chunk);
+            size += chunk.len();
+            writer.write_all(&chunk).await?;
+        }
+        writer.flush().await?;
+        let size =
+            NonZeroU32::new(size.try_into()?).ok_or_else(|| error::Kind::EmptyFileEncountered)?;
+        Ok(file::Property { hash: hasher.digest(), size, format })
+    }
+
+    #[cfg_attr(not(coverage_nightly), tracing::instrument(skip(self, database), err(Debug)))]
+    pub async fn download(&self, database: &Database, episode_id: Uuid) -> Result<(), Error> {
+        let episode = podcast_episodes::table
+            .filter(podcast_episodes::id.eq(episode_id))
+            .select(podcast_episodes::Download::as_select())
+            .get_result(&mut database.get().await?)
+            .await?;
+
+        let result = self.fetch(&episode).await;
+        let state = match result {
+            Ok(ref state) => state,
+            Err(ref error) => {
+                &podcast_episodes::State::status(Status::Error, Some(error.source.to_string()))
+            }
+        };
+        diesel::update(podcast_episodes::table)
+            .filter(podcast_episodes::id.eq(episode_id))
+            .set(state)
+            .execute(&mut database.get().await?)
+            .await?;
+        result.map(|_| ())
+    }
+
+    // Marks an episode as being downloaded, returns `false` if it is already downloaded or being
+    // downloaded.
+    pub async fn start_download(database: &Database, episode_id: Uuid) -> Result<bool, Error> {
+        let status = podcast_episodes::table
+            .filter(podcast_episodes::id.eq(episode_id))
+            .select(podcast_episodes::status)
+            .get_result::<Status>(&mut database.get().await?)
+            .await?;
+        if matches!(status, Status::Downloading | Status::Completed) {
+            return Ok(false);
+        }
+        let updated = diesel::update(podcast_episodes::table)
+            .filter(podcast_episodes::id.eq(episode_id))
+            .filter(podcast_episodes::status.eq(status))
+            .set(podcast_episodes::status.eq(Status::Downloading))
+            .execute(&mut database.get().await?)
+            .await?;
+        Ok(updated > 0)
+    }
+
+    pub async fn delete_episode(database: &Database, episode_id: Uuid) -> Result<(), Error> {
+        let path = podcast_episodes::table
+            .filter(podcast_episodes::id.eq(episode_id))
+            .select(podcast_episodes::path)
+            .get_result::<Option<String>>(&mut database.get().await?)
+            .await?;
+        diesel::update(podcast_episodes::table)
+            .filter(podcast_episodes::id.eq(episode_id))
+            .set(podcast_episodes::State::status(Status::Deleted, None))
+            .execute(&mut database.get().await?)
+            .await?;
+        if let Some(path) = path {
+            Self::remove(tokio::fs::remove_file(path).await)?;
+        }
+        Ok(())
+    }
+
+    pub async fn delete_channel(&self, database: &Database, channel_id: Uuid) -> Result<(), Error> {
+        let deleted = diesel::delete(podcast_channels::table)
+            .filter(podcast_channels::id.eq(channel_id))
+            .execute(&mut database.get().await?)
+            .await?;
+        if deleted == 0 {
+            return error::Kind::NotFound.into();
+        }
+        if let Some(ref dir) = self.config.dir {
+            Self::remove(tokio::fs::remove_dir_all(dir.join(channel_id.to_string())).await)?;
+        }
+        Ok(())
+    }
+
+    // Files that were already removed from the disk are not an error.
+    fn remove(result: std::io::Result<()>) -> Result<(), Error> {
+        match result {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+
+    #[coverage(off)]
+    pub fn spawn(self, database: Database) {
+        if self.config.refresh_interval == 0 {
+            return;
+        }
+        let interval = Duration::from_secs(self.config.refresh_interval);
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                // Errors are already logged by `refresh`.
+                let _ = self.refresh(&database).await;
+            }
+        });
+    }
+}
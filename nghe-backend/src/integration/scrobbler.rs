@@ -0,0 +1,420 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use diesel::{BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use itertools::Itertools;
+use nghe_api::id3;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{lastfm, listenbrainz};
+use crate::database::Database;
+use crate::orm::user_scrobblers::Service;
+use crate::orm::{id3 as orm_id3, plays, scrobble_retries, songs, user_scrobblers};
+use crate::{Error, config, error};
+
+#[derive(Debug, Clone)]
+pub struct Track {
+    pub artist: String,
+    pub title: String,
+    pub album: String,
+    pub duration: u32,
+    pub mbid: Option<Uuid>,
+}
+
+#[derive(Debug)]
+pub struct Listen {
+    pub play_id: Uuid,
+    pub track: Track,
+    pub played_at: OffsetDateTime,
+    pub client: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct Scrobbler {
+    lastfm: Option<lastfm::Client>,
+    listenbrainz: listenbrainz::Client,
+}
+
+impl From<id3::song::Short> for Track {
+    fn from(value: id3::song::Short) -> Self {
+        Self {
+            artist: value.song.artist,
+            title: value.song.title,
+            album: value.album,
+            duration: value.song.duration.whole_seconds().try_into().unwrap_or_default(),
+            mbid: value.song.music_brainz_id,
+        }
+    }
+}
+
+impl Scrobbler {
+    const MAXIMUM_ATTEMPT: i32 = 10;
+    const RETRY_PER_PAGE: i64 = 1000;
+    const RETRY_INTERVAL: Duration = Duration::from_mins(5);
+    const TIMEOUT: Duration = Duration::from_secs(30);
+
+    pub fn new(config: config::Integration) -> Self {
+        let reqwest = reqwest::Client::builder()
+            .timeout(Self::TIMEOUT)
+            .build()
+            .expect("Could not build scrobbler http client");
+        let lastfm =
+            lastfm::Client::new(reqwest.clone(), config.lastfm).filter(lastfm::Client::is_signable);
+        let listenbrainz = listenbrainz::Client::new(reqwest, config.listenbrainz);
+        Self { lastfm, listenbrainz }
+    }
+
+    fn lastfm(&self) -> Result<&lastfm::Client, Error> {
+        self.lastfm.as_ref().ok_or_else(|| error::Kind::ScrobblerNotEnabled(Service::Lastfm).into())
+    }
+
+    pub async fn link(
+        &self,
+        database: &Database,
+        user_id: Uuid,
+        service: Service,
+        token: &str,
+    ) -> Result<String, Error> {
+        let (username, token) = match service {
+            Service::Lastfm => {
+                let session = self.lastfm()?.get_session(token).await?;
+                (session.name, session.key)
+            }
+            Service::Listenbrainz => (
+                self.listenbrainz
+                    .validate_token(token)
+                    .await?
+                    .ok_or_else(|| Error::from(error::Kind::InvalidScrobblerToken(service)))?,
+                token.to_owned(),
+            ),
+        };
+        user_scrobblers::Upsert {
+            user_id,
+            service,
+            username: username.as_str().into(),
+            token: database.encrypt(token),
+        }
+        .upsert(database)
+        .await?;
+        Ok(username)
+    }
+
+    async fn tokens(database: &Database, user_id: Uuid) -> Result<Vec<(Service, String)>, Error> {
+        user_scrobblers::table
+            .filter(user_scrobblers::user_id.eq(user_id))
+            .select(user_scrobblers::Token::as_select())
+            .get_results(&mut database.get().await?)
+            .await?
+            .into_iter()
+            .map(|token| Ok((token.service, String::from_utf8(database.decrypt(token.token)?)?)))
+            .try_collect()
+    }
+
+    async fn tracks(
+        database: &Database,
+        user_id: Uuid,
+        song_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Track>, Error> {
+        orm_id3::song::short::query::with_user_id(user_id)
+            .filter(songs::id.eq_any(song_ids))
+            .get_results(&mut database.get().await?)
+            .await?
+            .into_iter()
+            .map(|song| {
+                let song: id3::song::Short = song.try_into()?;
+                Ok((song.song.id, song.into()))
+            })
+            .try_collect()
+    }
+
+    async fn listens(
+        database: &Database,
+        user_id: Uuid,
+        play_ids: &[Uuid],
+    ) -> Result<Vec<Listen>, Error> {
+        let plays = plays::table
+            .filter(plays::user_id.eq(user_id))
+            .filter(plays::id.eq_any(play_ids))
+            .order_by(plays::played_at)
+            .select((plays::id, plays::History::as_select()))
+            .get_results::<(Uuid, plays::History)>(&mut database.get().await?)
+            .await?;
+        let song_ids: Vec<_> = plays.iter().map(|(_, play)| play.song_id).unique().collect();
+        let tracks = Self::tracks(database, user_id, &song_ids).await?;
+
+        // Songs that are no longer accessible are silently skipped.
+        Ok(plays
+            .into_iter()
+            .filter_map(|(play_id, play)| {
+                tracks.get(&play.song_id).map(|track| Listen {
+                    play_id,
+                    track: track.clone(),
+                    played_at: play.played_at,
+                    client: play.client,
+                })
+            })
+            .collect())
+    }
+
+    // Returns the play ids of the chunks that could not be sent.
+    async fn send(&self, service: Service, token: &str, listens: &[Listen]) -> Vec<Uuid> {
+        let size = match service {
+            Service::Lastfm => lastfm::Client::MAX_SCROBBLE_PER_REQUEST,
+            Service::Listenbrainz => listenbrainz::Client::MAX_LISTEN_PER_REQUEST,
+        };
+
+        let mut failed = vec![];
+        for listens in listens.chunks(size) {
+            let result = match service {
+                Service::Lastfm => match self.lastfm() {
+                    Ok(lastfm) => lastfm.scrobble(token, listens).await,
+                    Err(error) => Err(error),
+                },
+                Service::Listenbrainz => self.listenbrainz.submit(token, listens).await,
+            };
+            if let Err(error) = result {
+                tracing::warn!(?service, n_listen = listens.len(), scrobble_error = ?error);
+                failed.extend(listens.iter().map(|listen| listen.play_id));
+            }
+        }
+        failed
+    }
+
+    #[cfg_attr(not(coverage_nightly), tracing::instrument(skip(self, database), err(Debug)))]
+    pub async fn now_playing(
+        &self,
+        database: &Database,
+        user_id: Uuid,
+        song_id: Uuid,
+        client: Option<&str>,
+    ) -> Result<(), Error> {
+        let tokens = Self::tokens(database, user_id).await?;
+        if tokens.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(track) = Self::tracks(database, user_id, &[song_id]).await?.remove(&song_id) {
+            for (service, token) in tokens {
+                let result = match service {
+                    // A disabled service should not prevent the others from being notified.
+                    Service::Lastfm => match self.lastfm() {
+                        Ok(lastfm) => lastfm.update_now_playing(&token, &track).await,
+                        Err(error) => Err(error),
+                    },
+                    Service::Listenbrainz => {
+                        self.listenbrainz.playing_now(&token, &track, client).await
+                    }
+                };
+                // Now playing events are only meaningful at the moment so they are not retried.
+                if let Err(error) = result {
+                    tracing::warn!(?service, now_playing_error = ?error);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(not(coverage_nightly), tracing::instrument(skip_all, err(Debug)))]
+    pub async fn submit(
+        &self,
+        database: &Database,
+        user_id: Uuid,
+        play_ids: &[Uuid],
+    ) -> Result<(), Error> {
+        let tokens = Self::tokens(database, user_id).await?;
+        if tokens.is_empty() {
+            return Ok(());
+        }
+
+        let listens = Self::listens(database, user_id, play_ids).await?;
+        for (service, token) in tokens {
+            let keys: Vec<_> = self
+                .send(service, &token, &listens)
+                .await
+                .into_iter()
+                .map(|play_id| scrobble_retries::Key { play_id, service })
+                .collect();
+            if !keys.is_empty() {
+                scrobble_retries::Key::insert(database, &keys).await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn spawn_now_playing(
+        &self,
+        database: Database,
+        user_id: Uuid,
+        song_id: Uuid,
+        client: Option<String>,
+    ) {
+        let scrobbler = self.clone();
+        tokio::spawn(async move {
+            // Errors are already logged by `now_playing`.
+            let _ = scrobbler.now_playing(&database, user_id, song_id, client.as_deref()).await;
+        });
+    }
+
+    pub fn spawn_submit(&self, database: Database, user_id: Uuid, play_ids: Vec<Uuid>) {
+        let scrobbler = self.clone();
+        tokio::spawn(async move {
+            // Errors are already logged by `submit` and failed forwards are queued for retry.
+            let _ = scrobbler.submit(&database, user_id, &play_ids).await;
+        });
+    }
+
+    #[cfg_attr(not(coverage_nightly), tracing::instrument(skip_all, err(Debug)))]
+    pub async fn retry(&self, database: &Database) -> Result<(), Error> {
+        // Retries are paginated by their primary key since processed rows are either deleted or
+        // updated in place.
+        let mut after: Option<(Uuid, Service)> = None;
+        loop {
+            let mut query = scrobble_retries::table
+                .inner_join(plays::table)
+                .inner_join(
+                    user_scrobblers::table.on(user_scrobblers::user_id
+                        .eq(plays::user_id)
+                        .and(user_scrobblers::service.eq(scrobble_retries::service))),
+                )
+                .order_by((scrobble_retries::play_id, scrobble_retries::service))
+                .limit(Self::RETRY_PER_PAGE)
+                .select((plays::user_id, user_scrobblers::Token::as_select(), plays::id))
+                .into_boxed();
+            if let Some((play_id, service)) = after {
+                query = query.filter(
+                    scrobble_retries::play_id.gt(play_id).or(scrobble_retries::play_id
+                        .eq(play_id)
+                        .and(scrobble_retries::service.gt(service))),
+                );
+            }
+            let retries = query
+                .get_results::<(Uuid, user_scrobblers::Token, Uuid)>(&mut database.get().await?)
+                .await?;
+            let Some((_, token, play_id)) = retries.last() else {
+                break;
+            };
+            after = Some((*play_id, token.service));
+            let is_last_page = retries.len() < Self::RETRY_PER_PAGE.try_into()?;
+
+            let retries = retries
+                .into_iter()
+                .map(|(user_id, token, play_id)| ((user_id, token.service), (token.token, play_id)))
+                .into_group_map();
+            for ((user_id, service), retries) in retries {
+                // Groups are never empty and all entries of a group share the same token.
+                let token = String::from_utf8(database.decrypt(&retries[0].0)?)?;
+                let play_ids: Vec<_> = retries.into_iter().map(|(_, play_id)| play_id).collect();
+
+                let listens = Self::listens(database, user_id, &play_ids).await?;
+                let failed = self.send(service, &token, &listens).await;
+                diesel::update(scrobble_retries::table)
+                    .filter(scrobble_retries::play_id.eq_any(&failed))
+                    .filter(scrobble_retries::service.eq(service))
+                    .set(scrobble_retries::attempt.eq(scrobble_retries::attempt + 1))
+                    .execute(&mut database.get().await?)
+                    .await?;
+                diesel::delete(scrobble_retries::table)
+                    .filter(scrobble_retries::play_id.eq_any(&play_ids))
+                    .filter(scrobble_retries::play_id.ne_all(&failed))
+                    .filter(scrobble_retries::service.eq(service))
+                    .execute(&mut database.get().await?)
+                    .await?;
+            }
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        let expired = diesel::delete(scrobble_retries::table)
+            .filter(scrobble_retries::attempt.ge(Self::MAXIMUM_ATTEMPT))
+            .execute(&mut database.get().await?)
+            .await?;
+        if expired > 0 {
+            tracing::warn!(expired, "scrobbles were dropped after too many attempts");
+        }
+        Ok(())
+    }
+
+    #[coverage(off)]
+    pub fn spawn(self, database: Database) {
+        tokio::spawn(async move {
+            loop {
+                // Errors are already logged by `retry`.
+                let _ = self.retry(&database).await;
+                tokio::time::sleep(Self::RETRY_INTERVAL).await;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test::{Mock, listenbrainz, mock};
+
+    async fn count_retry(mock: &Mock) -> i64 {
+        scrobble_retries::table.count().get_result(&mut mock.get().await).await.unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_submit_and_retry(#[future(awt)] mock: Mock) {
+        let listenbrainz = listenbrainz::Mock::new().await;
+        let scrobbler = Scrobbler::new(listenbrainz.integration());
+        let user_id = mock.user_id(0).await;
+        scrobbler
+            .link(mock.database(), user_id, Service::Listenbrainz, listenbrainz::Mock::TOKEN)
+            .await
+            .unwrap();
+
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song(2).call().await;
+        let ids: Vec<_> = music_folder.database.keys().copied().collect();
+        let submit = async || {
+            let now = crate::time::now().await;
+            let plays: Vec<_> = ids
+                .iter()
+                .map(|&song_id| plays::Play {
+                    user_id,
+                    song_id,
+                    played_at: now,
+                    client: Some("client".into()),
+                })
+                .collect();
+            let play_ids = plays::Play::insert(&mut *mock.get().await, &plays).await.unwrap();
+            scrobbler.submit(mock.database(), user_id, &play_ids).await.unwrap();
+        };
+
+        scrobbler.now_playing(mock.database(), user_id, ids[0], Some("client")).await.unwrap();
+        submit().await;
+        let submissions = listenbrainz.submissions();
+        assert_eq!(submissions.len(), 2);
+        assert_eq!(submissions[0]["listen_type"], "playing_now");
+        assert_eq!(submissions[1]["listen_type"], "import");
+        assert_eq!(submissions[1]["payload"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            submissions[1]["payload"][0]["track_metadata"]["additional_info"]["media_player"],
+            "client"
+        );
+
+        listenbrainz.set_offline(true);
+        submit().await;
+        assert_eq!(count_retry(&mock).await, 2);
+        scrobbler.retry(mock.database()).await.unwrap();
+        assert_eq!(count_retry(&mock).await, 2);
+        assert_eq!(listenbrainz.submissions().len(), 2);
+
+        listenbrainz.set_offline(false);
+        scrobbler.retry(mock.database()).await.unwrap();
+        assert_eq!(count_retry(&mock).await, 0);
+        let submissions = listenbrainz.submissions();
+        assert_eq!(submissions.len(), 3);
+        assert_eq!(submissions[2]["payload"].as_array().unwrap().len(), 2);
+    }
+}
@@ -85,9 +85,53 @@ pub fn init_tracing(log: &config::Log) -> Result<(), Error> {
 }
 
 #[coverage(off)]
-pub async fn build(config: config::Config) -> Router {
+pub async fn build(config: config::Config) -> Result<Router, Error> {
     let filesystem = filesystem::Filesystem::new(&config.filesystem.tls, &config.filesystem.s3);
+    let scrobbler = integration::Scrobbler::new(config.integration.clone());
     let informant = integration::Informant::new(config.integration).await;
+    let podcast = integration::Podcast::new(config.podcast);
+    let database = database::Database::new(&config.database);
+
+    let aborted = orm::scans::Key::abort_all(&database).await?;
+    if aborted > 0 {
+        tracing::warn!(aborted, "scans were interrupted by the previous shutdown");
+    }
+    let aborted = orm::podcast_episodes::State::abort_all(&database).await?;
+    if aborted > 0 {
+        tracing::warn!(aborted, "podcast downloads were interrupted by the previous shutdown");
+    }
+
+    let scanner_config = scan::scanner::Config {
+        lofty: lofty::config::ParseOptions::default(),
+        scan: config.filesystem.scan,
+        parsing: config.parsing,
+        index: config.index,
+        cover_art: config.cover_art.clone(),
+    };
+    let registry = scan::registry::Registry::default();
+    let subscriber = if config.filesystem.watch.enable {
+        scan::watcher::Watcher::new(
+            database.clone(),
+            filesystem.clone(),
+            scanner_config.clone(),
+            informant.clone(),
+            registry.clone(),
+        )
+        .spawn(config.filesystem.watch)
+        .await?
+    } else {
+        scan::watcher::Subscriber::default()
+    };
+    scan::scheduler::Scheduler::new(
+        database.clone(),
+        filesystem.clone(),
+        scanner_config.clone(),
+        informant.clone(),
+        registry.clone(),
+    )
+    .spawn();
+    scrobbler.clone().spawn(database.clone());
+    podcast.clone().spawn(database.clone());
 
     let backend_middleware = ServiceBuilder::new()
         .layer(RequestDecompressionLayer::new().br(true).gzip(true).zstd(true))
@@ -103,37 +147,37 @@ pub async fn build(config: config::Config) -> Router {
         .layer(CompressionLayer::new().br(true).gzip(true).zstd(true));
 
     let backend_router = Router::new()
-        .merge(route::music_folder::router(filesystem.clone()))
+        .merge(route::music_folder::router(filesystem.clone(), subscriber))
         .merge(route::permission::router())
         .merge(route::user::router())
         .merge(route::media_retrieval::router(
             filesystem.clone(),
-            config.transcode,
+            config.transcode.clone(),
             config.cover_art.clone(),
         ))
-        .merge(route::scan::router(
-            filesystem,
-            scan::scanner::Config {
-                lofty: lofty::config::ParseOptions::default(),
-                scan: config.filesystem.scan,
-                parsing: config.parsing,
-                index: config.index,
-                cover_art: config.cover_art.clone(),
-            },
-            informant.clone(),
-        ))
+        .merge(route::scan::router(filesystem.clone(), scanner_config, informant.clone(), registry))
         .merge(route::bookmarks::router())
-        .merge(route::browsing::router())
+        .merge(route::browsing::router(informant.clone()))
+        .merge(route::history::router())
+        .merge(route::internet_radio::router(config.transcode))
         .merge(route::lists::router())
-        .merge(route::media_annotation::router(config.cover_art, informant))
+        .merge(route::media_annotation::router(
+            config.cover_art.clone(),
+            informant,
+            scrobbler.clone(),
+        ))
         .merge(route::playlists::router())
+        .merge(route::podcasts::router(podcast))
+        .merge(route::scrobbler::router(scrobbler))
         .merge(route::search::router())
+        .merge(route::sharing::router(filesystem, config.cover_art, config.share))
         .merge(route::system::router())
         .merge(route::key::router())
-        .with_state(database::Database::new(&config.database))
+        .with_state(database)
+        .layer(axum::middleware::from_fn(http::xml::layer))
         .layer(backend_middleware);
 
-    Router::new().nest(nghe_api::common::BACKEND_PREFIX, backend_router).fallback_service(
+    Ok(Router::new().nest(nghe_api::common::BACKEND_PREFIX, backend_router).fallback_service(
         Redirect::<axum::body::Body>::permanent(nghe_api::common::FRONTEND_PREFIX.parse().unwrap()),
-    )
+    ))
 }
@@ -19,5 +19,5 @@ async fn main() {
         .await
         .unwrap()
         .tap_io(|tcp_stream| tcp_stream.set_nodelay(true).unwrap());
-    axum::serve(listener, build(config).await).await.unwrap();
+    axum::serve(listener, Box::pin(build(config)).await.unwrap()).await.unwrap();
 }
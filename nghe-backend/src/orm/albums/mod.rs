@@ -1,11 +1,11 @@
-use std::borrow::Cow;
-
 use diesel::prelude::*;
 use uuid::Uuid;
 
 pub use crate::schema::albums::{self, *};
 
 pub mod date;
+pub mod name_date_mbz;
+pub mod release;
 
 #[derive(Debug, Queryable, Selectable, Insertable, AsChangeset)]
 #[diesel(table_name = albums, check_for_backend(crate::orm::Type))]
@@ -21,14 +21,10 @@ pub struct Foreign {
 #[diesel(treat_none_as_null = true)]
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct Data<'a> {
-    pub name: Cow<'a, str>,
-    #[diesel(embed)]
-    pub date: date::Date,
     #[diesel(embed)]
-    pub release_date: date::Release,
+    pub main: name_date_mbz::NameDateMbz<'a>,
     #[diesel(embed)]
-    pub original_release_date: date::OriginalRelease,
-    pub mbz_id: Option<Uuid>,
+    pub release: release::Release<'a>,
 }
 
 #[derive(Debug, Queryable, Selectable, Insertable, AsChangeset)]
@@ -53,7 +49,7 @@ mod upsert {
 
     impl crate::orm::upsert::Insert for Upsert<'_> {
         async fn insert(&self, database: &Database) -> Result<Uuid, Error> {
-            if self.data.mbz_id.is_some() {
+            if self.data.main.mbz_id.is_some() {
                 diesel::insert_into(albums::table)
                     .values(self)
                     .on_conflict((albums::music_folder_id, albums::mbz_id))
@@ -82,6 +78,8 @@ mod upsert {
                     .do_update()
                     .set((
                         albums::cover_art_id.eq(self.foreign.cover_art_id),
+                        albums::sort_name.eq(&self.data.main.sort_name),
+                        &self.data.release,
                         albums::scanned_at.eq(crate::time::now().await),
                     ))
                     .returning(albums::id)
@@ -0,0 +1,22 @@
+use std::borrow::Cow;
+
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::{albums, date};
+
+#[derive(Debug, Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = albums, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub struct NameDateMbz<'a> {
+    pub name: Cow<'a, str>,
+    #[diesel(embed)]
+    pub date: date::Date,
+    #[diesel(embed)]
+    pub release_date: date::Release,
+    #[diesel(embed)]
+    pub original_release_date: date::OriginalRelease,
+    pub mbz_id: Option<Uuid>,
+    pub sort_name: Option<Cow<'a, str>>,
+}
@@ -0,0 +1,24 @@
+use std::borrow::Cow;
+
+use diesel::dsl::sql;
+use diesel::expression::SqlLiteral;
+use diesel::prelude::*;
+use diesel::sql_types;
+
+use super::albums;
+
+#[allow(clippy::struct_field_names)]
+#[derive(Debug, Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = albums, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub struct Release<'a> {
+    #[diesel(select_expression = sql("albums.release_types release_types"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Text>>)]
+    pub release_types: Vec<Cow<'a, str>>,
+    #[diesel(select_expression = sql("albums.record_labels record_labels"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Text>>)]
+    pub record_labels: Vec<Cow<'a, str>>,
+    pub catalog_number: Option<Cow<'a, str>>,
+    pub barcode: Option<Cow<'a, str>>,
+}
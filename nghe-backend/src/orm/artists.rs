@@ -11,6 +11,7 @@ pub use crate::schema::artists::{self, *};
 pub struct Data<'a> {
     pub name: Cow<'a, str>,
     pub mbz_id: Option<Uuid>,
+    pub sort_name: Option<Cow<'a, str>>,
 }
 
 #[derive(Debug, Queryable, Selectable, Insertable, AsChangeset)]
@@ -32,7 +33,9 @@ pub struct Artist<'a> {
 }
 
 mod upsert {
-    use diesel::{DecoratableTarget, ExpressionMethods};
+    use diesel::dsl::case_when;
+    use diesel::upsert::excluded;
+    use diesel::{BoolExpressionMethods, DecoratableTarget, ExpressionMethods, IntoSql, sql_types};
     use diesel_async::RunQueryDsl;
     use uuid::Uuid;
 
@@ -40,14 +43,35 @@ mod upsert {
     use crate::Error;
     use crate::database::Database;
 
-    impl crate::orm::upsert::Insert for Upsert<'_> {
-        async fn insert(&self, database: &Database) -> Result<Uuid, Error> {
+    impl Upsert<'_> {
+        async fn insert_impl(&self, database: &Database, contributor: bool) -> Result<Uuid, Error> {
+            // A contributor without sort name does not remove the sort name (and the index
+            // computed from it) of an existing artist, while song and album artists always
+            // overwrite them with their tags.
+            let sort = || {
+                let keep = excluded(artists::sort_name)
+                    .is_null()
+                    .and(artists::sort_name.is_not_null())
+                    .and(contributor.into_sql::<sql_types::Bool>());
+                (
+                    artists::index
+                        .eq(case_when(keep, artists::index).otherwise(excluded(artists::index))),
+                    artists::sort_name
+                        .eq(case_when(keep, artists::sort_name)
+                            .otherwise(excluded(artists::sort_name))),
+                )
+            };
+
             if self.data.mbz_id.is_some() {
                 diesel::insert_into(artists::table)
                     .values(self)
                     .on_conflict(artists::mbz_id)
                     .do_update()
-                    .set((self, artists::scanned_at.eq(crate::time::now().await)))
+                    .set((
+                        artists::name.eq(excluded(artists::name)),
+                        sort(),
+                        artists::scanned_at.eq(crate::time::now().await),
+                    ))
                     .returning(artists::id)
                     .get_result(&mut database.get().await?)
                     .await
@@ -57,12 +81,22 @@ mod upsert {
                     .on_conflict(artists::name)
                     .filter_target(artists::mbz_id.is_null())
                     .do_update()
-                    .set(artists::scanned_at.eq(crate::time::now().await))
+                    .set((sort(), artists::scanned_at.eq(crate::time::now().await)))
                     .returning(artists::id)
                     .get_result(&mut database.get().await?)
                     .await
             }
             .map_err(Error::from)
         }
+
+        pub async fn insert_contributor(&self, database: &Database) -> Result<Uuid, Error> {
+            self.insert_impl(database, true).await
+        }
+    }
+
+    impl crate::orm::upsert::Insert for Upsert<'_> {
+        async fn insert(&self, database: &Database) -> Result<Uuid, Error> {
+            self.insert_impl(database, false).await
+        }
     }
 }
@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use diesel::SelectableHelper;
+use diesel::dsl::{AsSelect, AssumeNotNull, auto_type};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::file::audio;
+use crate::orm::podcast_channels::Status;
+use crate::orm::podcast_episodes;
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = podcast_episodes, check_for_backend(crate::orm::Type))]
+pub struct Episode<'path> {
+    #[diesel(select_expression = podcast_episodes::path.assume_not_null())]
+    #[diesel(select_expression_type = AssumeNotNull<podcast_episodes::path>)]
+    pub path: Cow<'path, str>,
+    #[diesel(select_expression = podcast_episodes::file_hash.assume_not_null())]
+    #[diesel(select_expression_type = AssumeNotNull<podcast_episodes::file_hash>)]
+    pub file_hash: i64,
+    #[diesel(select_expression = podcast_episodes::file_size.assume_not_null())]
+    #[diesel(select_expression_type = AssumeNotNull<podcast_episodes::file_size>)]
+    pub file_size: i32,
+    #[diesel(select_expression = podcast_episodes::format.assume_not_null())]
+    #[diesel(select_expression_type = AssumeNotNull<podcast_episodes::format>)]
+    pub format: audio::Format,
+}
+
+// Downloaded episodes are available to every user.
+#[auto_type]
+pub fn query<'path>(episode_id: Uuid) -> _ {
+    let completed: Status = Status::Completed;
+    let select_episode: AsSelect<Episode<'path>, crate::orm::Type> = Episode::as_select();
+    podcast_episodes::table
+        .filter(podcast_episodes::id.eq(episode_id))
+        .filter(podcast_episodes::status.eq(completed))
+        .select(select_episode)
+}
@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+use diesel::prelude::*;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+pub use crate::schema::bookmarks::{self, *};
+
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = bookmarks, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+pub struct Data<'a> {
+    pub position: i64,
+    pub comment: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = bookmarks, check_for_backend(crate::orm::Type))]
+pub struct Bookmark {
+    pub song_id: Uuid,
+    pub position: i64,
+    pub comment: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel_async::RunQueryDsl;
+    use uuid::Uuid;
+
+    use super::{Data, bookmarks};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Data<'_> {
+        pub async fn upsert(
+            &self,
+            database: &Database,
+            user_id: Uuid,
+            song_id: Uuid,
+        ) -> Result<(), Error> {
+            diesel::insert_into(bookmarks::table)
+                .values((bookmarks::user_id.eq(user_id), bookmarks::song_id.eq(song_id), self))
+                .on_conflict((bookmarks::user_id, bookmarks::song_id))
+                .do_update()
+                .set(self)
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::directory::Directory;
+pub use crate::schema::directories::{self, *};
+
+#[derive(Debug, Clone, Copy, Insertable)]
+#[diesel(table_name = directories, check_for_backend(super::Type))]
+pub struct Upsert<'a> {
+    pub id: Uuid,
+    pub music_folder_id: Uuid,
+    pub parent_id: Uuid,
+    pub path: &'a str,
+}
+
+impl<'a> Upsert<'a> {
+    // The root is not stored since it shares its id with the music folder.
+    fn new(directory: Directory<'a>) -> Option<Self> {
+        directory.parent().map(|parent| Self {
+            id: directory.id(),
+            music_folder_id: directory.music_folder_id,
+            parent_id: parent.id(),
+            path: directory.path,
+        })
+    }
+}
+
+mod upsert {
+    use std::collections::BTreeSet;
+
+    use diesel::dsl::sql;
+    use diesel::{ExpressionMethods, QueryDsl, sql_types};
+    use diesel_async::RunQueryDsl;
+    use uuid::Uuid;
+
+    use super::{Upsert, directories};
+    use crate::Error;
+    use crate::database::Database;
+    use crate::orm::directory::{self, Directory};
+    use crate::orm::{albums, songs};
+
+    impl Upsert<'_> {
+        // Each row has four parameters and Postgres allows at most 65535 parameters per query.
+        const MAX_ROW_PER_INSERT: usize = 10000;
+
+        // Directories are derived from the paths of the songs, so they are synced as a whole
+        // after the songs of a music folder change.
+        pub async fn sync(database: &Database, music_folder_id: Uuid) -> Result<(), Error> {
+            let paths = songs::table
+                .inner_join(albums::table)
+                .filter(albums::music_folder_id.eq(music_folder_id))
+                .select(sql::<sql_types::Text>(directory::PATH))
+                .distinct()
+                .get_results::<String>(&mut database.get().await?)
+                .await?;
+            let directories: BTreeSet<_> = paths
+                .iter()
+                .flat_map(|path| Directory { music_folder_id, path }.ancestors())
+                .collect();
+            let upserts: Vec<_> = directories.into_iter().filter_map(Upsert::new).collect();
+            let ids: Vec<_> = upserts.iter().map(|upsert| upsert.id).collect();
+
+            diesel::delete(directories::table)
+                .filter(directories::music_folder_id.eq(music_folder_id))
+                .filter(directories::id.ne_all(&ids))
+                .execute(&mut database.get().await?)
+                .await?;
+            for upserts in upserts.chunks(Self::MAX_ROW_PER_INSERT) {
+                diesel::insert_into(directories::table)
+                    .values(upserts)
+                    .on_conflict_do_nothing()
+                    .execute(&mut database.get().await?)
+                    .await?;
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,138 @@
+#![allow(clippy::elidable_lifetime_names)]
+
+use diesel::prelude::*;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::orm::{albums, songs};
+
+// Same as the separators of `PATH`.
+const SEPARATORS: [char; 2] = ['/', '\\'];
+// Same as the path of `Entry::directory` but computed by the database.
+pub const PATH: &str = r"coalesce(substring(songs.relative_path, '^(.*)[/\\]'), '')";
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = songs, check_for_backend(crate::orm::Type))]
+pub struct Entry {
+    pub id: Uuid,
+    #[diesel(select_expression = albums::music_folder_id)]
+    #[diesel(select_expression_type = albums::music_folder_id)]
+    pub music_folder_id: Uuid,
+    pub relative_path: String,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Directory<'a> {
+    pub music_folder_id: Uuid,
+    // Empty for the root of the music folder.
+    pub path: &'a str,
+}
+
+impl Entry {
+    pub fn directory(&self) -> Directory<'_> {
+        Directory {
+            music_folder_id: self.music_folder_id,
+            path: self.relative_path.rsplit_once(SEPARATORS).map_or("", |(path, _)| path),
+        }
+    }
+}
+
+impl<'a> Directory<'a> {
+    pub fn root(music_folder_id: Uuid) -> Self {
+        Self { music_folder_id, path: "" }
+    }
+
+    pub fn id(&self) -> Uuid {
+        // The root shares its id with the music folder while the other directories derive their
+        // ids from their path so they stay the same between scans.
+        if self.path.is_empty() {
+            self.music_folder_id
+        } else {
+            Uuid::new_v5(&self.music_folder_id, self.path.as_bytes())
+        }
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.path.rsplit_once(SEPARATORS).map_or(self.path, |(_, name)| name)
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        if self.path.is_empty() {
+            None
+        } else {
+            Some(Self {
+                music_folder_id: self.music_folder_id,
+                path: self.path.rsplit_once(SEPARATORS).map_or("", |(path, _)| path),
+            })
+        }
+    }
+
+    pub fn ancestors(self) -> impl Iterator<Item = Self> {
+        std::iter::successors(Some(self), Self::parent)
+    }
+
+    // Direct child of this directory on the way to a descendant.
+    pub fn child_to(&self, descendant: Self) -> Option<Self> {
+        descendant.ancestors().find(|directory| directory.parent().as_ref() == Some(self))
+    }
+}
+
+pub mod query {
+    use diesel::dsl::{AsSelect, auto_type};
+    use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+    use uuid::Uuid;
+
+    use super::Entry;
+    use crate::orm::{albums, permission, songs};
+
+    #[auto_type]
+    pub fn with_user_id(user_id: Uuid) -> _ {
+        let permission: permission::with_album = permission::with_album(user_id);
+        let entry: AsSelect<Entry, crate::orm::Type> = Entry::as_select();
+        songs::table.inner_join(albums::table).filter(permission).select(entry)
+    }
+
+    #[auto_type]
+    pub fn with_music_folder<'ids>(user_id: Uuid, music_folder_ids: &'ids [Uuid]) -> _ {
+        let with_user_id: with_user_id = with_user_id(user_id);
+        with_user_id.filter(albums::music_folder_id.eq_any(music_folder_ids))
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("", None, "")]
+    #[case("a", Some(""), "a")]
+    #[case("a/b", Some("a"), "b")]
+    #[case("a/b/c", Some("a/b"), "c")]
+    #[case(r"a\b", Some("a"), "b")]
+    fn test_directory(#[case] path: &str, #[case] parent: Option<&str>, #[case] name: &str) {
+        let music_folder_id = Uuid::new_v4();
+        let directory = Directory { music_folder_id, path };
+        assert_eq!(directory.parent().map(|parent| parent.path), parent);
+        assert_eq!(directory.name(), name);
+        assert_eq!(directory.id(), Directory { music_folder_id, path }.id());
+        assert_eq!(directory.ancestors().last(), Some(Directory::root(music_folder_id)));
+    }
+
+    #[test]
+    fn test_child_to() {
+        let music_folder_id = Uuid::new_v4();
+        let root = Directory::root(music_folder_id);
+        let descendant = Directory { music_folder_id, path: "a/b/c" };
+        assert_eq!(root.child_to(descendant), Some(Directory { music_folder_id, path: "a" }));
+        assert_eq!(
+            Directory { music_folder_id, path: "a" }.child_to(descendant),
+            Some(Directory { music_folder_id, path: "a/b" })
+        );
+        assert_eq!(Directory { music_folder_id, path: "d" }.child_to(descendant), None);
+        assert_eq!(descendant.child_to(descendant), None);
+    }
+}
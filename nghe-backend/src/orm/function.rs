@@ -1,3 +1,8 @@
 use diesel::define_sql_function;
+use diesel::sql_types::{Nullable, Text};
 
+define_sql_function!(fn coalesce(value: Nullable<Text>, fallback: Text) -> Text);
+define_sql_function!(fn lower(string: Text) -> Text);
 define_sql_function!(fn random() -> Bool);
+define_sql_function!(fn regexp_replace(string: Text, pattern: Text, replacement: Text, flags: Text) -> Text);
+define_sql_function!(fn starts_with(string: Text, prefix: Text) -> Bool);
@@ -33,6 +33,7 @@ pub struct Album {
     pub date: albums::date::Date,
     #[diesel(column_name = mbz_id)]
     pub music_brainz_id: Option<Uuid>,
+    pub sort_name: Option<String>,
     #[diesel(embed)]
     pub genres: Genres,
     #[diesel(embed)]
@@ -42,15 +43,50 @@ pub struct Album {
     #[diesel(select_expression = sql("any_value(star_albums.created_at) starred"))]
     #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Timestamptz>>)]
     pub starred: Option<OffsetDateTime>,
+    #[diesel(select_expression = sql("any_value(rating_albums.rating) user_rating"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Int2>>)]
+    pub user_rating: Option<i16>,
+    #[diesel(select_expression = sql(
+        "(select avg(ratings.rating)::float4 from rating_albums ratings \
+        where ratings.album_id = albums.id) average_rating"
+    ))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Float>>)]
+    pub average_rating: Option<f32>,
+    #[diesel(select_expression = sql("albums.release_types release_types"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Text>>)]
+    pub release_types: Vec<String>,
+    #[diesel(select_expression = sql("albums.record_labels record_labels"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Text>>)]
+    pub record_labels: Vec<String>,
+    pub catalog_number: Option<String>,
+    pub barcode: Option<String>,
 }
 
-pub type BuilderSet = builder::SetStarred<
-    builder::SetReleaseDate<
-        builder::SetOriginalReleaseDate<
-            builder::SetGenres<
-                builder::SetMusicBrainzId<
-                    builder::SetYear<
-                        builder::SetCreated<builder::SetCoverArt<builder::SetName<builder::SetId>>>,
+pub type BuilderSet = builder::SetBarcode<
+    builder::SetCatalogNumber<
+        builder::SetRecordLabels<
+            builder::SetReleaseTypes<
+                builder::SetAverageRating<
+                    builder::SetUserRating<
+                        builder::SetStarred<
+                            builder::SetReleaseDate<
+                                builder::SetOriginalReleaseDate<
+                                    builder::SetGenres<
+                                        builder::SetSortName<
+                                            builder::SetMusicBrainzId<
+                                                builder::SetYear<
+                                                    builder::SetCreated<
+                                                        builder::SetCoverArt<
+                                                            builder::SetName<builder::SetId>,
+                                                        >,
+                                                    >,
+                                                >,
+                                            >,
+                                        >,
+                                    >,
+                                >,
+                            >,
+                        >,
                     >,
                 >,
             >,
@@ -67,10 +103,22 @@ impl Album {
             .created(self.created)
             .year(self.date.year.map(u16::try_from).transpose()?)
             .music_brainz_id(self.music_brainz_id)
+            .sort_name(self.sort_name)
             .genres(self.genres.into())
             .original_release_date(self.original_release_date.try_into()?)
             .release_date(self.release_date.try_into()?)
-            .starred(self.starred))
+            .starred(self.starred)
+            .user_rating(self.user_rating.map(u8::try_from).transpose()?)
+            .average_rating(self.average_rating)
+            .release_types(self.release_types)
+            .record_labels(
+                self.record_labels
+                    .into_iter()
+                    .map(|name| id3::album::RecordLabel { name })
+                    .collect(),
+            )
+            .catalog_number(self.catalog_number)
+            .barcode(self.barcode))
     }
 }
 
@@ -78,7 +126,7 @@ pub mod query {
     use diesel::dsl::{AsSelect, auto_type};
 
     use super::*;
-    use crate::orm::{genres, songs_genres, star_albums};
+    use crate::orm::{function, genres, rating_albums, songs_genres, star_albums};
 
     #[auto_type]
     pub fn with_user_id_unchecked_no_group_by(user_id: Uuid) -> _ {
@@ -90,7 +138,12 @@ pub mod query {
                 star_albums::table
                     .on(star_albums::album_id.eq(albums::id).and(star_albums::user_id.eq(user_id))),
             )
-            .order_by(albums::name)
+            .left_join(
+                rating_albums::table.on(rating_albums::album_id
+                    .eq(albums::id)
+                    .and(rating_albums::user_id.eq(user_id))),
+            )
+            .order_by(function::coalesce(albums::sort_name, albums::name))
     }
 
     #[auto_type]
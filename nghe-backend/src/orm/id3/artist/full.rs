@@ -29,11 +29,16 @@ impl Full {
         self,
         database: &Database,
         user_id: Uuid,
+        release_type: Option<String>,
     ) -> Result<id3::artist::Full, Error> {
+        // Release types are stored in lowercase.
+        let release_types: Vec<_> =
+            release_type.map(|release_type| release_type.to_lowercase()).into_iter().collect();
         Ok(id3::artist::Full {
             artist: self.artist.try_into()?,
             album: album::short::query::with_user_id_unchecked(user_id)
                 .filter(albums::id.eq_any(self.albums))
+                .filter(albums::release_types.contains(&release_types))
                 .get_results(&mut database.get().await?)
                 .await?
                 .into_iter()
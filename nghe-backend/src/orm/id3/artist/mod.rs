@@ -14,11 +14,11 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::Error;
-use crate::orm::{albums, artists, songs};
+use crate::orm::{albums, artists, songs, songs_contributors};
 
 #[derive(Debug, Queryable, Selectable)]
 #[diesel(table_name = artists, check_for_backend(crate::orm::Type))]
-#[cfg_attr(test, derive(PartialEq, Eq, fake::Dummy))]
+#[cfg_attr(test, derive(PartialEq, fake::Dummy))]
 pub struct Artist {
     #[diesel(embed)]
     pub required: Required,
@@ -33,13 +33,39 @@ pub struct Artist {
     #[diesel(select_expression = sql("any_value(star_artists.created_at) starred"))]
     #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Timestamptz>>)]
     pub starred: Option<OffsetDateTime>,
+    #[diesel(select_expression = sql("any_value(rating_artists.rating) user_rating"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Int2>>)]
+    #[cfg_attr(test, dummy(expr = "Some(fake::Fake::fake(&(1..=5)))"))]
+    pub user_rating: Option<i16>,
+    #[diesel(select_expression = sql(
+        "(select avg(ratings.rating)::float4 from rating_artists ratings \
+        where ratings.artist_id = artists.id) average_rating"
+    ))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Float>>)]
+    pub average_rating: Option<f32>,
     #[diesel(column_name = mbz_id)]
     pub music_brainz_id: Option<Uuid>,
+    pub sort_name: Option<String>,
+    #[diesel(select_expression = sql(
+        "array(select distinct songs_contributors.role from songs_contributors \
+        where songs_contributors.artist_id = artists.id order by songs_contributors.role) \
+        contributor_roles"
+    ))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Int2>>)]
+    pub contributor_roles: Vec<songs_contributors::Role>,
 }
 
 pub type BuilderSet = builder::SetRoles<
-    builder::SetMusicBrainzId<
-        builder::SetStarred<builder::SetAlbumCount<builder::SetCoverArt<builder::SetRequired>>>,
+    builder::SetSortName<
+        builder::SetMusicBrainzId<
+            builder::SetAverageRating<
+                builder::SetUserRating<
+                    builder::SetStarred<
+                        builder::SetAlbumCount<builder::SetCoverArt<builder::SetRequired>>,
+                    >,
+                >,
+            >,
+        >,
     >,
 >;
 
@@ -52,13 +78,17 @@ impl Artist {
         if self.album_count > 0 {
             roles.push(id3::artist::Role::AlbumArtist);
         }
+        roles.extend(self.contributor_roles.into_iter().map(songs_contributors::Role::into));
 
         Ok(id3::artist::Artist::builder()
             .required(self.required.into())
             .cover_art(self.cover_art)
             .album_count(self.album_count.try_into()?)
             .starred(self.starred)
+            .user_rating(self.user_rating.map(u8::try_from).transpose()?)
+            .average_rating(self.average_rating)
             .music_brainz_id(self.music_brainz_id)
+            .sort_name(self.sort_name)
             .roles(roles))
     }
 }
@@ -76,11 +106,16 @@ pub mod query {
 
     use super::*;
     use crate::orm::{
-        artist_informations, songs_album_artists, songs_artists, star_artists,
+        artist_informations, rating_artists, songs_album_artists, songs_artists, star_artists,
         user_music_folder_permissions,
     };
 
-    diesel::alias!(albums as albums_sa: AlbumsSA, songs as songs_saa: SongsSAA);
+    diesel::alias!(
+        albums as albums_sa: AlbumsSA,
+        songs as songs_saa: SongsSAA,
+        albums as albums_sc: AlbumsSC,
+        songs as songs_sc: SongsSC
+    );
 
     #[auto_type]
     fn with_user_id_unchecked(user_id: Uuid) -> _ {
@@ -107,14 +142,32 @@ pub mod query {
                     .eq(artists::id)
                     .and(star_artists::user_id.eq(user_id))),
             )
+            .left_join(rating_artists::table.on(
+                rating_artists::artist_id.eq(artists::id).and(rating_artists::user_id.eq(user_id)),
+            ))
             .group_by(artists::id)
             .select(artist)
     }
 
+    #[auto_type]
+    fn contributor_music_folder_ids() -> _ {
+        // Artists which only appear as contributors are not joined with any album, so their music
+        // folders are looked up via `songs_contributors` instead.
+        songs_contributors::table
+            .inner_join(songs_sc.on(songs_sc.field(songs::id).eq(songs_contributors::song_id)))
+            .inner_join(
+                albums_sc.on(albums_sc.field(albums::id).eq(songs_sc.field(songs::album_id))),
+            )
+            .filter(songs_contributors::artist_id.eq(artists::id))
+            .select(albums_sc.field(albums::music_folder_id))
+    }
+
     #[auto_type]
     pub fn with_user_id(user_id: Uuid) -> _ {
-        // Permission should be checked against `albums_sa` and `albums`.
+        // Permission should be checked against `albums_sa`, `albums` and `albums_sc`.
         let with_user_id_unchecked: with_user_id_unchecked = with_user_id_unchecked(user_id);
+        let contributor_music_folder_ids: contributor_music_folder_ids =
+            contributor_music_folder_ids();
         with_user_id_unchecked.filter(exists(
             user_music_folder_permissions::table
                 .filter(user_music_folder_permissions::user_id.eq(user_id))
@@ -122,20 +175,28 @@ pub mod query {
                     user_music_folder_permissions::music_folder_id
                         .eq(albums_sa.field(albums::music_folder_id))
                         .or(user_music_folder_permissions::music_folder_id
-                            .eq(albums::music_folder_id)),
+                            .eq(albums::music_folder_id))
+                        .or(user_music_folder_permissions::music_folder_id
+                            .eq_any(contributor_music_folder_ids)),
                 ),
         ))
     }
 
     #[auto_type]
     pub fn with_music_folder<'ids>(user_id: Uuid, music_folder_ids: &'ids [Uuid]) -> _ {
-        // Permission should be checked against `albums_sa` and `albums`.
+        // Permission should be checked against `albums_sa`, `albums` and `albums_sc`.
         let with_user_id: with_user_id = with_user_id(user_id);
+        let contributor_music_folder_ids: contributor_music_folder_ids =
+            contributor_music_folder_ids();
         with_user_id.filter(
             albums_sa
                 .field(albums::music_folder_id)
                 .eq_any(music_folder_ids)
-                .or(albums::music_folder_id.eq_any(music_folder_ids)),
+                .or(albums::music_folder_id.eq_any(music_folder_ids))
+                .or(exists(
+                    contributor_music_folder_ids
+                        .filter(albums_sc.field(albums::music_folder_id).eq_any(music_folder_ids)),
+                )),
         )
     }
 }
@@ -253,17 +314,84 @@ mod tests {
     }
 
     #[rstest]
-    #[case(0, 0, &[])]
-    #[case(1, 0, &[id3::artist::Role::Artist])]
-    #[case(0, 1, &[id3::artist::Role::AlbumArtist])]
-    #[case(1, 1, &[id3::artist::Role::Artist, id3::artist::Role::AlbumArtist])]
+    #[tokio::test]
+    async fn test_query_contributor(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        let music_folder_id = mock.add_music_folder().allow(allow).call().await;
+        let user_id = mock.user_id(0).await;
+
+        let artist: audio::Artist = Faker.fake();
+        let artist_id = artist.upsert_mock(&mock).await;
+        let contributors: audio::Contributors = [
+            audio::Contributor {
+                role: songs_contributors::Role::Composer,
+                sub_role: None,
+                artist: artist.clone(),
+            },
+            audio::Contributor {
+                role: songs_contributors::Role::Conductor,
+                sub_role: None,
+                artist,
+            },
+        ]
+        .into_iter()
+        .collect();
+        mock.music_folder(0).await.add_audio().contributors(contributors).n_song(2).call().await;
+
+        let database_artist = query::with_user_id(user_id)
+            .filter(artists::id.eq(artist_id))
+            .get_result(&mut mock.get().await)
+            .await;
+        let database_artist_music_folder = query::with_music_folder(user_id, &[music_folder_id])
+            .filter(artists::id.eq(artist_id))
+            .get_result(&mut mock.get().await)
+            .await;
+
+        if allow {
+            let database_artist = database_artist.unwrap();
+            assert_eq!(database_artist.song_count, 0);
+            assert_eq!(database_artist.album_count, 0);
+            assert_eq!(
+                database_artist.contributor_roles,
+                &[songs_contributors::Role::Composer, songs_contributors::Role::Conductor]
+            );
+            assert_eq!(database_artist, database_artist_music_folder.unwrap());
+        } else {
+            assert!(database_artist.is_err());
+            assert!(database_artist_music_folder.is_err());
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0, &[], &[])]
+    #[case(1, 0, &[], &[id3::artist::Role::Artist])]
+    #[case(0, 1, &[], &[id3::artist::Role::AlbumArtist])]
+    #[case(1, 1, &[], &[id3::artist::Role::Artist, id3::artist::Role::AlbumArtist])]
+    #[case(0, 0, &[songs_contributors::Role::Composer], &[id3::artist::Role::Composer])]
+    #[case(
+        1,
+        0,
+        &[songs_contributors::Role::Composer, songs_contributors::Role::Performer],
+        &[id3::artist::Role::Artist, id3::artist::Role::Composer, id3::artist::Role::Performer]
+    )]
     fn test_try_into_api(
         #[case] song_count: i64,
         #[case] album_count: i64,
+        #[case] contributor_roles: &[songs_contributors::Role],
         #[case] roles: &[id3::artist::Role],
     ) {
-        let artist: id3::artist::Artist =
-            Artist { song_count, album_count, ..Faker.fake() }.try_into().unwrap();
+        let artist: id3::artist::Artist = Artist {
+            song_count,
+            album_count,
+            contributor_roles: contributor_roles.to_vec(),
+            ..Faker.fake()
+        }
+        .try_into()
+        .unwrap();
         assert_eq!(artist.roles, roles);
     }
 
@@ -0,0 +1,72 @@
+use diesel::deserialize::{self, FromSql};
+use diesel::dsl::sql;
+use diesel::expression::SqlLiteral;
+use diesel::pg::PgValue;
+use diesel::prelude::*;
+use diesel::sql_types;
+use itertools::Itertools;
+use nghe_api::id3;
+use uuid::Uuid;
+
+use crate::orm::id3::artist::required::Required;
+use crate::orm::songs_contributors;
+
+pub type SqlType = sql_types::Record<(
+    sql_types::Int2,
+    sql_types::Nullable<sql_types::Text>,
+    sql_types::Text,
+    sql_types::Uuid,
+)>;
+
+#[derive(Debug)]
+pub struct Contributor {
+    pub role: songs_contributors::Role,
+    pub sub_role: Option<String>,
+    pub artist: Required,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+pub struct Contributors {
+    #[diesel(select_expression = sql(
+        "array(select (songs_contributors.role, nullif(songs_contributors.sub_role, ''), \
+        contributor_artists.name, contributor_artists.id) from songs_contributors \
+        inner join artists contributor_artists \
+        on contributor_artists.id = songs_contributors.artist_id \
+        where songs_contributors.song_id = songs.id \
+        order by songs_contributors.upserted_at, songs_contributors.role) contributors"
+    ))]
+    #[diesel(select_expression_type = SqlLiteral::<sql_types::Array<SqlType>>)]
+    pub value: Vec<Contributor>,
+}
+
+impl Contributors {
+    pub fn display_composer(&self) -> Option<String> {
+        let composer = self
+            .value
+            .iter()
+            .filter(|contributor| contributor.role == songs_contributors::Role::Composer)
+            .map(|contributor| contributor.artist.name.as_str())
+            .join(", ");
+        if composer.is_empty() { None } else { Some(composer) }
+    }
+}
+
+impl From<Contributor> for id3::song::Contributor {
+    fn from(value: Contributor) -> Self {
+        Self { role: value.role.into(), sub_role: value.sub_role, artist: value.artist.into() }
+    }
+}
+
+impl From<Contributors> for Vec<id3::song::Contributor> {
+    fn from(value: Contributors) -> Self {
+        value.value.into_iter().map(Contributor::into).collect()
+    }
+}
+
+impl FromSql<SqlType, crate::orm::Type> for Contributor {
+    fn from_sql(bytes: PgValue) -> deserialize::Result<Self> {
+        let (role, sub_role, name, id): (songs_contributors::Role, Option<String>, String, Uuid) =
+            FromSql::<SqlType, crate::orm::Type>::from_sql(bytes)?;
+        Ok(Self { role, sub_role, artist: Required { id, name } })
+    }
+}
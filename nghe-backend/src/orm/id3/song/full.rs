@@ -102,7 +102,7 @@ mod test {
         if allow {
             let database_song = database_song.unwrap();
             let database_artists: Vec<String> = database_song.short.song.artists.into();
-            assert_eq!(database_song.short.album, album.name);
+            assert_eq!(database_song.short.album, album.main.name);
             assert_eq!(database_song.short.album_id, album_id);
             assert_eq!(database_artists, artists);
             assert_eq!(database_song.genres.value.len(), n_genre);
@@ -1,4 +1,5 @@
 pub mod artists;
+pub mod contributors;
 pub mod durations;
 pub mod full;
 pub mod short;
@@ -44,37 +45,41 @@ pub struct Song {
     pub artists: artists::Artists,
     #[diesel(column_name = mbz_id)]
     pub music_brainz_id: Option<Uuid>,
+    pub sort_name: Option<String>,
     #[diesel(select_expression = sql("any_value(star_songs.created_at) starred"))]
     #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Timestamptz>>)]
     pub starred: Option<OffsetDateTime>,
+    #[diesel(select_expression = sql("any_value(rating_songs.rating) user_rating"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Int2>>)]
+    pub user_rating: Option<i16>,
+    #[diesel(select_expression = sql(
+        "(select avg(ratings.rating)::float4 from rating_songs ratings \
+        where ratings.song_id = songs.id) average_rating"
+    ))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Float>>)]
+    pub average_rating: Option<f32>,
+    #[diesel(embed)]
+    pub replay_gain: songs::replay_gain::ReplayGain,
+    #[diesel(embed)]
+    pub loudness: songs::loudness::Loudness,
+    #[diesel(embed)]
+    pub contributors: contributors::Contributors,
 }
 
-pub type BuilderSet = builder::SetStarred<
-    builder::SetMusicBrainzId<
-        builder::SetArtists<
-            builder::SetArtistId<
-                builder::SetArtist<
-                    builder::SetCreated<
-                        builder::SetDiscNumber<
-                            builder::SetChannelCount<
-                                builder::SetSamplingRate<
-                                    builder::SetBitDepth<
-                                        builder::SetBitRate<
-                                            builder::SetDuration<
-                                                builder::SetSuffix<
-                                                    builder::SetContentType<
-                                                        builder::SetSize<
-                                                            builder::SetCoverArt<
-                                                                builder::SetYear<
-                                                                    builder::SetTrack<
-                                                                        builder::SetTitle<
-                                                                            builder::SetId,
-                                                                        >,
-                                                                    >,
-                                                                >,
-                                                            >,
-                                                        >,
-                                                    >,
+type PropertyBuilderSet = builder::SetCreated<
+    builder::SetDiscNumber<
+        builder::SetChannelCount<
+            builder::SetSamplingRate<
+                builder::SetBitDepth<
+                    builder::SetBitRate<
+                        builder::SetDuration<
+                            builder::SetSuffix<
+                                builder::SetContentType<
+                                    builder::SetSize<
+                                        builder::SetCoverArt<
+                                            builder::SetYear<
+                                                builder::SetTrack<
+                                                    builder::SetTitle<builder::SetId>,
                                                 >,
                                             >,
                                         >,
@@ -89,6 +94,26 @@ pub type BuilderSet = builder::SetStarred<
     >,
 >;
 
+pub type BuilderSet = builder::SetDisplayComposer<
+    builder::SetContributors<
+        builder::SetReplayGain<
+            builder::SetAverageRating<
+                builder::SetUserRating<
+                    builder::SetStarred<
+                        builder::SetSortName<
+                            builder::SetMusicBrainzId<
+                                builder::SetArtists<
+                                    builder::SetArtistId<builder::SetArtist<PropertyBuilderSet>>,
+                                >,
+                            >,
+                        >,
+                    >,
+                >,
+            >,
+        >,
+    >,
+>;
+
 impl audio::duration::Trait for Song {
     fn duration(&self) -> audio::Duration {
         self.property.duration.duration()
@@ -98,6 +123,7 @@ impl audio::duration::Trait for Song {
 impl Song {
     pub fn try_into_builder(self) -> Result<builder::Builder<BuilderSet>, Error> {
         let duration = self.duration();
+        let display_composer = self.contributors.display_composer();
         let main_artist =
             self.artists.value.first().ok_or_else(|| error::Kind::DatabaseCorruptionDetected)?;
         Ok(id3::song::Song::builder()
@@ -120,7 +146,15 @@ impl Song {
             .artist_id(main_artist.id)
             .artists(self.artists.into())
             .music_brainz_id(self.music_brainz_id)
-            .starred(self.starred))
+            .sort_name(self.sort_name)
+            .starred(self.starred)
+            .user_rating(self.user_rating.map(u8::try_from).transpose()?)
+            .average_rating(self.average_rating)
+            .replay_gain(
+                audio::ReplayGain::from(self.replay_gain).or_loudness(self.loudness).into(),
+            )
+            .contributors(self.contributors.into())
+            .display_composer(display_composer))
     }
 }
 
@@ -143,7 +177,7 @@ pub mod query {
 
     use super::*;
     use crate::orm::id3::artist;
-    use crate::orm::{albums, songs_artists, star_songs};
+    use crate::orm::{albums, rating_songs, songs_artists, star_songs};
 
     #[auto_type]
     pub fn with_user_id_unchecked_no_group_by(user_id: Uuid) -> _ {
@@ -155,6 +189,10 @@ pub mod query {
                 star_songs::table
                     .on(star_songs::song_id.eq(songs::id).and(star_songs::user_id.eq(user_id))),
             )
+            .left_join(
+                rating_songs::table
+                    .on(rating_songs::song_id.eq(songs::id).and(rating_songs::user_id.eq(user_id))),
+            )
             .order_by((
                 songs::disc_number.asc().nulls_first(),
                 songs::track_number.asc().nulls_first(),
@@ -0,0 +1,61 @@
+use std::borrow::Cow;
+
+use diesel::prelude::*;
+use nghe_api::internet_radio::InternetRadioStation;
+use o2o::o2o;
+use uuid::Uuid;
+
+pub use crate::schema::internet_radio_stations::{self, *};
+use crate::{Error, error};
+
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = internet_radio_stations, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+pub struct Data<'a> {
+    pub name: Cow<'a, str>,
+    pub stream_url: Cow<'a, str>,
+    pub homepage_url: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Queryable, Selectable, o2o)]
+#[owned_into(InternetRadioStation)]
+#[diesel(table_name = internet_radio_stations, check_for_backend(crate::orm::Type))]
+pub struct Station {
+    pub id: Uuid,
+    pub name: String,
+    pub stream_url: String,
+    pub homepage_url: Option<String>,
+}
+
+impl Data<'_> {
+    pub fn validate(self) -> Result<Self, Error> {
+        // The stream url is opened by ffmpeg when proxying, which would otherwise also accept
+        // local files and other protocols.
+        if reqwest::Url::parse(&self.stream_url)
+            .is_ok_and(|value| matches!(value.scheme(), "http" | "https"))
+        {
+            Ok(self)
+        } else {
+            error::Kind::InvalidInternetRadioUrl(self.stream_url.into_owned()).into()
+        }
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("https://radio.example/stream", true)]
+    #[case("http://radio.example:8000/stream.mp3", true)]
+    #[case("file:///etc/passwd", false)]
+    #[case("/etc/passwd", false)]
+    #[case("rtmp://radio.example/stream", false)]
+    fn test_validate(#[case] url: &str, #[case] valid: bool) {
+        let data = Data { name: "radio".into(), stream_url: url.into(), homepage_url: None };
+        assert_eq!(data.validate().is_ok(), valid);
+    }
+}
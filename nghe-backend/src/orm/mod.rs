@@ -2,23 +2,41 @@ pub mod albums;
 pub mod artist_informations;
 pub mod artists;
 pub mod binary;
+pub mod bookmarks;
 pub mod configs;
 pub mod cover_arts;
+pub mod directories;
+pub mod directory;
 pub mod function;
 pub mod genres;
 pub mod id3;
+pub mod internet_radio_stations;
 pub mod lyrics;
 pub mod music_folders;
+pub mod now_playings;
 pub mod permission;
 pub mod playbacks;
 pub mod playlist;
 pub mod playlists;
+pub mod playlists_rules;
 pub mod playlists_songs;
 pub mod playlists_users;
 pub mod playqueues;
+pub mod plays;
+pub mod podcast_channels;
+pub mod podcast_episodes;
+pub mod rating_albums;
+pub mod rating_artists;
+pub mod rating_songs;
+pub mod scans;
+pub mod scrobble_retries;
+pub mod shares;
+pub mod similar_artists;
+pub mod similar_songs;
 pub mod songs;
 pub mod songs_album_artists;
 pub mod songs_artists;
+pub mod songs_contributors;
 pub mod songs_genres;
 pub mod star_albums;
 pub mod star_artists;
@@ -26,6 +44,7 @@ pub mod star_songs;
 pub mod upsert;
 pub mod user_keys;
 pub mod user_music_folder_permissions;
+pub mod user_scrobblers;
 pub mod users;
 
 pub type Type = diesel::pg::Pg;
@@ -1,3 +1,4 @@
+pub mod schedule;
 pub mod stat;
 
 use std::borrow::Cow;
@@ -104,5 +105,14 @@ mod query {
                 .await
                 .map_err(Error::from)
         }
+
+        pub async fn query_local(database: &Database) -> Result<Vec<Self>, Error> {
+            music_folders::table
+                .filter(music_folders::fs_type.eq(super::FilesystemType::Local))
+                .select(Self::as_select())
+                .get_results(&mut database.get().await?)
+                .await
+                .map_err(Error::from)
+        }
     }
 }
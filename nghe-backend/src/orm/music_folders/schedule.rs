@@ -0,0 +1,169 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use color_eyre::eyre::OptionExt;
+use diesel::prelude::*;
+use nghe_api::scan;
+use o2o::o2o;
+use time::OffsetDateTime;
+
+use super::{MusicFolder, music_folders};
+use crate::{Error, error};
+
+#[derive(Debug, Default, Clone, Copy, Queryable, Selectable, AsChangeset, o2o)]
+#[diesel(table_name = music_folders, check_for_backend(crate::orm::Type))]
+#[map_owned(scan::start::Full)]
+pub struct Full {
+    #[diesel(column_name = scan_full_file)]
+    pub file: bool,
+    #[diesel(column_name = scan_full_external_lyric)]
+    pub external_lyric: bool,
+    #[diesel(column_name = scan_full_dir_image)]
+    pub dir_image: bool,
+    #[diesel(column_name = scan_full_information)]
+    pub information: bool,
+    #[diesel(column_name = scan_full_loudness)]
+    pub loudness: bool,
+}
+
+#[derive(Debug, Default, Queryable, Selectable, AsChangeset)]
+#[diesel(table_name = music_folders, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+pub struct Schedule<'a> {
+    #[diesel(column_name = scan_cron)]
+    pub cron: Option<Cow<'a, str>>,
+    #[diesel(column_name = scan_interval)]
+    pub interval: Option<i64>,
+    #[diesel(embed)]
+    pub full: Full,
+    #[diesel(column_name = scan_next_at)]
+    pub next_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = music_folders, check_for_backend(crate::orm::Type))]
+pub struct Scheduled<'a> {
+    #[diesel(embed)]
+    pub music_folder: MusicFolder<'a>,
+    #[diesel(embed)]
+    pub schedule: Schedule<'a>,
+}
+
+impl<'a> Schedule<'a> {
+    pub fn new(
+        schedule: &'a scan::set_scan_schedule::Schedule,
+        full: scan::start::Full,
+        after: OffsetDateTime,
+    ) -> Result<Self, Error> {
+        let mut schedule = match schedule {
+            scan::set_scan_schedule::Schedule::Cron(cron) => {
+                Self { cron: Some(cron.into()), full: full.into(), ..Default::default() }
+            }
+            scan::set_scan_schedule::Schedule::Interval(interval) => {
+                if *interval == 0 {
+                    return error::Kind::InvalidScanSchedule("interval must be positive".into())
+                        .into();
+                }
+                Self { interval: Some((*interval).into()), full: full.into(), ..Default::default() }
+            }
+        };
+        schedule.next_at = schedule.next(after)?;
+        Ok(schedule)
+    }
+
+    pub fn next(&self, after: OffsetDateTime) -> Result<Option<OffsetDateTime>, Error> {
+        if let Some(ref cron) = self.cron {
+            // Cron expressions are evaluated in UTC.
+            let cron = croner::Cron::from_str(cron)
+                .map_err(|error| error::Kind::InvalidScanSchedule(error.to_string()))?;
+            let after = chrono::DateTime::from_timestamp(after.unix_timestamp(), 0)
+                .ok_or_eyre("Could not convert scan schedule time")?;
+            let next = cron
+                .find_next_occurrence(&after, false)
+                .map_err(|error| error::Kind::InvalidScanSchedule(error.to_string()))?;
+            Ok(Some(OffsetDateTime::from_unix_timestamp(next.timestamp())?))
+        } else if let Some(interval) = self.interval {
+            // Schedules do not need a precision finer than a second.
+            Ok(Some((after + time::Duration::seconds(interval)).replace_nanosecond(0)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl TryFrom<Scheduled<'_>> for scan::get_scan_schedules::ScanSchedule {
+    type Error = Error;
+
+    fn try_from(value: Scheduled<'_>) -> Result<Self, Self::Error> {
+        let Schedule { cron, interval, full, next_at } = value.schedule;
+        let schedule = if let Some(cron) = cron {
+            scan::set_scan_schedule::Schedule::Cron(cron.into_owned())
+        } else {
+            scan::set_scan_schedule::Schedule::Interval(
+                interval
+                    .ok_or_eyre("Scan schedule should have either cron or interval")?
+                    .try_into()?,
+            )
+        };
+        Ok(Self {
+            music_folder_id: value.music_folder.id,
+            schedule,
+            full: full.into(),
+            next_scan_at: next_at
+                .ok_or_eyre("Scheduled music folder should have next scan time")?,
+        })
+    }
+}
+
+mod query {
+    use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+    use diesel_async::RunQueryDsl;
+    use time::OffsetDateTime;
+
+    use super::{Scheduled, music_folders};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Scheduled<'static> {
+        pub async fn query_due(
+            database: &Database,
+            now: OffsetDateTime,
+        ) -> Result<Vec<Self>, Error> {
+            music_folders::table
+                .filter(music_folders::scan_next_at.le(now))
+                .select(Self::as_select())
+                .get_results(&mut database.get().await?)
+                .await
+                .map_err(Error::from)
+        }
+
+        pub async fn query_next_at(database: &Database) -> Result<Option<OffsetDateTime>, Error> {
+            music_folders::table
+                .select(diesel::dsl::min(music_folders::scan_next_at))
+                .get_result(&mut database.get().await?)
+                .await
+                .map_err(Error::from)
+        }
+    }
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel_async::RunQueryDsl;
+    use uuid::Uuid;
+
+    use super::{Schedule, music_folders};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Schedule<'_> {
+        pub async fn update(&self, database: &Database, id: Uuid) -> Result<(), Error> {
+            diesel::update(music_folders::table)
+                .filter(music_folders::id.eq(id))
+                .set(self)
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+}
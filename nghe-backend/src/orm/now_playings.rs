@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+
+use diesel::prelude::*;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+pub use crate::schema::now_playings::{self, *};
+
+// Clients are expected to report the playing song again at least once per song.
+pub const EXPIRY: Duration = Duration::minutes(30);
+
+#[derive(Insertable, AsChangeset)]
+#[diesel(table_name = now_playings, check_for_backend(crate::orm::Type))]
+pub struct Upsert<'a> {
+    pub user_id: Uuid,
+    pub client: Cow<'a, str>,
+    pub song_id: Uuid,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = now_playings, check_for_backend(crate::orm::Type))]
+pub struct NowPlaying {
+    pub client: String,
+    pub player_id: i32,
+    pub song_id: Uuid,
+    pub updated_at: OffsetDateTime,
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel_async::RunQueryDsl;
+
+    use super::{EXPIRY, Upsert, now_playings};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Upsert<'_> {
+        pub async fn upsert(&self, database: &Database) -> Result<(), Error> {
+            // Expired rows are purged on write so the table does not grow unbounded.
+            diesel::delete(now_playings::table)
+                .filter(now_playings::updated_at.le(self.updated_at - EXPIRY))
+                .execute(&mut database.get().await?)
+                .await?;
+            diesel::insert_into(now_playings::table)
+                .values(self)
+                .on_conflict((now_playings::user_id, now_playings::client))
+                .do_update()
+                .set(self)
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+}
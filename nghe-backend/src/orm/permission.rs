@@ -21,3 +21,13 @@ pub fn with_music_folder(user_id: Uuid) -> _ {
             .filter(user_music_folder_permissions::music_folder_id.eq(music_folders::id)),
     )
 }
+
+#[auto_type]
+pub fn with_music_folder_owner(user_id: Uuid) -> _ {
+    exists(
+        user_music_folder_permissions::table
+            .filter(user_music_folder_permissions::user_id.eq(user_id))
+            .filter(user_music_folder_permissions::music_folder_id.eq(music_folders::id))
+            .filter(user_music_folder_permissions::owner),
+    )
+}
@@ -16,14 +16,16 @@ pub struct Scrobble {
 mod upsert {
     use diesel::ExpressionMethods;
     use diesel::upsert::excluded;
-    use diesel_async::RunQueryDsl;
+    use diesel_async::{AsyncPgConnection, RunQueryDsl};
 
     use super::{Scrobble, playbacks};
     use crate::Error;
-    use crate::database::Database;
 
     impl Scrobble {
-        pub async fn upsert(database: &Database, values: &[Self]) -> Result<(), Error> {
+        pub async fn upsert(
+            connection: &mut AsyncPgConnection,
+            values: &[Self],
+        ) -> Result<(), Error> {
             diesel::insert_into(playbacks::table)
                 .values(values)
                 .on_conflict((playbacks::user_id, playbacks::song_id))
@@ -32,7 +34,7 @@ mod upsert {
                     playbacks::count.eq(playbacks::count + 1),
                     playbacks::updated_at.eq(excluded(playbacks::updated_at)),
                 ))
-                .execute(&mut database.get().await?)
+                .execute(connection)
                 .await?;
             Ok(())
         }
@@ -31,13 +31,17 @@ impl Full {
         database: &Database,
         user_id: Uuid,
     ) -> Result<playlist::Full, Error> {
-        let entry = song::short::query::with_user_id_unchecked(user_id)
-            .inner_join(playlists_songs::table)
-            .filter(songs::id.eq_any(self.entries))
-            .filter(playlists_songs::playlist_id.eq(self.playlist.id))
-            .order_by(sql::<sql_types::Timestamptz>("any_value(playlists_songs.created_at)"))
-            .get_results(&mut database.get().await?)
-            .await?;
+        let entry = if self.playlist.smart {
+            super::smart::query(database, user_id, self.playlist.id).await?
+        } else {
+            song::short::query::with_user_id_unchecked(user_id)
+                .inner_join(playlists_songs::table)
+                .filter(songs::id.eq_any(self.entries))
+                .filter(playlists_songs::playlist_id.eq(self.playlist.id))
+                .order_by(sql::<sql_types::Timestamptz>("any_value(playlists_songs.created_at)"))
+                .get_results(&mut database.get().await?)
+                .await?
+        };
         let duration = entry.duration();
         let entry: Vec<_> = entry.into_iter().map(song::short::Short::try_into).try_collect()?;
 
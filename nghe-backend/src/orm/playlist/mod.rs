@@ -1,6 +1,7 @@
 pub mod full;
 pub mod permission;
 pub mod short;
+pub mod smart;
 
 use diesel::dsl::sql;
 use diesel::expression::SqlLiteral;
@@ -20,6 +21,20 @@ pub struct Playlist {
     pub name: String,
     pub comment: Option<String>,
     pub public: bool,
+    #[diesel(select_expression = sql(
+        "(select users.username from playlists_users owners inner join users on \
+        users.id = owners.user_id where owners.playlist_id = playlists.id and owners.owner) owner"
+    ))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Text>)]
+    pub owner: String,
+    #[diesel(select_expression = sql(
+        "exists(select 1 from playlists_rules rules where rules.playlist_id = playlists.id) smart"
+    ))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Bool>)]
+    pub smart: bool,
+    #[diesel(select_expression = sql("bool_or(playlists_users.write) write"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Bool>)]
+    pub write: bool,
     #[diesel(column_name = created_at)]
     pub created: OffsetDateTime,
     #[diesel(select_expression = sql(
@@ -30,7 +45,13 @@ pub struct Playlist {
 }
 
 pub type BuilderSet = builder::SetChanged<
-    builder::SetCreated<builder::SetPublic<builder::SetComment<builder::SetName<builder::SetId>>>>,
+    builder::SetCreated<
+        builder::SetReadonly<
+            builder::SetOwner<
+                builder::SetPublic<builder::SetComment<builder::SetName<builder::SetId>>>,
+            >,
+        >,
+    >,
 >;
 
 impl Playlist {
@@ -40,6 +61,8 @@ impl Playlist {
             .name(self.name)
             .comment(self.comment)
             .public(self.public)
+            .owner(self.owner)
+            .readonly(self.smart || !self.write)
             .created(self.created)
             .changed(self.changed)
     }
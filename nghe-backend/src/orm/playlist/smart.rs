@@ -0,0 +1,324 @@
+#![allow(clippy::too_many_lines)]
+
+use std::collections::HashMap;
+
+use diesel::dsl::{IntoBoxed, auto_type, exists, max};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::id3::song;
+use crate::orm::playlists_rules::{self, Sort};
+use crate::orm::{
+    artists, function, genres, permission, playbacks, rating_songs, songs, songs_artists,
+    songs_genres, star_songs,
+};
+use crate::{Error, error};
+
+pub async fn is_smart(database: &Database, playlist_id: Uuid) -> Result<bool, Error> {
+    diesel::select(exists(
+        playlists_rules::table.filter(playlists_rules::playlist_id.eq(playlist_id)),
+    ))
+    .get_result(&mut database.get().await?)
+    .await
+    .map_err(Error::from)
+}
+
+pub async fn rules(database: &Database, playlist_id: Uuid) -> Result<playlists_rules::Data, Error> {
+    playlists_rules::table
+        .filter(playlists_rules::playlist_id.eq(playlist_id))
+        .select(playlists_rules::Data::as_select())
+        .get_result(&mut database.get().await?)
+        .await
+        .map_err(Error::from)
+}
+
+pub async fn query(
+    database: &Database,
+    user_id: Uuid,
+    playlist_id: Uuid,
+) -> Result<Vec<song::short::Short>, Error> {
+    let rules = rules(database, playlist_id).await?;
+    query_rules(database, user_id, &rules).await
+}
+
+pub async fn query_rules(
+    database: &Database,
+    user_id: Uuid,
+    rules: &playlists_rules::Data,
+) -> Result<Vec<song::short::Short>, Error> {
+    let now = crate::time::now().await;
+    let song_ids: Vec<Uuid> =
+        song_ids(user_id, rules, now).get_results(&mut database.get().await?).await?;
+    let mut songs: HashMap<_, _> = song::short::query::with_user_id(user_id)
+        .filter(songs::id.eq_any(&song_ids))
+        .get_results(&mut database.get().await?)
+        .await?
+        .into_iter()
+        .map(|song| (song.song.id, song))
+        .collect();
+    Ok(song_ids.into_iter().filter_map(|song_id| songs.remove(&song_id)).collect())
+}
+
+pub async fn durations(
+    database: &Database,
+    user_id: Uuid,
+    rules: &playlists_rules::Data,
+) -> Result<song::durations::Durations, Error> {
+    // Only the aggregate is computed so the songs themselves are never loaded.
+    let now = crate::time::now().await;
+    songs::table
+        .filter(songs::id.eq_any(song_ids(user_id, rules, now)))
+        .select(song::durations::Durations::as_select())
+        .get_result(&mut database.get().await?)
+        .await
+        .map_err(Error::from)
+}
+
+#[auto_type]
+fn with_user_id(user_id: Uuid) -> _ {
+    let with_user_id_unchecked_no_group_by: song::query::with_user_id_unchecked_no_group_by =
+        song::query::with_user_id_unchecked_no_group_by(user_id);
+    let permission: permission::with_album = permission::with_album(user_id);
+    with_user_id_unchecked_no_group_by
+        .left_join(
+            playbacks::table
+                .on(playbacks::song_id.eq(songs::id).and(playbacks::user_id.eq(user_id))),
+        )
+        .filter(permission)
+        .group_by(songs::id)
+        .select(songs::id)
+}
+
+// Ordered ids of the songs matching the rules, which can be used as a subquery.
+fn song_ids(
+    user_id: Uuid,
+    rules: &playlists_rules::Data,
+    now: OffsetDateTime,
+) -> IntoBoxed<'_, with_user_id, crate::orm::Type> {
+    let cutoff = |days: i32| now - Duration::days(days.into());
+
+    let mut query = with_user_id(user_id).into_boxed();
+
+    if !rules.genres.is_empty() {
+        query = query.filter(exists(
+            songs_genres::table
+                .inner_join(genres::table)
+                .filter(songs_genres::song_id.eq(songs::id))
+                .filter(genres::value.eq_any(&rules.genres)),
+        ));
+    }
+    if !rules.artists.is_empty() {
+        // `songs_artists` and `artists` are already joined for the artists of each song.
+        let songs_artists_rule = diesel::alias!(songs_artists as songs_artists_rule);
+        let artists_rule = diesel::alias!(artists as artists_rule);
+        query = query.filter(exists(
+            songs_artists_rule
+                .filter(songs_artists_rule.field(songs_artists::song_id).eq(songs::id))
+                .filter(
+                    songs_artists_rule.field(songs_artists::artist_id).eq_any(
+                        artists_rule
+                            .filter(artists_rule.field(artists::name).eq_any(&rules.artists))
+                            .select(artists_rule.field(artists::id)),
+                    ),
+                ),
+        ));
+    }
+    if !rules.formats.is_empty() {
+        query = query.filter(songs::format.eq_any(&rules.formats));
+    }
+
+    if let Some(from_year) = rules.from_year {
+        query = query.filter(songs::year.ge(from_year));
+    }
+    if let Some(to_year) = rules.to_year {
+        query = query.filter(songs::year.le(to_year));
+    }
+    if let Some(min_bit_depth) = rules.min_bit_depth {
+        query = query.filter(songs::bit_depth.ge(min_bit_depth));
+    }
+    if let Some(max_bit_depth) = rules.max_bit_depth {
+        query = query.filter(songs::bit_depth.le(max_bit_depth));
+    }
+
+    // A song without any playback has a play count of zero and has never been played.
+    if let Some(min_play_count) = rules.min_play_count
+        && min_play_count > 0
+    {
+        query = query.filter(playbacks::count.ge(min_play_count));
+    }
+    if let Some(max_play_count) = rules.max_play_count {
+        query = query.filter(playbacks::count.is_null().or(playbacks::count.le(max_play_count)));
+    }
+    if let Some(days) = rules.played_within_days {
+        query = query.filter(playbacks::updated_at.ge(cutoff(days)));
+    }
+    if let Some(days) = rules.not_played_within_days {
+        query = query
+            .filter(playbacks::updated_at.is_null().or(playbacks::updated_at.lt(cutoff(days))));
+    }
+
+    if let Some(starred) = rules.starred {
+        query = if starred {
+            query.filter(star_songs::created_at.is_not_null())
+        } else {
+            query.filter(star_songs::created_at.is_null())
+        };
+    }
+    if let Some(min_rating) = rules.min_rating {
+        query = query.filter(rating_songs::rating.ge(min_rating));
+    }
+    if let Some(days) = rules.added_within_days {
+        query = query.filter(songs::created_at.ge(cutoff(days)));
+    }
+
+    query = match (rules.sort, rules.descending) {
+        (Sort::Title, false) => query.order_by(songs::title.asc()),
+        (Sort::Title, true) => query.order_by(songs::title.desc()),
+        (Sort::Year, false) => query.order_by(songs::year.asc().nulls_last()),
+        (Sort::Year, true) => query.order_by(songs::year.desc().nulls_last()),
+        (Sort::Added, false) => query.order_by(songs::created_at.asc()),
+        (Sort::Added, true) => query.order_by(songs::created_at.desc()),
+        (Sort::PlayCount, false) => query.order_by(max(playbacks::count).asc().nulls_first()),
+        (Sort::PlayCount, true) => query.order_by(max(playbacks::count).desc().nulls_last()),
+        (Sort::LastPlayed, false) => query.order_by(max(playbacks::updated_at).asc().nulls_first()),
+        (Sort::LastPlayed, true) => query.order_by(max(playbacks::updated_at).desc().nulls_last()),
+        (Sort::Rating, false) => query.order_by(max(rating_songs::rating).asc().nulls_first()),
+        (Sort::Rating, true) => query.order_by(max(rating_songs::rating).desc().nulls_last()),
+        (Sort::Random, _) => query.order_by(function::random()),
+    };
+    if !matches!(rules.sort, Sort::Title | Sort::Random) {
+        query = query.then_order_by(songs::title.asc());
+    }
+
+    if let Some(song_limit) = rules.song_limit {
+        query = query.limit(song_limit.into());
+    }
+
+    query
+}
+
+pub fn validate(rules: &playlists_rules::Data) -> Result<(), Error> {
+    if let Some(min_rating) = rules.min_rating
+        && !(1..=5).contains(&min_rating)
+    {
+        return error::Kind::InvalidRating(min_rating.try_into()?).into();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use indexmap::IndexSet;
+    use nghe_api::playlists::rules::{Rules, Sort as ApiSort};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::orm::upsert::{Insert as _, Update as _};
+    use crate::orm::{playlists, star_songs};
+    use crate::route::media_annotation::set_rating;
+    use crate::test::{Mock, mock};
+
+    async fn song_ids(mock: &Mock, user_id: Uuid, rules: Rules) -> Vec<Uuid> {
+        let rules: playlists_rules::Data = rules.try_into().unwrap();
+        query_rules(mock.database(), user_id, &rules)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|song| song.song.id)
+            .collect()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_query_music_folder(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().allow(allow).call().await;
+        mock.add_music_folder().call().await;
+
+        let mut music_folder_permission = mock.music_folder(0).await;
+        let mut music_folder = mock.music_folder(1).await;
+        music_folder_permission.add_audio().n_song((2..4).fake()).call().await;
+        music_folder.add_audio().n_song((2..4).fake()).call().await;
+
+        let user_id = mock.user_id(0).await;
+        let database_song_ids: IndexSet<_> =
+            song_ids(&mock, user_id, Rules::default()).await.into_iter().collect();
+
+        let song_ids: IndexSet<_> = if allow {
+            music_folder_permission
+                .database
+                .keys()
+                .chain(music_folder.database.keys())
+                .copied()
+                .collect()
+        } else {
+            music_folder.database.keys().copied().collect()
+        };
+        assert_eq!(database_song_ids, song_ids);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_query_rules(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song(5).call().await;
+        let user_id = mock.user_id(0).await;
+
+        let starred_id = music_folder.song_id(0);
+        diesel::insert_into(star_songs::table)
+            .values((star_songs::user_id.eq(user_id), star_songs::song_id.eq(starred_id)))
+            .execute(&mut mock.get().await)
+            .await
+            .unwrap();
+        let database_song_ids =
+            song_ids(&mock, user_id, Rules { starred: Some(true), ..Default::default() }).await;
+        assert_eq!(database_song_ids, vec![starred_id]);
+
+        let rated_ids = [music_folder.song_id(1), music_folder.song_id(2)];
+        for (rating, id) in [3, 5].into_iter().zip(rated_ids) {
+            set_rating::handler(mock.database(), user_id, set_rating::Request { id, rating })
+                .await
+                .unwrap();
+        }
+        let database_song_ids = song_ids(
+            &mock,
+            user_id,
+            Rules {
+                min_rating: Some(3),
+                sort: ApiSort::Rating,
+                descending: true,
+                ..Default::default()
+            },
+        )
+        .await;
+        assert_eq!(database_song_ids, vec![rated_ids[1], rated_ids[0]]);
+
+        let database_song_ids =
+            song_ids(&mock, user_id, Rules { limit: Some(2), ..Default::default() }).await;
+        assert_eq!(database_song_ids.len(), 2);
+
+        let database_song_ids =
+            song_ids(&mock, user_id, Rules { genres: vec![Faker.fake()], ..Default::default() })
+                .await;
+        assert!(database_song_ids.is_empty());
+
+        let playlist_id = playlists::Upsert { name: Some("smart".into()), ..Default::default() }
+            .insert(mock.database())
+            .await
+            .unwrap();
+        let rules: playlists_rules::Data =
+            Rules { starred: Some(false), ..Default::default() }.try_into().unwrap();
+        rules.update(mock.database(), playlist_id).await.unwrap();
+        assert_eq!(query(mock.database(), user_id, playlist_id).await.unwrap().len(), 4);
+        assert_eq!(durations(mock.database(), user_id, &rules).await.unwrap().count(), 4);
+    }
+}
@@ -0,0 +1,105 @@
+use color_eyre::eyre::OptionExt;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::dsl::sql;
+use diesel::expression::{AsExpression, SqlLiteral};
+use diesel::pg::PgValue;
+use diesel::prelude::*;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{self, Int2};
+use o2o::o2o;
+use strum::FromRepr;
+
+use crate::Error;
+pub use crate::schema::playlists_rules::{self, *};
+
+#[repr(i16)]
+#[derive(Debug, Clone, Copy, FromRepr, AsExpression, FromSqlRow, PartialEq, Eq, o2o)]
+#[diesel(sql_type = Int2)]
+#[map_owned(nghe_api::playlists::rules::Sort)]
+pub enum Sort {
+    Title = 1,
+    Year = 2,
+    Added = 3,
+    PlayCount = 4,
+    LastPlayed = 5,
+    Rating = 6,
+    Random = 7,
+}
+
+#[derive(Debug, Queryable, Selectable, Insertable, AsChangeset, o2o)]
+#[diesel(table_name = playlists_rules, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+#[try_from_owned(nghe_api::playlists::rules::Rules, Error)]
+pub struct Data {
+    #[diesel(select_expression = sql("playlists_rules.genres genres"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Text>>)]
+    pub genres: Vec<String>,
+    #[diesel(select_expression = sql("playlists_rules.artists artists"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Text>>)]
+    pub artists: Vec<String>,
+    #[diesel(select_expression = sql("playlists_rules.formats formats"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Text>>)]
+    pub formats: Vec<String>,
+    #[from(~.map(i16::try_from).transpose()?)]
+    pub from_year: Option<i16>,
+    #[from(~.map(i16::try_from).transpose()?)]
+    pub to_year: Option<i16>,
+    #[from(~.map(i16::from))]
+    pub min_bit_depth: Option<i16>,
+    #[from(~.map(i16::from))]
+    pub max_bit_depth: Option<i16>,
+    #[from(~.map(i32::try_from).transpose()?)]
+    pub min_play_count: Option<i32>,
+    #[from(~.map(i32::try_from).transpose()?)]
+    pub max_play_count: Option<i32>,
+    #[from(~.map(i32::try_from).transpose()?)]
+    pub played_within_days: Option<i32>,
+    #[from(~.map(i32::try_from).transpose()?)]
+    pub not_played_within_days: Option<i32>,
+    pub starred: Option<bool>,
+    #[from(~.map(i16::from))]
+    pub min_rating: Option<i16>,
+    #[from(~.map(i32::try_from).transpose()?)]
+    pub added_within_days: Option<i32>,
+    #[from(~.into())]
+    pub sort: Sort,
+    pub descending: bool,
+    #[from(limit, ~.map(i32::try_from).transpose()?)]
+    pub song_limit: Option<i32>,
+}
+
+impl ToSql<Int2, crate::orm::Type> for Sort {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, crate::orm::Type>) -> serialize::Result {
+        <i16 as ToSql<Int2, crate::orm::Type>>::to_sql(&(*self as i16), &mut out.reborrow())
+    }
+}
+
+impl FromSql<Int2, crate::orm::Type> for Sort {
+    fn from_sql(bytes: PgValue) -> deserialize::Result<Self> {
+        Ok(Sort::from_repr(i16::from_sql(bytes)?)
+            .ok_or_eyre("Database playlist sort constraint violation")?)
+    }
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel_async::RunQueryDsl;
+    use uuid::Uuid;
+
+    use super::{Data, playlists_rules};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl crate::orm::upsert::Update for Data {
+        async fn update(&self, database: &Database, id: Uuid) -> Result<(), Error> {
+            diesel::insert_into(playlists_rules::table)
+                .values((playlists_rules::playlist_id.eq(id), self))
+                .on_conflict(playlists_rules::playlist_id)
+                .do_update()
+                .set(self)
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+}
@@ -13,12 +13,27 @@ pub struct Upsert {
 
 mod upsert {
     use diesel::ExpressionMethods;
-    use diesel_async::RunQueryDsl;
+    use diesel::upsert::excluded;
+    use diesel_async::scoped_futures::ScopedFutureExt;
+    use diesel_async::{AsyncConnection, RunQueryDsl};
     use uuid::Uuid;
 
     use super::{Upsert, playlists_users};
-    use crate::Error;
     use crate::database::Database;
+    use crate::{Error, error};
+
+    // The target user is only referenced by its id, so a missing one violates the foreign key.
+    fn unknown_user(error: diesel::result::Error) -> Error {
+        if let diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::ForeignKeyViolation,
+            _,
+        ) = error
+        {
+            error::Kind::NotFound.into()
+        } else {
+            error.into()
+        }
+    }
 
     impl Upsert {
         pub async fn insert_owner(
@@ -35,5 +50,53 @@ mod upsert {
                 .await?;
             Ok(())
         }
+
+        pub async fn upsert(&self, database: &Database) -> Result<(), Error> {
+            diesel::insert_into(playlists_users::table)
+                .values(self)
+                .on_conflict((playlists_users::playlist_id, playlists_users::user_id))
+                .do_update()
+                .set(playlists_users::write.eq(excluded(playlists_users::write)))
+                .execute(&mut database.get().await?)
+                .await
+                .map_err(unknown_user)?;
+            Ok(())
+        }
+
+        pub async fn transfer_owner(
+            database: &Database,
+            playlist_id: Uuid,
+            user_id: Uuid,
+        ) -> Result<(), Error> {
+            // There is at most one owner per playlist so the previous owner has to be demoted
+            // before promoting the new one.
+            database
+                .get()
+                .await?
+                .transaction(|connection| {
+                    async move {
+                        diesel::update(playlists_users::table)
+                            .filter(playlists_users::playlist_id.eq(playlist_id))
+                            .filter(playlists_users::owner)
+                            .set(playlists_users::owner.eq(false))
+                            .execute(connection)
+                            .await?;
+                        diesel::insert_into(playlists_users::table)
+                            .values((
+                                Upsert { playlist_id, user_id, write: true },
+                                playlists_users::owner.eq(true),
+                            ))
+                            .on_conflict((playlists_users::playlist_id, playlists_users::user_id))
+                            .do_update()
+                            .set((playlists_users::write.eq(true), playlists_users::owner.eq(true)))
+                            .execute(connection)
+                            .await
+                            .map_err(unknown_user)?;
+                        Ok::<_, Error>(())
+                    }
+                    .scope_boxed()
+                })
+                .await
+        }
     }
 }
@@ -1,24 +1,68 @@
+use std::borrow::Cow;
+
 use diesel::dsl::sql;
 use diesel::expression::SqlLiteral;
 use diesel::prelude::*;
 use diesel::sql_types;
-use o2o::o2o;
+use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::Error;
 pub use crate::schema::playqueues::{self, *};
+use crate::{Error, error};
 
-#[derive(Debug, Queryable, Selectable, Insertable, AsChangeset, o2o)]
+#[derive(Debug, Insertable, AsChangeset)]
 #[diesel(table_name = playqueues, check_for_backend(crate::orm::Type))]
 #[diesel(treat_none_as_null = true)]
-#[try_from_owned(nghe_api::bookmarks::save_playqueue::Request, Error)]
-pub struct Data {
+pub struct Data<'a> {
+    pub ids: Vec<Uuid>,
+    pub current_index: Option<i32>,
+    pub position: Option<i64>,
+    pub changed_by: Option<Cow<'a, str>>,
+    pub changed_at: OffsetDateTime,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = playqueues, check_for_backend(crate::orm::Type))]
+pub struct Playqueue {
     #[diesel(select_expression = sql("playqueues.ids ids"))]
     #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Uuid>>)]
     pub ids: Vec<Uuid>,
-    pub current: Option<Uuid>,
-    #[from(~.map(i64::try_from).transpose()?)]
+    pub current_index: Option<i32>,
     pub position: Option<i64>,
+    pub changed_by: Option<String>,
+    pub changed_at: OffsetDateTime,
+}
+
+impl<'a> Data<'a> {
+    pub fn new(
+        song_ids: Vec<Uuid>,
+        index: Option<u32>,
+        offset: Option<u64>,
+        client: Option<&'a str>,
+        now: OffsetDateTime,
+    ) -> Result<Self, Error> {
+        if let Some(index) = index
+            && usize::try_from(index)? >= song_ids.len()
+        {
+            return error::Kind::InvalidPlayqueueIndex(index).into();
+        }
+        Ok(Self {
+            ids: song_ids,
+            current_index: index.map(i32::try_from).transpose()?,
+            position: offset.map(i64::try_from).transpose()?,
+            changed_by: client.map(Cow::Borrowed),
+            changed_at: now,
+        })
+    }
+}
+
+impl Playqueue {
+    pub fn current(&self) -> Option<Uuid> {
+        self.current_index
+            .and_then(|index| usize::try_from(index).ok())
+            .and_then(|index| self.ids.get(index))
+            .copied()
+    }
 }
 
 mod upsert {
@@ -30,7 +74,7 @@ mod upsert {
     use crate::Error;
     use crate::database::Database;
 
-    impl crate::orm::upsert::Update for Data {
+    impl crate::orm::upsert::Update for Data<'_> {
         async fn update(&self, database: &Database, id: Uuid) -> Result<(), Error> {
             diesel::insert_into(playqueues::table)
                 .values((playqueues::user_id.eq(id), self))
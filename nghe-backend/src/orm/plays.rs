@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+
+use diesel::prelude::*;
+use nghe_api::history;
+use o2o::o2o;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+pub use crate::schema::plays::{self, *};
+
+#[derive(Insertable)]
+#[diesel(table_name = plays)]
+#[diesel(check_for_backend(crate::orm::Type))]
+pub struct Play<'a> {
+    pub user_id: Uuid,
+    pub song_id: Uuid,
+    pub played_at: OffsetDateTime,
+    pub client: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Queryable, Selectable, o2o)]
+#[diesel(table_name = plays, check_for_backend(crate::orm::Type))]
+#[owned_into(history::get_play_history::Play)]
+pub struct History {
+    pub song_id: Uuid,
+    pub played_at: OffsetDateTime,
+    pub client: Option<String>,
+}
+
+#[derive(Debug, Queryable, o2o)]
+#[owned_into(history::get_top_plays::Top)]
+pub struct Top {
+    pub id: Uuid,
+    pub name: String,
+    #[into(~.cast_unsigned())]
+    pub play_count: i64,
+}
+
+pub mod query {
+    use diesel::dsl::auto_type;
+    use diesel::{ExpressionMethods, QueryDsl};
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    use super::plays;
+    use crate::orm::{albums, permission, songs};
+
+    #[auto_type]
+    pub fn with_user_id(user_id: Uuid, from: OffsetDateTime, to: OffsetDateTime) -> _ {
+        let permission: permission::with_album = permission::with_album(user_id);
+        plays::table
+            .inner_join(songs::table.inner_join(albums::table))
+            .filter(plays::user_id.eq(user_id))
+            .filter(plays::played_at.ge(from))
+            .filter(plays::played_at.lt(to))
+            .filter(permission)
+    }
+}
+
+mod upsert {
+    use diesel_async::{AsyncPgConnection, RunQueryDsl};
+    use uuid::Uuid;
+
+    use super::{Play, plays};
+    use crate::Error;
+
+    impl Play<'_> {
+        pub async fn insert(
+            connection: &mut AsyncPgConnection,
+            values: &[Self],
+        ) -> Result<Vec<Uuid>, Error> {
+            diesel::insert_into(plays::table)
+                .values(values)
+                .returning(plays::id)
+                .get_results(connection)
+                .await
+                .map_err(Error::from)
+        }
+    }
+}
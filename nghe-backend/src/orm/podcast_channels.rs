@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+
+use color_eyre::eyre::OptionExt;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::pg::PgValue;
+use diesel::prelude::*;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::Int2;
+use nghe_api::podcasts;
+use o2o::o2o;
+use strum::FromRepr;
+use uuid::Uuid;
+
+use crate::Error;
+pub use crate::schema::podcast_channels::{self, *};
+
+#[repr(i16)]
+#[derive(Debug, Clone, Copy, FromRepr, AsExpression, FromSqlRow, PartialEq, Eq, o2o)]
+#[diesel(sql_type = Int2)]
+#[map_owned(podcasts::Status)]
+pub enum Status {
+    New = 1,
+    Downloading = 2,
+    Completed = 3,
+    Error = 4,
+    Deleted = 5,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = podcast_channels, check_for_backend(crate::orm::Type))]
+pub struct Data<'a> {
+    pub url: Cow<'a, str>,
+}
+
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = podcast_channels, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+pub struct Information<'a> {
+    pub title: Option<Cow<'a, str>>,
+    pub description: Option<Cow<'a, str>>,
+    pub image_url: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = podcast_channels, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+pub struct State<'a> {
+    pub status: Status,
+    pub error_message: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = podcast_channels, check_for_backend(crate::orm::Type))]
+pub struct Channel {
+    pub id: Uuid,
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub status: Status,
+    pub error_message: Option<String>,
+}
+
+impl Channel {
+    pub fn into(self, episode: Vec<podcasts::Episode>) -> podcasts::Channel {
+        podcasts::Channel {
+            id: self.id,
+            url: self.url,
+            title: self.title,
+            description: self.description,
+            original_image_url: self.image_url,
+            status: self.status.into(),
+            error_message: self.error_message,
+            episode,
+        }
+    }
+}
+
+impl ToSql<Int2, super::Type> for Status {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, super::Type>) -> serialize::Result {
+        match self {
+            Status::New => <i16 as ToSql<Int2, super::Type>>::to_sql(&(Status::New as i16), out),
+            Status::Downloading => {
+                <i16 as ToSql<Int2, super::Type>>::to_sql(&(Status::Downloading as i16), out)
+            }
+            Status::Completed => {
+                <i16 as ToSql<Int2, super::Type>>::to_sql(&(Status::Completed as i16), out)
+            }
+            Status::Error => {
+                <i16 as ToSql<Int2, super::Type>>::to_sql(&(Status::Error as i16), out)
+            }
+            Status::Deleted => {
+                <i16 as ToSql<Int2, super::Type>>::to_sql(&(Status::Deleted as i16), out)
+            }
+        }
+    }
+}
+
+impl FromSql<Int2, super::Type> for Status {
+    fn from_sql(bytes: PgValue) -> deserialize::Result<Self> {
+        Ok(Status::from_repr(i16::from_sql(bytes)?)
+            .ok_or_eyre("Database podcast status constraint violation")?)
+    }
+}
+
+impl State<'_> {
+    pub fn from_result<T>(result: &Result<T, Error>) -> Self {
+        match result {
+            Ok(_) => Self { status: Status::Completed, error_message: None },
+            Err(error) => {
+                Self { status: Status::Error, error_message: Some(error.source.to_string().into()) }
+            }
+        }
+    }
+}
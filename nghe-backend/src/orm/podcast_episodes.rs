@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+
+use diesel::prelude::*;
+use nghe_api::common::format::Trait as _;
+use nghe_api::podcasts;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::podcast_channels::Status;
+use crate::file::audio;
+pub use crate::schema::podcast_episodes::{self, *};
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = podcast_episodes, check_for_backend(crate::orm::Type))]
+pub struct Data<'a> {
+    pub channel_id: Uuid,
+    pub guid: Cow<'a, str>,
+    pub title: Cow<'a, str>,
+    pub description: Option<Cow<'a, str>>,
+    pub url: Cow<'a, str>,
+    pub published_at: Option<OffsetDateTime>,
+    pub duration: Option<audio::Duration>,
+}
+
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = podcast_episodes, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+pub struct State<'a> {
+    pub status: Status,
+    pub error_message: Option<Cow<'a, str>>,
+    pub path: Option<Cow<'a, str>>,
+    pub file_hash: Option<i64>,
+    pub file_size: Option<i32>,
+    pub format: Option<audio::Format>,
+    // The duration announced by the feed is kept if the downloaded file could not be parsed.
+    #[diesel(treat_none_as_null = false)]
+    pub duration: Option<audio::Duration>,
+    pub bitrate: Option<i32>,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = podcast_episodes, check_for_backend(crate::orm::Type))]
+pub struct Episode {
+    pub id: Uuid,
+    pub channel_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub published_at: Option<OffsetDateTime>,
+    pub status: Status,
+    pub duration: Option<audio::Duration>,
+    pub bitrate: Option<i32>,
+    pub file_size: Option<i32>,
+    pub format: Option<audio::Format>,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = podcast_episodes, check_for_backend(crate::orm::Type))]
+pub struct Download {
+    pub id: Uuid,
+    pub channel_id: Uuid,
+    pub url: String,
+}
+
+impl State<'_> {
+    pub fn status(value: Status, message: Option<String>) -> Self {
+        Self {
+            status: value,
+            error_message: message.map(Cow::Owned),
+            path: None,
+            file_hash: None,
+            file_size: None,
+            format: None,
+            duration: None,
+            bitrate: None,
+        }
+    }
+}
+
+impl From<Episode> for podcasts::Episode {
+    fn from(value: Episode) -> Self {
+        Self {
+            id: value.id,
+            stream_id: if value.status == Status::Completed { Some(value.id) } else { None },
+            channel_id: value.channel_id,
+            title: value.title,
+            description: value.description,
+            publish_date: value.published_at,
+            status: value.status.into(),
+            is_dir: false,
+            size: value.file_size.map(i32::cast_unsigned),
+            content_type: value.format.map(|value| value.mime().into()),
+            suffix: value.format.map(|value| value.extension().into()),
+            duration: value.duration.and_then(|value| value.0.whole_seconds().try_into().ok()),
+            bit_rate: value.bitrate.map(i32::cast_unsigned),
+        }
+    }
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel::upsert::excluded;
+    use diesel_async::RunQueryDsl;
+
+    use super::{Data, State, Status, podcast_episodes};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Data<'_> {
+        pub async fn upsert(database: &Database, data: &[Self]) -> Result<(), Error> {
+            // Only the information from the feed is updated, the download state is kept.
+            diesel::insert_into(podcast_episodes::table)
+                .values(data)
+                .on_conflict((podcast_episodes::channel_id, podcast_episodes::guid))
+                .do_update()
+                .set((
+                    podcast_episodes::title.eq(excluded(podcast_episodes::title)),
+                    podcast_episodes::description.eq(excluded(podcast_episodes::description)),
+                    podcast_episodes::url.eq(excluded(podcast_episodes::url)),
+                    podcast_episodes::published_at.eq(excluded(podcast_episodes::published_at)),
+                ))
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+
+    impl State<'_> {
+        pub async fn abort_all(database: &Database) -> Result<usize, Error> {
+            // Downloads that are still running when the server starts were interrupted by a
+            // shutdown or a crash, mark them as failed so they can be downloaded again.
+            diesel::update(podcast_episodes::table)
+                .filter(podcast_episodes::status.eq(Status::Downloading))
+                .set(State::status(
+                    Status::Error,
+                    Some("Download was interrupted by a shutdown".to_owned()),
+                ))
+                .execute(&mut database.get().await?)
+                .await
+                .map_err(Error::from)
+        }
+    }
+}
+
+pub mod query {
+    use diesel::dsl::{AsSelect, auto_type};
+    use diesel::prelude::*;
+
+    use super::{Episode, podcast_episodes};
+
+    #[auto_type]
+    pub fn unchecked() -> _ {
+        let episode: AsSelect<Episode, crate::orm::Type> = Episode::as_select();
+        podcast_episodes::table
+            .order_by((
+                podcast_episodes::published_at.desc().nulls_last(),
+                podcast_episodes::created_at.desc(),
+            ))
+            .select(episode)
+    }
+}
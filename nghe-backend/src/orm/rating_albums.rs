@@ -0,0 +1,38 @@
+use diesel::prelude::*;
+use uuid::Uuid;
+
+pub use crate::schema::rating_albums::{self, *};
+
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = rating_albums, check_for_backend(crate::orm::Type))]
+pub struct Upsert {
+    pub user_id: Uuid,
+    pub album_id: Uuid,
+    pub rating: i16,
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel::upsert::excluded;
+    use diesel_async::RunQueryDsl;
+
+    use super::{Upsert, rating_albums};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Upsert {
+        pub async fn upsert(&self, database: &Database) -> Result<(), Error> {
+            diesel::insert_into(rating_albums::table)
+                .values(self)
+                .on_conflict((rating_albums::user_id, rating_albums::album_id))
+                .do_update()
+                .set((
+                    rating_albums::rating.eq(excluded(rating_albums::rating)),
+                    rating_albums::updated_at.eq(excluded(rating_albums::updated_at)),
+                ))
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+}
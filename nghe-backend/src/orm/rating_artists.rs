@@ -0,0 +1,38 @@
+use diesel::prelude::*;
+use uuid::Uuid;
+
+pub use crate::schema::rating_artists::{self, *};
+
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = rating_artists, check_for_backend(crate::orm::Type))]
+pub struct Upsert {
+    pub user_id: Uuid,
+    pub artist_id: Uuid,
+    pub rating: i16,
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel::upsert::excluded;
+    use diesel_async::RunQueryDsl;
+
+    use super::{Upsert, rating_artists};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Upsert {
+        pub async fn upsert(&self, database: &Database) -> Result<(), Error> {
+            diesel::insert_into(rating_artists::table)
+                .values(self)
+                .on_conflict((rating_artists::user_id, rating_artists::artist_id))
+                .do_update()
+                .set((
+                    rating_artists::rating.eq(excluded(rating_artists::rating)),
+                    rating_artists::updated_at.eq(excluded(rating_artists::updated_at)),
+                ))
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use diesel::prelude::*;
+use uuid::Uuid;
+
+pub use crate::schema::rating_songs::{self, *};
+
+#[derive(Debug, Insertable, AsChangeset)]
+#[diesel(table_name = rating_songs, check_for_backend(crate::orm::Type))]
+pub struct Upsert {
+    pub user_id: Uuid,
+    pub song_id: Uuid,
+    pub rating: i16,
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel::upsert::excluded;
+    use diesel_async::RunQueryDsl;
+
+    use super::{Upsert, rating_songs};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Upsert {
+        pub async fn upsert(&self, database: &Database) -> Result<(), Error> {
+            diesel::insert_into(rating_songs::table)
+                .values(self)
+                .on_conflict((rating_songs::user_id, rating_songs::song_id))
+                .do_update()
+                .set((
+                    rating_songs::rating.eq(excluded(rating_songs::rating)),
+                    rating_songs::updated_at.eq(excluded(rating_songs::updated_at)),
+                ))
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use diesel::prelude::*;
+use o2o::o2o;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+pub use crate::schema::scans::{self, *};
+
+#[derive(Debug, Clone, Copy, Insertable)]
+#[diesel(table_name = scans, check_for_backend(crate::orm::Type))]
+pub struct Key {
+    pub started_at: OffsetDateTime,
+    pub music_folder_id: Uuid,
+}
+
+#[derive(Debug, Default, Clone, Copy, AsChangeset)]
+#[diesel(table_name = scans, check_for_backend(crate::orm::Type))]
+pub struct Deleted {
+    #[diesel(column_name = deleted_song_count)]
+    pub song: i64,
+    #[diesel(column_name = deleted_album_count)]
+    pub album: i64,
+    #[diesel(column_name = deleted_artist_count)]
+    pub artist: i64,
+    #[diesel(column_name = deleted_genre_count)]
+    pub genre: i64,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Progress {
+    pub scanned: i64,
+    pub upserted: i64,
+    pub error: i64,
+}
+
+impl Progress {
+    pub fn add(&mut self, upserted: bool, error: bool) {
+        self.scanned += 1;
+        self.upserted += i64::from(upserted);
+        self.error += i64::from(error);
+    }
+}
+
+#[derive(Debug, Queryable, Selectable, o2o)]
+#[diesel(table_name = scans, check_for_backend(crate::orm::Type))]
+#[owned_into(nghe_api::scan::get_scans::Scan)]
+#[allow(clippy::struct_field_names)]
+pub struct Scan {
+    pub started_at: OffsetDateTime,
+    pub is_scanning: bool,
+    pub finished_at: Option<OffsetDateTime>,
+    #[into(~.cast_unsigned())]
+    pub scanned_song_count: i64,
+    #[into(~.cast_unsigned())]
+    pub upserted_song_count: i64,
+    #[into(~.cast_unsigned())]
+    pub deleted_song_count: i64,
+    #[into(~.cast_unsigned())]
+    pub deleted_album_count: i64,
+    #[into(~.cast_unsigned())]
+    pub deleted_artist_count: i64,
+    #[into(~.cast_unsigned())]
+    pub deleted_genre_count: i64,
+    #[into(~.cast_unsigned())]
+    pub scan_error_count: i64,
+    pub unrecoverable: Option<bool>,
+    pub cancelled: bool,
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel_async::RunQueryDsl;
+
+    use super::{Deleted, Key, Progress, scans};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Key {
+        pub async fn start(database: &Database, key: Self) -> Result<Option<Self>, Error> {
+            // There can be only one running scan per music folder, enforced by the
+            // `scans_music_folder_id_is_scanning_idx` unique index.
+            let inserted = diesel::insert_into(scans::table)
+                .values(key)
+                .on_conflict_do_nothing()
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(if inserted > 0 { Some(key) } else { None })
+        }
+
+        pub async fn progress(&self, database: &Database, progress: Progress) -> Result<(), Error> {
+            if progress.scanned == 0 {
+                return Ok(());
+            }
+            diesel::update(scans::table)
+                .filter(scans::started_at.eq(self.started_at))
+                .filter(scans::music_folder_id.eq(self.music_folder_id))
+                .set((
+                    scans::scanned_song_count.eq(scans::scanned_song_count + progress.scanned),
+                    scans::upserted_song_count.eq(scans::upserted_song_count + progress.upserted),
+                    scans::scan_error_count.eq(scans::scan_error_count + progress.error),
+                ))
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+
+        pub async fn deleted(&self, database: &Database, deleted: Deleted) -> Result<(), Error> {
+            diesel::update(scans::table)
+                .filter(scans::started_at.eq(self.started_at))
+                .filter(scans::music_folder_id.eq(self.music_folder_id))
+                .set(deleted)
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+
+        pub async fn finish(
+            &self,
+            database: &Database,
+            unrecoverable: bool,
+            cancelled: bool,
+        ) -> Result<(), Error> {
+            diesel::update(scans::table)
+                .filter(scans::started_at.eq(self.started_at))
+                .filter(scans::music_folder_id.eq(self.music_folder_id))
+                .set((
+                    scans::is_scanning.eq(false),
+                    scans::finished_at.eq(crate::time::now().await),
+                    scans::unrecoverable.eq(unrecoverable),
+                    scans::cancelled.eq(cancelled),
+                ))
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+
+        pub async fn abort_all(database: &Database) -> Result<usize, Error> {
+            // Scans that are still running when the server starts were interrupted by a shutdown
+            // or a crash, mark them as unrecoverable so the music folders can be scanned again.
+            diesel::update(scans::table)
+                .filter(scans::is_scanning)
+                .set((
+                    scans::is_scanning.eq(false),
+                    scans::finished_at.eq(crate::time::now().await),
+                    scans::unrecoverable.eq(true),
+                ))
+                .execute(&mut database.get().await?)
+                .await
+                .map_err(Error::from)
+        }
+    }
+}
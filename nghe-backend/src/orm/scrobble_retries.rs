@@ -0,0 +1,31 @@
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::user_scrobblers::Service;
+pub use crate::schema::scrobble_retries::{self, *};
+
+#[derive(Debug, Clone, Copy, Insertable)]
+#[diesel(table_name = scrobble_retries, check_for_backend(super::Type))]
+pub struct Key {
+    pub play_id: Uuid,
+    pub service: Service,
+}
+
+mod upsert {
+    use diesel_async::RunQueryDsl;
+
+    use super::{Key, scrobble_retries};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Key {
+        pub async fn insert(database: &Database, values: &[Self]) -> Result<(), Error> {
+            diesel::insert_into(scrobble_retries::table)
+                .values(values)
+                .on_conflict_do_nothing()
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,246 @@
+#![allow(clippy::option_option)]
+
+use std::borrow::Cow;
+
+use concat_string::concat_string;
+use diesel::dsl::sql;
+use diesel::expression::SqlLiteral;
+use diesel::prelude::*;
+use diesel::sql_types;
+use diesel_async::RunQueryDsl;
+use nghe_api::id3;
+use nghe_api::sharing::share;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::id3::song;
+use crate::orm::{playlist, playlists, songs, users};
+pub use crate::schema::shares::{self, *};
+use crate::{Error, config};
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = shares, check_for_backend(crate::orm::Type))]
+pub struct Data<'a> {
+    pub user_id: Uuid,
+    pub description: Option<Cow<'a, str>>,
+    pub song_ids: Vec<Uuid>,
+    pub album_ids: Vec<Uuid>,
+    pub playlist_ids: Vec<Uuid>,
+    pub expires_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = shares, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = false)]
+pub struct Update<'a> {
+    pub description: Option<Option<Cow<'a, str>>>,
+    pub expires_at: Option<Option<OffsetDateTime>>,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = shares, check_for_backend(crate::orm::Type))]
+pub struct Share {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    #[diesel(select_expression = users::username)]
+    #[diesel(select_expression_type = users::username)]
+    pub username: String,
+    pub description: Option<String>,
+    #[diesel(select_expression = sql("shares.song_ids song_ids"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Uuid>>)]
+    pub song_ids: Vec<Uuid>,
+    #[diesel(select_expression = sql("shares.album_ids album_ids"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Uuid>>)]
+    pub album_ids: Vec<Uuid>,
+    #[diesel(select_expression = sql("shares.playlist_ids playlist_ids"))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Uuid>>)]
+    pub playlist_ids: Vec<Uuid>,
+    pub visit_count: i32,
+    pub expires_at: Option<OffsetDateTime>,
+    pub last_visited_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl Share {
+    pub async fn query_unexpired(database: &Database, share_id: Uuid) -> Result<Self, Error> {
+        query::unexpired(crate::time::now().await)
+            .filter(shares::id.eq(share_id))
+            .get_result(&mut database.get().await?)
+            .await
+            .map_err(Error::from)
+    }
+
+    // Shared items are always resolved with the permissions of the owner, so the songs that the
+    // owner could not access anymore are not shared either.
+    pub async fn entries(&self, database: &Database) -> Result<Vec<id3::song::Short>, Error> {
+        let mut entries: Vec<_> = if self.song_ids.is_empty() && self.album_ids.is_empty() {
+            vec![]
+        } else {
+            song::short::query::with_user_id(self.user_id)
+                .filter(
+                    songs::id.eq_any(&self.song_ids).or(songs::album_id.eq_any(&self.album_ids)),
+                )
+                .get_results(&mut database.get().await?)
+                .await?
+                .into_iter()
+                .map(song::short::Short::try_into)
+                .try_collect()?
+        };
+
+        for playlist_id in &self.playlist_ids {
+            let playlist = playlist::full::query::with_user_id(self.user_id)
+                .filter(playlists::id.eq(playlist_id))
+                .get_result(&mut database.get().await?)
+                .await
+                .optional()?;
+            if let Some(playlist) = playlist {
+                entries.extend(playlist.try_into(database, self.user_id).await?.entry);
+            }
+        }
+
+        Ok(entries)
+    }
+
+    pub async fn try_into(
+        self,
+        database: &Database,
+        config: &config::Share,
+    ) -> Result<share::Share, Error> {
+        let entry = self.entries(database).await?;
+        Ok(share::Share {
+            id: self.id,
+            url: concat_string!(
+                config.base_url.as_deref().unwrap_or_default().trim_end_matches('/'),
+                nghe_api::common::FRONTEND_PREFIX,
+                "/share/",
+                self.id.to_string()
+            ),
+            description: self.description,
+            username: self.username,
+            created: self.created_at,
+            expires: self.expires_at,
+            last_visited: self.last_visited_at,
+            visit_count: self.visit_count.try_into()?,
+            entry,
+        })
+    }
+}
+
+pub mod query {
+    use diesel::dsl::{AsSelect, auto_type};
+    use diesel::prelude::*;
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    use super::{Share, shares, users};
+
+    #[auto_type]
+    pub fn unchecked() -> _ {
+        let share: AsSelect<Share, crate::orm::Type> = Share::as_select();
+        shares::table.inner_join(users::table).select(share)
+    }
+
+    #[auto_type]
+    pub fn with_user_id(user_id: Uuid) -> _ {
+        let unchecked: unchecked = unchecked();
+        unchecked.filter(shares::user_id.eq(user_id)).order_by(shares::created_at.desc())
+    }
+
+    #[auto_type]
+    pub fn unexpired(now: OffsetDateTime) -> _ {
+        let unchecked: unchecked = unchecked();
+        unchecked.filter(shares::expires_at.is_null().or(shares::expires_at.gt(now)))
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use diesel::prelude::*;
+    use diesel_async::RunQueryDsl;
+    use fake::{Fake, Faker};
+    use rstest::rstest;
+
+    use super::{Data, query, shares};
+    use crate::orm::songs;
+    use crate::route::playlists::create_playlist;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_entries(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().allow(allow).call().await;
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().album(Faker.fake()).n_song(2).call().await;
+        music_folder.add_audio().album(Faker.fake()).n_song(3).call().await;
+        music_folder.add_audio().album(Faker.fake()).n_song(1).call().await;
+
+        let user_id = mock.user_id(0).await;
+        let album_id = songs::table
+            .filter(songs::id.eq(music_folder.song_id(2)))
+            .select(songs::album_id)
+            .get_result(&mut mock.get().await)
+            .await
+            .unwrap();
+        let playlist_ids = if allow {
+            vec![
+                create_playlist::handler(
+                    mock.database(),
+                    user_id,
+                    create_playlist::Request {
+                        create_or_update: Faker.fake::<String>().into(),
+                        song_ids: Some(vec![music_folder.song_id(5)]),
+                    },
+                )
+                .await
+                .unwrap()
+                .playlist
+                .playlist
+                .id,
+            ]
+        } else {
+            vec![]
+        };
+
+        let share_id: uuid::Uuid = diesel::insert_into(shares::table)
+            .values(Data {
+                user_id,
+                description: None,
+                song_ids: vec![music_folder.song_id(0)],
+                album_ids: vec![album_id],
+                playlist_ids,
+                expires_at: None,
+            })
+            .returning(shares::id)
+            .get_result(&mut mock.get().await)
+            .await
+            .unwrap();
+
+        let share = query::unchecked()
+            .filter(shares::id.eq(share_id))
+            .get_result(&mut mock.get().await)
+            .await
+            .unwrap();
+        let entries: Vec<_> = share
+            .entries(mock.database())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|song| song.song.id)
+            .collect();
+        if allow {
+            assert_eq!(entries.len(), 5);
+            assert!(entries.contains(&music_folder.song_id(0)));
+            assert!(!entries.contains(&music_folder.song_id(1)));
+            assert_eq!(entries.last(), Some(&music_folder.song_id(5)));
+        } else {
+            assert!(entries.is_empty());
+        }
+    }
+}
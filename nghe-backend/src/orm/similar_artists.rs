@@ -0,0 +1,83 @@
+#![allow(clippy::elidable_lifetime_names)]
+
+use std::borrow::Cow;
+
+use diesel::prelude::*;
+use uuid::Uuid;
+
+pub use crate::schema::similar_artists::{self, *};
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = similar_artists, check_for_backend(crate::orm::Type))]
+pub struct Data<'a> {
+    pub name: Cow<'a, str>,
+    pub mbz_id: Option<Uuid>,
+    pub score: f32,
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel_async::scoped_futures::ScopedFutureExt;
+    use diesel_async::{AsyncConnection, RunQueryDsl};
+    use uuid::Uuid;
+
+    use super::{Data, similar_artists};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Data<'_> {
+        pub async fn replace(
+            database: &Database,
+            artist_id: Uuid,
+            data: &[Self],
+        ) -> Result<(), Error> {
+            let values: Vec<_> =
+                data.iter().map(|data| (similar_artists::artist_id.eq(artist_id), data)).collect();
+            database
+                .get()
+                .await?
+                .transaction(|connection| {
+                    async move {
+                        diesel::delete(similar_artists::table)
+                            .filter(similar_artists::artist_id.eq(artist_id))
+                            .execute(connection)
+                            .await?;
+                        if !values.is_empty() {
+                            diesel::insert_into(similar_artists::table)
+                                .values(values)
+                                .on_conflict_do_nothing()
+                                .execute(connection)
+                                .await?;
+                        }
+                        Ok::<_, Error>(())
+                    }
+                    .scope_boxed()
+                })
+                .await
+        }
+    }
+}
+
+pub mod query {
+    use diesel::dsl::{auto_type, not};
+    use diesel::prelude::*;
+    use uuid::Uuid;
+
+    use crate::orm::{artists, function, similar_artists};
+
+    #[auto_type]
+    pub fn local<'ids>(artist_ids: &'ids [Uuid]) -> _ {
+        // Similar artists are matched against local artists by their MusicBrainz id first, then by
+        // their case-insensitive name.
+        artists::table
+            .inner_join(
+                similar_artists::table.on(artists::mbz_id
+                    .eq(similar_artists::mbz_id)
+                    .or(function::lower(artists::name).eq(function::lower(similar_artists::name)))),
+            )
+            .filter(similar_artists::artist_id.eq_any(artist_ids))
+            .filter(not(artists::id.eq_any(artist_ids)))
+            .order_by(similar_artists::score.desc())
+            .select(artists::id)
+    }
+}
@@ -0,0 +1,107 @@
+#![allow(clippy::elidable_lifetime_names)]
+
+use std::borrow::Cow;
+
+use diesel::prelude::*;
+
+pub use crate::schema::similar_songs::{self, *};
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = similar_songs, check_for_backend(crate::orm::Type))]
+pub struct Data<'a> {
+    pub name: Cow<'a, str>,
+    pub artist_name: Cow<'a, str>,
+    pub score: f32,
+}
+
+mod upsert {
+    use diesel::ExpressionMethods;
+    use diesel_async::scoped_futures::ScopedFutureExt;
+    use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+    use uuid::Uuid;
+
+    use super::{Data, similar_songs};
+    use crate::Error;
+    use crate::database::Database;
+    use crate::orm::songs;
+
+    impl Data<'_> {
+        pub async fn replace(
+            database: &Database,
+            song_id: Uuid,
+            data: &[Self],
+        ) -> Result<(), Error> {
+            let values: Vec<_> =
+                data.iter().map(|data| (similar_songs::song_id.eq(song_id), data)).collect();
+            let now = crate::time::now().await;
+            database
+                .get()
+                .await?
+                .transaction(|connection| {
+                    async move {
+                        diesel::delete(similar_songs::table)
+                            .filter(similar_songs::song_id.eq(song_id))
+                            .execute(connection)
+                            .await?;
+                        if !values.is_empty() {
+                            diesel::insert_into(similar_songs::table)
+                                .values(values)
+                                .on_conflict_do_nothing()
+                                .execute(connection)
+                                .await?;
+                        }
+                        // Songs without any similar song are also marked so they are not fetched
+                        // again by the next scan.
+                        Self::mark_fetched_at(connection, song_id, now).await
+                    }
+                    .scope_boxed()
+                })
+                .await
+        }
+
+        // Keeps the existing similar songs, used when they could not be fetched.
+        pub async fn mark_fetched(database: &Database, song_id: Uuid) -> Result<(), Error> {
+            let now = crate::time::now().await;
+            Self::mark_fetched_at(&mut *database.get().await?, song_id, now).await
+        }
+
+        async fn mark_fetched_at(
+            connection: &mut AsyncPgConnection,
+            song_id: Uuid,
+            now: time::OffsetDateTime,
+        ) -> Result<(), Error> {
+            diesel::update(songs::table)
+                .filter(songs::id.eq(song_id))
+                .set(songs::similar_songs_fetched_at.eq(now))
+                .execute(connection)
+                .await?;
+            Ok(())
+        }
+    }
+}
+
+pub mod query {
+    use diesel::dsl::{auto_type, not};
+    use diesel::prelude::*;
+    use uuid::Uuid;
+
+    use crate::orm::{artists, function, similar_songs, songs, songs_artists};
+
+    #[auto_type]
+    pub fn local(song_id: Uuid) -> _ {
+        // Similar songs are matched against local songs by their case-insensitive title and
+        // artist name.
+        songs::table
+            .inner_join(songs_artists::table)
+            .inner_join(artists::table.on(artists::id.eq(songs_artists::artist_id)))
+            .inner_join(similar_songs::table.on(
+                function::lower(songs::title).eq(function::lower(similar_songs::name)).and(
+                    function::lower(artists::name).eq(function::lower(similar_songs::artist_name)),
+                ),
+            ))
+            .filter(similar_songs::song_id.eq(song_id))
+            .filter(not(songs::id.eq(song_id)))
+            .order_by(similar_songs::score.desc())
+            .select(songs::id)
+    }
+}
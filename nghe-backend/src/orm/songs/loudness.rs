@@ -0,0 +1,44 @@
+use diesel::dsl::sql;
+use diesel::expression::SqlLiteral;
+use diesel::prelude::*;
+use diesel::sql_types;
+
+use super::songs;
+use crate::file::audio::transcode;
+
+// Measured loudness of a song and of its album, the latter is derived after every measurement
+// phase of a scan.
+#[derive(Debug, Default, Clone, Copy, Queryable, Selectable)]
+#[diesel(table_name = songs, check_for_backend(crate::orm::Type))]
+pub struct Loudness {
+    #[diesel(column_name = loudness_integrated)]
+    pub track_integrated: Option<f32>,
+    #[diesel(column_name = loudness_peak)]
+    pub track_peak: Option<f32>,
+    #[diesel(select_expression = sql(
+        "(select loudness_albums.loudness_integrated from albums loudness_albums \
+        where loudness_albums.id = songs.album_id) album_loudness_integrated"
+    ))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Float>>)]
+    pub album_integrated: Option<f32>,
+    #[diesel(select_expression = sql(
+        "(select loudness_albums.loudness_peak from albums loudness_albums \
+        where loudness_albums.id = songs.album_id) album_loudness_peak"
+    ))]
+    #[diesel(select_expression_type = SqlLiteral<sql_types::Nullable<sql_types::Float>>)]
+    pub album_peak: Option<f32>,
+}
+
+impl Loudness {
+    pub fn track(&self) -> Option<transcode::Loudness> {
+        self.track_integrated
+            .zip(self.track_peak)
+            .map(|(integrated, peak)| transcode::Loudness { integrated, peak })
+    }
+
+    pub fn album(&self) -> Option<transcode::Loudness> {
+        self.album_integrated
+            .zip(self.album_peak)
+            .map(|(integrated, peak)| transcode::Loudness { integrated, peak })
+    }
+}
@@ -16,9 +16,11 @@ use crate::file::audio;
 pub use crate::schema::songs::{self, *};
 
 pub mod date;
+pub mod loudness;
 pub mod name_date_mbz;
 pub mod position;
 pub mod property;
+pub mod replay_gain;
 
 #[derive(Debug, Queryable, Selectable, Insertable, AsChangeset)]
 #[diesel(table_name = songs, check_for_backend(crate::orm::Type))]
@@ -40,6 +42,8 @@ pub struct Song<'a> {
     #[diesel(select_expression = sql("songs.languages languages"))]
     #[diesel(select_expression_type = SqlLiteral<sql_types::Array<sql_types::Text>>)]
     pub languages: Vec<Cow<'a, str>>,
+    #[diesel(embed)]
+    pub replay_gain: replay_gain::ReplayGain,
 }
 
 #[derive(Debug, Queryable, Selectable, Insertable, AsChangeset)]
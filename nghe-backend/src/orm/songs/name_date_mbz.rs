@@ -18,4 +18,5 @@ pub struct NameDateMbz<'a> {
     #[diesel(embed)]
     pub original_release_date: date::OriginalRelease,
     pub mbz_id: Option<Uuid>,
+    pub sort_name: Option<Cow<'a, str>>,
 }
@@ -0,0 +1,17 @@
+use diesel::prelude::*;
+
+use super::songs;
+
+#[derive(Debug, Clone, Copy, Queryable, Selectable, Insertable, AsChangeset)]
+#[diesel(table_name = songs, check_for_backend(crate::orm::Type))]
+#[diesel(treat_none_as_null = true)]
+pub struct ReplayGain {
+    #[diesel(column_name = replay_gain_track_gain)]
+    pub track_gain: Option<f32>,
+    #[diesel(column_name = replay_gain_track_peak)]
+    pub track_peak: Option<f32>,
+    #[diesel(column_name = replay_gain_album_gain)]
+    pub album_gain: Option<f32>,
+    #[diesel(column_name = replay_gain_album_peak)]
+    pub album_peak: Option<f32>,
+}
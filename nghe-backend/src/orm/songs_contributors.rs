@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+
+use color_eyre::eyre::OptionExt;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::pg::PgValue;
+use diesel::prelude::*;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::Int2;
+use o2o::o2o;
+use strum::FromRepr;
+use uuid::Uuid;
+
+pub use crate::schema::songs_contributors::{self, *};
+
+#[repr(i16)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    FromRepr,
+    AsExpression,
+    FromSqlRow,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    o2o,
+)]
+#[diesel(sql_type = Int2)]
+#[owned_into(nghe_api::id3::artist::Role)]
+#[cfg_attr(test, derive(fake::Dummy))]
+pub enum Role {
+    Composer = 1,
+    Conductor = 2,
+    Performer = 3,
+    Lyricist = 4,
+    Arranger = 5,
+    Producer = 6,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = songs_contributors, check_for_backend(crate::orm::Type))]
+pub struct Data<'a> {
+    pub song_id: Uuid,
+    pub artist_id: Uuid,
+    pub role: Role,
+    pub sub_role: Cow<'a, str>,
+}
+
+impl ToSql<Int2, crate::orm::Type> for Role {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, crate::orm::Type>) -> serialize::Result {
+        <i16 as ToSql<Int2, crate::orm::Type>>::to_sql(&(*self as i16), &mut out.reborrow())
+    }
+}
+
+impl FromSql<Int2, crate::orm::Type> for Role {
+    fn from_sql(bytes: PgValue) -> deserialize::Result<Self> {
+        Ok(Role::from_repr(i16::from_sql(bytes)?)
+            .ok_or_eyre("Database contributor role constraint violation")?)
+    }
+}
+
+impl TryFrom<nghe_api::id3::artist::Role> for Role {
+    type Error = nghe_api::id3::artist::Role;
+
+    fn try_from(value: nghe_api::id3::artist::Role) -> Result<Self, Self::Error> {
+        match value {
+            nghe_api::id3::artist::Role::Composer => Ok(Self::Composer),
+            nghe_api::id3::artist::Role::Conductor => Ok(Self::Conductor),
+            nghe_api::id3::artist::Role::Performer => Ok(Self::Performer),
+            nghe_api::id3::artist::Role::Lyricist => Ok(Self::Lyricist),
+            nghe_api::id3::artist::Role::Arranger => Ok(Self::Arranger),
+            nghe_api::id3::artist::Role::Producer => Ok(Self::Producer),
+            nghe_api::id3::artist::Role::Artist | nghe_api::id3::artist::Role::AlbumArtist => {
+                Err(value)
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::borrow::Cow;
+
+use color_eyre::eyre::OptionExt;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::pg::PgValue;
+use diesel::prelude::*;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::Int2;
+use o2o::o2o;
+use strum::FromRepr;
+use uuid::Uuid;
+
+pub use crate::schema::user_scrobblers::{self, *};
+
+#[repr(i16)]
+#[derive(Debug, Clone, Copy, FromRepr, AsExpression, FromSqlRow, PartialEq, Eq, Hash, o2o)]
+#[diesel(sql_type = Int2)]
+#[map_owned(nghe_api::scrobbler::Service)]
+pub enum Service {
+    Lastfm = 1,
+    Listenbrainz = 2,
+}
+
+#[derive(Insertable, AsChangeset)]
+#[diesel(table_name = user_scrobblers, check_for_backend(super::Type))]
+pub struct Upsert<'a> {
+    pub user_id: Uuid,
+    pub service: Service,
+    pub username: Cow<'a, str>,
+    pub token: Vec<u8>,
+}
+
+#[derive(Debug, Queryable, Selectable, o2o)]
+#[diesel(table_name = user_scrobblers, check_for_backend(super::Type))]
+#[owned_into(nghe_api::scrobbler::get::Scrobbler)]
+pub struct Scrobbler {
+    #[into(~.into())]
+    pub service: Service,
+    pub username: String,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = user_scrobblers, check_for_backend(super::Type))]
+pub struct Token {
+    pub service: Service,
+    pub token: Vec<u8>,
+}
+
+impl ToSql<Int2, super::Type> for Service {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, super::Type>) -> serialize::Result {
+        match self {
+            Service::Lastfm => {
+                <i16 as ToSql<Int2, super::Type>>::to_sql(&(Service::Lastfm as i16), out)
+            }
+            Service::Listenbrainz => {
+                <i16 as ToSql<Int2, super::Type>>::to_sql(&(Service::Listenbrainz as i16), out)
+            }
+        }
+    }
+}
+
+impl FromSql<Int2, super::Type> for Service {
+    fn from_sql(bytes: PgValue) -> deserialize::Result<Self> {
+        Ok(Service::from_repr(i16::from_sql(bytes)?)
+            .ok_or_eyre("Database scrobbler service constraint violation")?)
+    }
+}
+
+mod upsert {
+    use diesel_async::RunQueryDsl;
+
+    use super::{Upsert, user_scrobblers};
+    use crate::Error;
+    use crate::database::Database;
+
+    impl Upsert<'_> {
+        pub async fn upsert(&self, database: &Database) -> Result<(), Error> {
+            diesel::insert_into(user_scrobblers::table)
+                .values(self)
+                .on_conflict((user_scrobblers::user_id, user_scrobblers::service))
+                .do_update()
+                .set(self)
+                .execute(&mut database.get().await?)
+                .await?;
+            Ok(())
+        }
+    }
+}
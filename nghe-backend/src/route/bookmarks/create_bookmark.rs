@@ -0,0 +1,37 @@
+use diesel::dsl::{exists, select};
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::bookmarks::create_bookmark::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::{albums, bookmarks, permission, songs};
+use crate::{Error, error};
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let accessible: bool = select(exists(
+        songs::table
+            .inner_join(albums::table)
+            .filter(permission::with_album(user_id))
+            .filter(songs::id.eq(request.id)),
+    ))
+    .get_result(&mut database.get().await?)
+    .await?;
+    if !accessible {
+        return error::Kind::NotFound.into();
+    }
+
+    bookmarks::Data {
+        position: request.position.try_into()?,
+        comment: request.comment.map(Into::into),
+    }
+    .upsert(database, user_id, request.id)
+    .await?;
+    Ok(Response)
+}
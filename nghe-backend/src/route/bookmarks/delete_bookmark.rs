@@ -0,0 +1,23 @@
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+pub use nghe_api::bookmarks::delete_bookmark::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::bookmarks;
+use crate::{Error, error};
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let deleted = diesel::delete(bookmarks::table)
+        .filter(bookmarks::user_id.eq(user_id))
+        .filter(bookmarks::song_id.eq(request.id))
+        .execute(&mut database.get().await?)
+        .await?;
+    if deleted > 0 { Ok(Response) } else { error::Kind::NotFound.into() }
+}
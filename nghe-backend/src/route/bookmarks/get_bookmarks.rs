@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use itertools::Itertools;
+use nghe_api::bookmarks::get_bookmarks::{Bookmark, Bookmarks};
+pub use nghe_api::bookmarks::get_bookmarks::{Request, Response};
+use nghe_api::id3::song;
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{bookmarks, id3, songs, users};
+
+#[handler]
+pub async fn handler(database: &Database, user_id: Uuid) -> Result<Response, Error> {
+    let username: String = users::table
+        .filter(users::id.eq(user_id))
+        .select(users::username)
+        .get_result(&mut database.get().await?)
+        .await?;
+    let bookmarks = bookmarks::table
+        .filter(bookmarks::user_id.eq(user_id))
+        .order_by(bookmarks::updated_at.desc())
+        .select(bookmarks::Bookmark::as_select())
+        .get_results(&mut database.get().await?)
+        .await?;
+
+    // Bookmarks are unique per user and song so each song is used at most once.
+    let song_ids: Vec<_> = bookmarks.iter().map(|bookmark| bookmark.song_id).collect();
+    let mut songs: HashMap<_, song::Full> = id3::song::full::query::with_user_id(user_id)
+        .filter(songs::id.eq_any(song_ids))
+        .get_results::<id3::song::full::Full>(&mut database.get().await?)
+        .await?
+        .into_iter()
+        .map(|song| Ok::<_, Error>((song.short.song.id, song.try_into()?)))
+        .try_collect()?;
+
+    // Songs that the user no longer has access to are skipped.
+    let bookmark = bookmarks
+        .into_iter()
+        .filter_map(|bookmark| {
+            songs.remove(&bookmark.song_id).map(|song| {
+                Ok::<_, Error>(Bookmark {
+                    position: bookmark.position.try_into()?,
+                    username: username.clone(),
+                    comment: bookmark.comment,
+                    created: bookmark.created_at,
+                    changed: bookmark.updated_at,
+                    entry: song,
+                })
+            })
+        })
+        .try_collect::<_, Vec<_>, Error>()?;
+
+    Ok(Response { bookmarks: Bookmarks { bookmark } })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::bookmarks::{create_bookmark, delete_bookmark};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(2, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().allow(allow).call().await;
+        mock.add_music_folder().call().await;
+
+        let mut music_folder_permission = mock.music_folder(0).await;
+        music_folder_permission.add_audio().call().await;
+        let mut music_folder = mock.music_folder(1).await;
+        music_folder.add_audio().n_song(2).call().await;
+
+        let song_id_permission = music_folder_permission.song_id(0);
+        let song_ids = [music_folder.song_id(0), music_folder.song_id(1)];
+
+        let user_id = mock.user_id(0).await;
+        let other_user_id = mock.user_id(1).await;
+
+        let create = async |user_id, id, position, comment: Option<&str>| {
+            create_bookmark::handler(
+                mock.database(),
+                user_id,
+                create_bookmark::Request { id, position, comment: comment.map(str::to_owned) },
+            )
+            .await
+        };
+
+        create(user_id, song_ids[0], 1000, None).await.unwrap();
+        create(user_id, song_ids[1], 2000, Some("comment")).await.unwrap();
+        // Bookmarking the same song again only moves the position.
+        create(user_id, song_ids[0], 3000, Some("resume")).await.unwrap();
+        create(other_user_id, song_ids[1], 4000, None).await.unwrap();
+        assert_eq!(create(user_id, song_id_permission, 5000, None).await.is_ok(), allow);
+
+        let username = mock.user(0).await.username();
+        let bookmark = handler(mock.database(), user_id).await.unwrap().bookmarks.bookmark;
+        let mut bookmark: Vec<_> = bookmark
+            .into_iter()
+            .map(|bookmark| {
+                assert_eq!(bookmark.username, username);
+                (bookmark.entry.short.song.id, bookmark.position, bookmark.comment)
+            })
+            .collect();
+        bookmark.sort();
+
+        let mut expected = vec![
+            (song_ids[0], 3000, Some("resume".to_owned())),
+            (song_ids[1], 2000, Some("comment".to_owned())),
+        ];
+        if allow {
+            expected.push((song_id_permission, 5000, None));
+        }
+        expected.sort();
+        assert_eq!(bookmark, expected);
+
+        delete_bookmark::handler(
+            mock.database(),
+            user_id,
+            delete_bookmark::Request { id: song_ids[0] },
+        )
+        .await
+        .unwrap();
+        assert!(
+            delete_bookmark::handler(
+                mock.database(),
+                user_id,
+                delete_bookmark::Request { id: song_ids[0] },
+            )
+            .await
+            .is_err()
+        );
+        let bookmark = handler(mock.database(), user_id).await.unwrap().bookmarks.bookmark;
+        assert_eq!(bookmark.len(), if allow { 2 } else { 1 });
+    }
+}
@@ -3,45 +3,58 @@ use diesel_async::RunQueryDsl;
 use futures_lite::{StreamExt as _, stream};
 use nghe_api::bookmarks::get_playqueue::Playqueue;
 pub use nghe_api::bookmarks::get_playqueue::{Request, Response};
+use nghe_api::id3::song::Short;
 use nghe_proc_macro::handler;
 use uuid::Uuid;
 
 use crate::Error;
 use crate::database::Database;
-use crate::orm::{id3, playqueues, songs};
+use crate::orm::{id3, playqueues, songs, users};
+
+pub async fn load(
+    database: &Database,
+    user_id: Uuid,
+) -> Result<Option<(String, playqueues::Playqueue, Vec<Short>)>, Error> {
+    let playqueue = playqueues::table
+        .inner_join(users::table)
+        .filter(playqueues::user_id.eq(user_id))
+        .select((users::username, playqueues::Playqueue::as_select()))
+        .get_result::<(String, playqueues::Playqueue)>(&mut database.get().await?)
+        .await
+        .optional()?;
+    let Some((username, playqueue)) = playqueue else {
+        return Ok(None);
+    };
+
+    let entry = stream::iter(playqueue.ids.iter().copied())
+        .then(async |id| {
+            id3::song::short::query::with_user_id(user_id)
+                .filter(songs::id.eq(id))
+                .get_result(&mut database.get().await?)
+                .await?
+                .try_into()
+        })
+        .try_collect()
+        .await?;
+    Ok(Some((username, playqueue, entry)))
+}
 
 #[handler]
 pub async fn handler(database: &Database, user_id: Uuid) -> Result<Response, Error> {
-    Ok(
-        if let Some(data) = playqueues::table
-            .filter(playqueues::user_id.eq(user_id))
-            .select(playqueues::Data::as_select())
-            .get_result(&mut database.get().await?)
-            .await
-            .optional()?
-        {
-            let entry = stream::iter(data.ids)
-                .then(async |id| {
-                    id3::song::short::query::with_user_id(user_id)
-                        .filter(songs::id.eq(id))
-                        .get_result(&mut database.get().await?)
-                        .await?
-                        .try_into()
-                })
-                .try_collect()
-                .await?;
-
-            Response {
-                playqueue: Playqueue {
-                    entry,
-                    current: data.current,
-                    position: data.position.map(i64::try_into).transpose()?,
-                },
-            }
-        } else {
-            Response::default()
-        },
-    )
+    Ok(if let Some((username, playqueue, entry)) = load(database, user_id).await? {
+        Response {
+            playqueue: Playqueue {
+                entry,
+                current: playqueue.current(),
+                position: playqueue.position.map(i64::try_into).transpose()?,
+                username: Some(username),
+                changed: Some(playqueue.changed_at),
+                changed_by: playqueue.changed_by,
+            },
+        }
+    } else {
+        Response::default()
+    })
 }
 
 #[cfg(test)]
@@ -83,6 +96,7 @@ mod test {
         save_playqueue::handler(
             mock.database(),
             user_id,
+            None,
             save_playqueue::Request { ids: song_ids, position: None, current: None },
         )
         .await
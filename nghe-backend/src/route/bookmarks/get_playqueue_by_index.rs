@@ -0,0 +1,109 @@
+use nghe_api::bookmarks::get_playqueue_by_index::PlayqueueByIndex;
+pub use nghe_api::bookmarks::get_playqueue_by_index::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use super::get_playqueue;
+use crate::Error;
+use crate::database::Database;
+
+#[handler]
+pub async fn handler(database: &Database, user_id: Uuid) -> Result<Response, Error> {
+    Ok(if let Some((username, playqueue, entry)) = get_playqueue::load(database, user_id).await? {
+        Response {
+            playqueue: PlayqueueByIndex {
+                entry,
+                current_index: playqueue.current_index.map(i32::try_into).transpose()?,
+                position: playqueue.position.map(i64::try_into).transpose()?,
+                username: Some(username),
+                changed: Some(playqueue.changed_at),
+                changed_by: playqueue.changed_by,
+            },
+        }
+    } else {
+        Response::default()
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::bookmarks::{save_playqueue, save_playqueue_by_index};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song(2).call().await;
+        let song_ids = [music_folder.song_id(0), music_folder.song_id(1)];
+        // The same song appears twice in the queue.
+        let ids = vec![song_ids[0], song_ids[1], song_ids[0]];
+
+        let user_id = mock.user_id(0).await;
+        assert_eq!(handler(mock.database(), user_id).await.unwrap().playqueue.current_index, None);
+
+        save_playqueue_by_index::handler(
+            mock.database(),
+            user_id,
+            Some("device".to_owned()),
+            save_playqueue_by_index::Request {
+                ids: ids.clone(),
+                current_index: Some(2),
+                position: Some(1000),
+            },
+        )
+        .await
+        .unwrap();
+
+        let playqueue = handler(mock.database(), user_id).await.unwrap().playqueue;
+        assert_eq!(playqueue.entry.iter().map(|entry| entry.song.id).collect::<Vec<_>>(), ids);
+        assert_eq!(playqueue.current_index, Some(2));
+        assert_eq!(playqueue.position, Some(1000));
+        assert_eq!(playqueue.username, Some(mock.user(0).await.username()));
+        assert_eq!(playqueue.changed_by.as_deref(), Some("device"));
+        assert!(playqueue.changed.is_some());
+
+        let playqueue = get_playqueue::handler(mock.database(), user_id).await.unwrap().playqueue;
+        assert_eq!(playqueue.current, Some(song_ids[0]));
+        assert_eq!(playqueue.changed_by.as_deref(), Some("device"));
+
+        // Saving without an index based queue resolves the first occurrence of the current song.
+        save_playqueue::handler(
+            mock.database(),
+            user_id,
+            Some("other".to_owned()),
+            save_playqueue::Request { ids, current: Some(song_ids[0]), position: None },
+        )
+        .await
+        .unwrap();
+        let playqueue = handler(mock.database(), user_id).await.unwrap().playqueue;
+        assert_eq!(playqueue.current_index, Some(0));
+        assert_eq!(playqueue.position, None);
+        assert_eq!(playqueue.changed_by.as_deref(), Some("other"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler_invalid_index(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().call().await;
+
+        let user_id = mock.user_id(0).await;
+        for (ids, current_index) in [(vec![music_folder.song_id(0)], Some(1)), (vec![], Some(0))] {
+            assert!(
+                save_playqueue_by_index::handler(
+                    mock.database(),
+                    user_id,
+                    None,
+                    save_playqueue_by_index::Request { ids, current_index, position: None },
+                )
+                .await
+                .is_err()
+            );
+        }
+    }
+}
@@ -1,6 +1,19 @@
+pub mod create_bookmark;
+mod delete_bookmark;
+mod get_bookmarks;
 mod get_playqueue;
+mod get_playqueue_by_index;
 pub mod save_playqueue;
+pub mod save_playqueue_by_index;
 
 nghe_proc_macro::build_router! {
-    modules = [get_playqueue, save_playqueue],
+    modules = [
+        create_bookmark,
+        delete_bookmark,
+        get_bookmarks,
+        get_playqueue,
+        get_playqueue_by_index,
+        save_playqueue,
+        save_playqueue_by_index,
+    ],
 }
@@ -11,8 +11,23 @@ use crate::orm::upsert::Update;
 pub async fn handler(
     database: &Database,
     user_id: Uuid,
+    user_client: Option<String>,
     request: Request,
 ) -> Result<Response, Error> {
-    playqueues::Data::try_from(request)?.update(database, user_id).await?;
+    // The first occurrence is used if the current song appears more than once in the queue.
+    let index = request
+        .current
+        .and_then(|current| request.ids.iter().position(|id| *id == current))
+        .map(u32::try_from)
+        .transpose()?;
+    playqueues::Data::new(
+        request.ids,
+        index,
+        request.position,
+        user_client.as_deref(),
+        crate::time::now().await,
+    )?
+    .update(database, user_id)
+    .await?;
     Ok(Response)
 }
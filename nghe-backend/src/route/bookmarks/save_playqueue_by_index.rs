@@ -0,0 +1,27 @@
+pub use nghe_api::bookmarks::save_playqueue_by_index::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::playqueues;
+use crate::orm::upsert::Update;
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    user_client: Option<String>,
+    request: Request,
+) -> Result<Response, Error> {
+    playqueues::Data::new(
+        request.ids,
+        request.current_index,
+        request.position,
+        user_client.as_deref(),
+        crate::time::now().await,
+    )?
+    .update(database, user_id)
+    .await?;
+    Ok(Response)
+}
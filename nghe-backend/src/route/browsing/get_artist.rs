@@ -19,7 +19,7 @@ pub async fn handler(
             .filter(artists::id.eq(request.id))
             .get_result(&mut database.get().await?)
             .await?
-            .try_into(database, user_id)
+            .try_into(database, user_id, request.release_type)
             .await?,
     })
 }
@@ -58,10 +58,14 @@ mod tests {
                 .await;
         }
 
-        let artist = handler(mock.database(), mock.user_id(0).await, Request { id: artist_id })
-            .await
-            .unwrap()
-            .artist;
+        let artist = handler(
+            mock.database(),
+            mock.user_id(0).await,
+            Request { id: artist_id, release_type: None },
+        )
+        .await
+        .unwrap()
+        .artist;
 
         let n_album: usize = n_album.try_into().unwrap();
         assert_eq!(artist.album.len(), n_album);
@@ -9,7 +9,7 @@ use uuid::Uuid;
 
 use crate::Error;
 use crate::database::Database;
-use crate::orm::{artist_informations, artists, id3};
+use crate::orm::{artist_informations, artists, id3, similar_artists};
 
 #[handler]
 pub async fn handler(
@@ -29,15 +29,97 @@ pub async fn handler(
         .await
         .optional()?;
 
+    // Only similar artists that the user has access to are returned, ordered by their similarity.
+    let similar_ids: Vec<Uuid> = similar_artists::query::local(&[request.id])
+        .limit(request.count.unwrap_or(20).into())
+        .get_results(&mut database.get().await?)
+        .await?;
+    let mut similar_artist: Vec<id3::artist::Artist> = if similar_ids.is_empty() {
+        vec![]
+    } else {
+        id3::artist::query::with_user_id(user_id)
+            .filter(artists::id.eq_any(&similar_ids))
+            .get_results(&mut database.get().await?)
+            .await?
+    };
+    similar_artist
+        .sort_by_key(|artist| similar_ids.iter().position(|id| *id == artist.required.id));
+    let similar_artist =
+        similar_artist.into_iter().map(id3::artist::Artist::try_into).try_collect()?;
+
     Ok(Response {
         artist_info2: if let Some(lastfm) = lastfm {
             ArtistInfo2 {
                 music_brainz_id: music_brainz_id.or(lastfm.mbz_id),
                 lastfm_url: lastfm.url.map(Cow::into_owned),
                 biography: lastfm.biography.map(Cow::into_owned),
+                similar_artist,
             }
         } else {
-            ArtistInfo2 { music_brainz_id, ..Default::default() }
+            ArtistInfo2 { music_brainz_id, similar_artist, ..Default::default() }
         },
     })
 }
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_similar_artist(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().allow(allow).call().await;
+        mock.add_music_folder().call().await;
+
+        mock.music_folder(0)
+            .await
+            .add_audio_artist(["Permission".into()], ["Permission".into()], false, 1)
+            .await;
+        let mut music_folder = mock.music_folder(1).await;
+        music_folder.add_audio_artist(["Seed".into()], ["Seed".into()], false, 1).await;
+        music_folder.add_audio_artist(["Less".into()], ["Less".into()], false, 1).await;
+        music_folder.add_audio_artist(["More".into()], ["More".into()], false, 1).await;
+
+        let seed_id = audio::Artist::from("Seed").upsert_mock(&mock).await;
+        diesel::insert_into(similar_artists::table)
+            .values([("less", 0.2), ("Permission", 0.5), ("MORE", 0.8), ("Not Local", 1.0)].map(
+                |(name, score)| {
+                    (
+                        similar_artists::artist_id.eq(seed_id),
+                        similar_artists::Data { name: name.into(), mbz_id: None, score },
+                    )
+                },
+            ))
+            .execute(&mut mock.get().await)
+            .await
+            .unwrap();
+
+        let user_id = mock.user_id(0).await;
+        let similar_artist = async |count| {
+            handler(mock.database(), user_id, Request { id: seed_id, count })
+                .await
+                .unwrap()
+                .artist_info2
+                .similar_artist
+                .into_iter()
+                .map(|artist| artist.required.name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            similar_artist(None).await,
+            if allow { vec!["More", "Permission", "Less"] } else { vec!["More", "Less"] }
+        );
+        assert_eq!(similar_artist(Some(1)).await, vec!["More"]);
+    }
+}
@@ -1,3 +1,5 @@
+use diesel::dsl::exists;
+use diesel::{BoolExpressionMethods, ExpressionMethods, QueryDsl};
 use diesel_async::RunQueryDsl;
 use itertools::Itertools;
 use nghe_api::browsing::get_artists::Artists;
@@ -6,7 +8,7 @@ use nghe_proc_macro::{check_music_folder, handler};
 use uuid::Uuid;
 
 use crate::database::Database;
-use crate::orm::id3;
+use crate::orm::{artists, id3, songs_album_artists, songs_artists, songs_contributors};
 use crate::{Error, config};
 
 #[handler]
@@ -17,9 +19,30 @@ pub async fn handler(
 ) -> Result<Response, Error> {
     let ignored_articles = database.get_config::<config::Index>().await?;
 
-    let artists =
-        #[check_music_folder]
-        id3::artist::query::with_user_id(user_id).get_results(&mut database.get().await?).await?;
+    let role = request.role.map(songs_contributors::Role::try_from);
+    let artists = #[check_music_folder]
+    {
+        let query = id3::artist::query::with_user_id(user_id).into_boxed();
+        match role {
+            // Artists which only appear as contributors are listed with a role filter only.
+            None => query.filter(
+                songs_artists::artist_id
+                    .is_not_null()
+                    .or(songs_album_artists::album_artist_id.is_not_null()),
+            ),
+            Some(Ok(role)) => query.filter(exists(
+                songs_contributors::table
+                    .filter(songs_contributors::artist_id.eq(artists::id))
+                    .filter(songs_contributors::role.eq(role)),
+            )),
+            Some(Err(nghe_api::id3::artist::Role::AlbumArtist)) => {
+                query.filter(songs_album_artists::album_artist_id.is_not_null())
+            }
+            Some(Err(_)) => query.filter(songs_artists::artist_id.is_not_null()),
+        }
+        .get_results(&mut database.get().await?)
+        .await?
+    };
 
     let index = artists
         .into_iter()
@@ -31,7 +54,12 @@ pub async fn handler(
                 name,
                 artist: artist
                     .into_iter()
-                    .sorted_by(|lhs, rhs| Ord::cmp(&lhs.required.name, &rhs.required.name))
+                    .sorted_by(|lhs, rhs| {
+                        Ord::cmp(
+                            lhs.sort_name.as_ref().unwrap_or(&lhs.required.name),
+                            rhs.sort_name.as_ref().unwrap_or(&rhs.required.name),
+                        )
+                    })
                     .map(id3::artist::Artist::try_into)
                     .try_collect()?,
             })
@@ -44,10 +72,13 @@ pub async fn handler(
 #[cfg(test)]
 #[coverage(off)]
 mod tests {
+    use std::borrow::Cow;
+
     use concat_string::concat_string;
     use rstest::rstest;
 
     use super::*;
+    use crate::file::audio;
     use crate::test::{Mock, mock};
 
     #[rstest]
@@ -62,12 +93,15 @@ mod tests {
         )
         .await;
 
-        let index =
-            handler(mock.database(), mock.user_id(0).await, Request { music_folder_ids: None })
-                .await
-                .unwrap()
-                .artists
-                .index;
+        let index = handler(
+            mock.database(),
+            mock.user_id(0).await,
+            Request { music_folder_ids: None, role: None },
+        )
+        .await
+        .unwrap()
+        .artists
+        .index;
 
         for (i, index) in index.into_iter().enumerate() {
             let name =
@@ -80,6 +114,54 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_sort_name(#[future(awt)] mock: Mock) {
+        let artist = |name: &'static str, sort_name: Option<&'static str>| audio::Artist {
+            sort_name: sort_name.map(Cow::Borrowed),
+            ..name.into()
+        };
+        mock.add_audio_artist(
+            0,
+            [
+                artist("Bob Dylan", Some("Dylan, Bob")),
+                artist("Bee Gees", None),
+                artist("The Beatles", Some("Beatles, The")),
+                artist("Adele", None),
+            ],
+            [],
+            false,
+            1,
+        )
+        .await;
+
+        let index: Vec<_> = handler(
+            mock.database(),
+            mock.user_id(0).await,
+            Request { music_folder_ids: None, role: None },
+        )
+        .await
+        .unwrap()
+        .artists
+        .index
+        .into_iter()
+        .map(|index| {
+            (
+                index.name,
+                index.artist.into_iter().map(|artist| artist.required.name).collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+        assert_eq!(
+            index,
+            [
+                ("A".to_owned(), vec!["Adele".to_owned()]),
+                ("B".to_owned(), vec!["The Beatles".to_owned(), "Bee Gees".to_owned()]),
+                ("D".to_owned(), vec!["Bob Dylan".to_owned()]),
+            ]
+        );
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_check_music_folder(
@@ -97,16 +179,74 @@ mod tests {
 
         let user_id = mock.user_id(0).await;
         let with_user_id =
-            handler(mock.database(), user_id, Request { music_folder_ids: None }).await.unwrap();
+            handler(mock.database(), user_id, Request { music_folder_ids: None, role: None })
+                .await
+                .unwrap();
         let with_music_folder = handler(
             mock.database(),
             user_id,
             Request {
                 music_folder_ids: Some(vec![music_folder_deny.id(), music_folder_allow.id()]),
+                role: None,
             },
         )
         .await
         .unwrap();
         assert_eq!(with_user_id, with_music_folder);
     }
+
+    #[rstest]
+    #[case(None, &["A", "B"])]
+    #[case(Some(nghe_api::id3::artist::Role::Artist), &["A"])]
+    #[case(Some(nghe_api::id3::artist::Role::AlbumArtist), &["B"])]
+    #[case(Some(nghe_api::id3::artist::Role::Composer), &["C"])]
+    #[case(Some(nghe_api::id3::artist::Role::Performer), &["A", "D"])]
+    #[case(Some(nghe_api::id3::artist::Role::Conductor), &[])]
+    #[tokio::test]
+    async fn test_role(
+        #[future(awt)] mock: Mock,
+        #[case] role: Option<nghe_api::id3::artist::Role>,
+        #[case] names: &[&str],
+    ) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder
+            .add_audio()
+            .artists(audio::Artists {
+                song: ["A".into()].into(),
+                album: ["B".into()].into(),
+                compilation: false,
+            })
+            .contributors(
+                [
+                    audio::Contributor::new(audio::contributor::Role::Composer, "C", None),
+                    audio::Contributor::new(audio::contributor::Role::Performer, "D", None),
+                    audio::Contributor::new(
+                        audio::contributor::Role::Performer,
+                        "A",
+                        Some("piano"),
+                    ),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
+            )
+            .call()
+            .await;
+
+        let index = handler(
+            mock.database(),
+            mock.user_id(0).await,
+            Request { music_folder_ids: None, role },
+        )
+        .await
+        .unwrap()
+        .artists
+        .index;
+        let artists: Vec<_> = index
+            .into_iter()
+            .flat_map(|index| index.artist)
+            .map(|artist| artist.required.name)
+            .collect();
+        assert_eq!(artists, names);
+    }
 }
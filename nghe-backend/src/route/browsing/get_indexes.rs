@@ -0,0 +1,148 @@
+use std::collections::BTreeSet;
+
+use diesel_async::RunQueryDsl;
+use itertools::Itertools;
+use nghe_api::browsing::get_indexes::{Artist, Index, Indexes};
+pub use nghe_api::browsing::get_indexes::{Request, Response};
+use nghe_proc_macro::{check_music_folder, handler};
+use uuid::Uuid;
+
+use super::get_music_directory;
+use crate::database::Database;
+use crate::file::audio;
+use crate::orm::directory::{self, Directory};
+use crate::{Error, config};
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let ignored_articles = database.get_config::<config::Index>().await?;
+    let prefixes = config::Index::split(&ignored_articles);
+
+    let entries =
+        #[check_music_folder]
+        directory::query::with_user_id(user_id).get_results(&mut database.get().await?).await?;
+
+    let last_modified = entries
+        .iter()
+        .map(|entry| entry.updated_at.unix_timestamp_nanos() / 1_000_000)
+        .max()
+        .unwrap_or_default()
+        .try_into()?;
+
+    let mut folders = BTreeSet::new();
+    let mut files = vec![];
+    for entry in &entries {
+        let entry_directory = entry.directory();
+        let root = Directory::root(entry_directory.music_folder_id);
+        if entry_directory == root {
+            files.push((entry.music_folder_id, entry.id));
+        } else if let Some(folder) = root.child_to(entry_directory) {
+            folders.insert(folder);
+        }
+    }
+
+    let index = folders
+        .into_iter()
+        .map(|folder| {
+            Ok::<_, Error>((audio::Artist::name_index(folder.name(), &prefixes)?, folder))
+        })
+        .process_results(|iter| iter.into_group_map())?
+        .into_iter()
+        .sorted_by(|lhs, rhs| Ord::cmp(&lhs.0, &rhs.0))
+        .map(|(name, folders)| Index {
+            name: name.to_string(),
+            artist: folders
+                .into_iter()
+                .map(|folder| Artist { id: folder.id(), name: folder.name().to_owned() })
+                .sorted_by(|lhs, rhs| Ord::cmp(&lhs.name, &rhs.name))
+                .collect(),
+        })
+        .collect();
+
+    let mut child = vec![];
+    for (music_folder_id, song_ids) in files.into_iter().into_group_map() {
+        child.extend(
+            get_music_directory::files(database, user_id, music_folder_id, &song_ids).await?,
+        );
+    }
+
+    Ok(Response { indexes: Indexes { ignored_articles, last_modified, index, child } })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use nghe_api::browsing::get_music_directory::Child;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_indexes(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().call().await;
+        mock.add_music_folder().allow(allow).call().await;
+
+        let mut music_folder = mock.music_folder(0).await;
+        for relative_path in ["The Beatles/1.mp3", "Adele/2.mp3", "Adele/Live/3.mp3", "4.mp3"] {
+            music_folder.add_audio().relative_path(relative_path.into()).call().await;
+        }
+        let mut music_folder_other = mock.music_folder(1).await;
+        music_folder_other.add_audio().relative_path("Bach/5.mp3".into()).call().await;
+
+        let indexes = handler(mock.database(), mock.user_id(0).await, Request::default())
+            .await
+            .unwrap()
+            .indexes;
+        let index: Vec<_> = indexes
+            .index
+            .iter()
+            .map(|index| {
+                (
+                    index.name.as_str(),
+                    index.artist.iter().map(|artist| artist.name.as_str()).collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        if allow {
+            assert_eq!(index, vec![("A", vec!["Adele"]), ("B", vec!["Bach", "The Beatles"])]);
+        } else {
+            assert_eq!(index, vec![("A", vec!["Adele"]), ("B", vec!["The Beatles"])]);
+        }
+
+        let child: Vec<_> = indexes
+            .child
+            .iter()
+            .map(|child| match child {
+                Child::File(file) => file.song.song.id,
+                Child::Folder(folder) => folder.id,
+            })
+            .collect();
+        assert_eq!(child, vec![music_folder.song_id(3)]);
+
+        let indexes = handler(
+            mock.database(),
+            mock.user_id(0).await,
+            Request { music_folder_ids: Some(vec![music_folder_other.id()]) },
+        )
+        .await
+        .unwrap()
+        .indexes;
+        if allow {
+            assert_eq!(indexes.index.len(), 1);
+        } else {
+            assert!(indexes.index.is_empty());
+        }
+        assert!(indexes.child.is_empty());
+    }
+}
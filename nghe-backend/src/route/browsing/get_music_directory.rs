@@ -0,0 +1,191 @@
+use std::collections::BTreeSet;
+
+use diesel::dsl::sql;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, sql_types};
+use diesel_async::RunQueryDsl;
+use nghe_api::browsing::get_music_directory::{Child, Directory, File, Folder};
+pub use nghe_api::browsing::get_music_directory::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::{albums, directories, directory, id3, music_folders, permission, songs};
+use crate::{Error, error};
+
+pub async fn files(
+    database: &Database,
+    user_id: Uuid,
+    parent: Uuid,
+    song_ids: &[Uuid],
+) -> Result<Vec<Child>, Error> {
+    id3::song::short::query::with_user_id(user_id)
+        .filter(songs::id.eq_any(song_ids))
+        .get_results(&mut database.get().await?)
+        .await?
+        .into_iter()
+        .map(|song| {
+            Ok(Child::File(Box::new(File { song: song.try_into()?, parent, is_dir: false })))
+        })
+        .try_collect()
+}
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    // The root of a music folder shares its id with the music folder, the other directories are
+    // looked up from the ones stored while scanning.
+    let root = music_folders::table
+        .filter(music_folders::id.eq(request.id))
+        .filter(permission::with_music_folder(user_id))
+        .select(music_folders::name)
+        .get_result::<String>(&mut database.get().await?)
+        .await
+        .optional()?;
+    let (music_folder_id, path) = if root.is_some() {
+        (request.id, String::new())
+    } else {
+        directories::table
+            .inner_join(music_folders::table)
+            .filter(directories::id.eq(request.id))
+            .filter(permission::with_music_folder(user_id))
+            .select((directories::music_folder_id, directories::path))
+            .get_result(&mut database.get().await?)
+            .await
+            .optional()?
+            .ok_or_else(|| error::Kind::NotFound)?
+    };
+    let directory = directory::Directory { music_folder_id, path: &path };
+    let name = root.unwrap_or_else(|| directory.name().to_owned());
+
+    let paths: Vec<String> = directories::table
+        .filter(directories::parent_id.eq(request.id))
+        .select(directories::path)
+        .get_results(&mut database.get().await?)
+        .await?;
+    let folders: BTreeSet<_> =
+        paths.iter().map(|path| directory::Directory { music_folder_id, path }).collect();
+    let song_ids: Vec<Uuid> = songs::table
+        .inner_join(albums::table)
+        .filter(permission::with_album(user_id))
+        .filter(albums::music_folder_id.eq(directory.music_folder_id))
+        .filter(sql::<sql_types::Text>(directory::PATH).eq(directory.path))
+        .select(songs::id)
+        .get_results(&mut database.get().await?)
+        .await?;
+
+    let child = folders
+        .into_iter()
+        .map(|folder| {
+            Child::Folder(Folder {
+                id: folder.id(),
+                parent: request.id,
+                title: folder.name().to_owned(),
+                is_dir: true,
+            })
+        })
+        .chain(files(database, user_id, request.id, &song_ids).await?)
+        .collect();
+
+    Ok(Response {
+        directory: Directory {
+            id: request.id,
+            parent: directory.parent().map(|parent| parent.id()),
+            name,
+            child,
+        },
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test::{Mock, mock};
+
+    fn folder_titles(directory: &Directory) -> Vec<&str> {
+        directory
+            .child
+            .iter()
+            .filter_map(|child| match child {
+                Child::Folder(folder) => Some(folder.title.as_str()),
+                Child::File(_) => None,
+            })
+            .collect()
+    }
+
+    fn file_ids(directory: &Directory) -> Vec<Uuid> {
+        directory
+            .child
+            .iter()
+            .filter_map(|child| match child {
+                Child::File(file) => Some(file.song.song.id),
+                Child::Folder(_) => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_directory(
+        #[future(awt)]
+        #[with(1, 1)]
+        mock: Mock,
+    ) {
+        let database = mock.database();
+        let user_id = mock.user_id(0).await;
+        let mut music_folder = mock.music_folder(0).await;
+        for relative_path in ["a/b/1.mp3", "a/2.mp3", "3.mp3", "c/4.mp3"] {
+            music_folder.add_audio().relative_path(relative_path.into()).call().await;
+        }
+        let music_folder_id = music_folder.id();
+
+        let root =
+            handler(database, user_id, Request { id: music_folder_id }).await.unwrap().directory;
+        assert_eq!(root.parent, None);
+        let name: String = music_folders::table
+            .filter(music_folders::id.eq(music_folder_id))
+            .select(music_folders::name)
+            .get_result(&mut mock.get().await)
+            .await
+            .unwrap();
+        assert_eq!(root.name, name);
+        assert_eq!(folder_titles(&root), vec!["a", "c"]);
+        assert_eq!(file_ids(&root), vec![music_folder.song_id(2)]);
+
+        let a = root
+            .child
+            .iter()
+            .find_map(|child| match child {
+                Child::Folder(folder) if folder.title == "a" => Some(folder.id),
+                _ => None,
+            })
+            .unwrap();
+        let a = handler(database, user_id, Request { id: a }).await.unwrap().directory;
+        assert_eq!(a.parent, Some(music_folder_id));
+        assert_eq!(a.name, "a");
+        assert_eq!(folder_titles(&a), vec!["b"]);
+        assert_eq!(file_ids(&a), vec![music_folder.song_id(1)]);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_directory_deny(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+    ) {
+        mock.add_music_folder().allow(false).call().await;
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().relative_path("a/1.mp3".into()).call().await;
+
+        let result =
+            handler(mock.database(), mock.user_id(0).await, Request { id: music_folder.id() })
+                .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use itertools::Itertools;
+use nghe_api::browsing::get_similar_songs::SimilarSongs;
+pub use nghe_api::browsing::get_similar_songs::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use super::get_similar_songs2;
+use crate::database::Database;
+use crate::orm::{artists, id3, similar_songs, songs, songs_album_artists, songs_artists};
+use crate::{Error, error};
+
+async fn seed_artist_ids(database: &Database, id: Uuid) -> Result<Vec<Uuid>, Error> {
+    // The id can either be an artist, an album or a song.
+    if let Some(artist_id) = artists::table
+        .filter(artists::id.eq(id))
+        .select(artists::id)
+        .get_result(&mut database.get().await?)
+        .await
+        .optional()?
+    {
+        return Ok(vec![artist_id]);
+    }
+
+    let artist_ids: Vec<Uuid> = songs_album_artists::table
+        .inner_join(songs::table)
+        .filter(songs::album_id.eq(id))
+        .select(songs_album_artists::album_artist_id)
+        .distinct()
+        .get_results(&mut database.get().await?)
+        .await?;
+    if !artist_ids.is_empty() {
+        return Ok(artist_ids);
+    }
+
+    Ok(songs_artists::table
+        .filter(songs_artists::song_id.eq(id))
+        .select(songs_artists::artist_id)
+        .get_results(&mut database.get().await?)
+        .await?)
+}
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let count = request.count.unwrap_or(50);
+    let artist_ids = seed_artist_ids(database, request.id).await?;
+    if artist_ids.is_empty() {
+        return error::Kind::NotFound.into();
+    }
+
+    // Songs stored from the Last.fm `track.getSimilar` during the scan come first, the rest is
+    // filled up with the artist based mix.
+    let song_ids: Vec<Uuid> = similar_songs::query::local(request.id)
+        .get_results::<Uuid>(&mut database.get().await?)
+        .await?
+        .into_iter()
+        .unique()
+        .collect();
+    let mut similars: Vec<id3::song::full::Full> = if song_ids.is_empty() {
+        vec![]
+    } else {
+        let mut similars: Vec<id3::song::full::Full> =
+            id3::song::full::query::with_user_id(user_id)
+                .filter(songs::id.eq_any(&song_ids))
+                .get_results(&mut database.get().await?)
+                .await?;
+        similars.sort_by_key(|song| song_ids.iter().position(|id| *id == song.short.song.id));
+        similars.truncate(count.try_into()?);
+        similars
+    };
+
+    let remaining = count - u32::try_from(similars.len())?;
+    if remaining > 0 {
+        let exclude_song_ids: Vec<_> = song_ids.into_iter().chain([request.id]).collect();
+        similars.extend(
+            get_similar_songs2::query(database, user_id, &artist_ids, &exclude_song_ids, remaining)
+                .await?,
+        );
+    }
+
+    Ok(Response {
+        similar_songs: SimilarSongs {
+            song: similars.into_iter().map(id3::song::full::Full::try_into).try_collect()?,
+        },
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use diesel::JoinOnDsl;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::orm::similar_artists;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(1, 1)]
+        mock: Mock,
+    ) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio_artist(["Seed".into()], ["Seed".into()], false, 3).await;
+        music_folder.add_audio_artist(["Similar".into()], ["Similar".into()], false, 2).await;
+        music_folder.add_audio_artist(["Other".into()], ["Other".into()], false, 2).await;
+
+        let seed_id = audio::Artist::from("Seed").upsert_mock(&mock).await;
+        diesel::insert_into(similar_artists::table)
+            .values((
+                similar_artists::artist_id.eq(seed_id),
+                similar_artists::Data { name: "Similar".into(), mbz_id: None, score: 1.0 },
+            ))
+            .execute(&mut mock.get().await)
+            .await
+            .unwrap();
+
+        let song_id = music_folder.song_id(0);
+        let album_id = songs::table
+            .filter(songs::id.eq(song_id))
+            .select(songs::album_id)
+            .get_result(&mut mock.get().await)
+            .await
+            .unwrap();
+
+        let user_id = mock.user_id(0).await;
+        let similar_songs = async |id| {
+            handler(mock.database(), user_id, Request { id, count: None })
+                .await
+                .map(|response| response.similar_songs.song)
+        };
+
+        for id in [seed_id, album_id] {
+            let songs = similar_songs(id).await.unwrap();
+            assert_eq!(songs.len(), 5);
+            assert!(songs.iter().all(|song| song.short.song.artist != "Other"));
+        }
+
+        // The seed song itself is never part of the mix.
+        let songs = similar_songs(song_id).await.unwrap();
+        assert_eq!(songs.len(), 4);
+        assert!(songs.iter().all(|song| song.short.song.id != song_id));
+
+        // Stored similar songs are matched case-insensitively and come first.
+        let (other_id, other_title): (Uuid, String) = songs::table
+            .inner_join(songs_artists::table)
+            .inner_join(artists::table.on(artists::id.eq(songs_artists::artist_id)))
+            .filter(artists::name.eq("Other"))
+            .select((songs::id, songs::title))
+            .first(&mut mock.get().await)
+            .await
+            .unwrap();
+        similar_songs::Data::replace(
+            mock.database(),
+            song_id,
+            &[similar_songs::Data {
+                name: other_title.to_uppercase().into(),
+                artist_name: "other".into(),
+                score: 1.0,
+            }],
+        )
+        .await
+        .unwrap();
+        let songs = similar_songs(song_id).await.unwrap();
+        assert_eq!(songs.len(), 5);
+        assert_eq!(songs[0].short.song.id, other_id);
+
+        assert!(similar_songs(Uuid::new_v4()).await.is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use nghe_api::browsing::get_similar_songs2::SimilarSongs2;
+pub use nghe_api::browsing::get_similar_songs2::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{function, id3, similar_artists, songs, songs_artists};
+
+const MAX_SIMILAR_ARTIST: i64 = 20;
+
+diesel::alias!(songs_artists as songs_artists_similar: SongsArtistsSimilar);
+
+pub async fn query(
+    database: &Database,
+    user_id: Uuid,
+    artist_ids: &[Uuid],
+    exclude_song_ids: &[Uuid],
+    count: u32,
+) -> Result<Vec<id3::song::full::Full>, Error> {
+    // Songs of the seed artists are mixed with songs of the most similar local artists. The
+    // artist filter is done through a sub-query on an alias so the artist list of each song stays
+    // complete.
+    let similar_ids: Vec<Uuid> = similar_artists::query::local(artist_ids)
+        .limit(MAX_SIMILAR_ARTIST)
+        .get_results(&mut database.get().await?)
+        .await?;
+    let artist_ids: Vec<_> = artist_ids.iter().copied().chain(similar_ids).collect();
+
+    Ok(id3::song::full::query::with_user_id(user_id)
+        .filter(
+            songs::id.eq_any(
+                songs_artists_similar
+                    .filter(
+                        songs_artists_similar.field(songs_artists::artist_id).eq_any(artist_ids),
+                    )
+                    .select(songs_artists_similar.field(songs_artists::song_id)),
+            ),
+        )
+        .filter(songs::id.ne_all(exclude_song_ids))
+        .order_by(function::random())
+        .limit(count.into())
+        .get_results(&mut database.get().await?)
+        .await?)
+}
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    Ok(Response {
+        similar_songs2: SimilarSongs2 {
+            song: query(database, user_id, &[request.id], &[], request.count.unwrap_or(50))
+                .await?
+                .into_iter()
+                .map(id3::song::full::Full::try_into)
+                .try_collect()?,
+        },
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use diesel_async::RunQueryDsl;
+    use itertools::Itertools;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().allow(allow).call().await;
+        mock.add_music_folder().call().await;
+
+        let mut music_folder_permission = mock.music_folder(0).await;
+        music_folder_permission
+            .add_audio_artist(["Permission".into()], ["Permission".into()], false, 1)
+            .await;
+        let mut music_folder = mock.music_folder(1).await;
+        music_folder.add_audio_artist(["Seed".into()], ["Seed".into()], false, 2).await;
+        music_folder.add_audio_artist(["Similar".into()], ["Similar".into()], false, 3).await;
+        music_folder.add_audio_artist(["Other".into()], ["Other".into()], false, 4).await;
+
+        let seed_id = audio::Artist::from("Seed").upsert_mock(&mock).await;
+        diesel::insert_into(similar_artists::table)
+            .values([("SIMILAR", 0.9), ("Permission", 0.5), ("Not Local", 0.1)].map(
+                |(name, score)| {
+                    (
+                        similar_artists::artist_id.eq(seed_id),
+                        similar_artists::Data { name: name.into(), mbz_id: None, score },
+                    )
+                },
+            ))
+            .execute(&mut mock.get().await)
+            .await
+            .unwrap();
+
+        let user_id = mock.user_id(0).await;
+        let songs = handler(mock.database(), user_id, Request { id: seed_id, count: None })
+            .await
+            .unwrap()
+            .similar_songs2
+            .song;
+        let artists: Vec<_> =
+            songs.iter().map(|song| song.short.song.artist.as_str()).sorted().dedup().collect();
+        assert_eq!(
+            artists,
+            if allow { vec!["Permission", "Seed", "Similar"] } else { vec!["Seed", "Similar"] }
+        );
+        assert_eq!(songs.len(), if allow { 6 } else { 5 });
+
+        let songs = handler(mock.database(), user_id, Request { id: seed_id, count: Some(2) })
+            .await
+            .unwrap()
+            .similar_songs2
+            .song;
+        assert_eq!(songs.len(), 2);
+    }
+}
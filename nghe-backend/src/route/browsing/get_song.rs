@@ -61,7 +61,7 @@ mod test {
                 .unwrap()
                 .song;
 
-        assert_eq!(database_song.short.album, album.name);
+        assert_eq!(database_song.short.album, album.main.name);
         assert_eq!(database_song.short.album_id, album_id);
 
         let database_artists: Vec<_> =
@@ -4,10 +4,16 @@ pub mod get_artist;
 mod get_artist_info2;
 pub mod get_artists;
 mod get_genres;
+mod get_indexes;
+mod get_music_directory;
 mod get_music_folders;
-mod get_song;
+mod get_similar_songs;
+mod get_similar_songs2;
+pub mod get_song;
 mod get_top_songs;
 
+use crate::integration::Informant;
+
 nghe_proc_macro::build_router! {
     modules = [
         get_album,
@@ -16,8 +22,13 @@ nghe_proc_macro::build_router! {
         get_artist_info2,
         get_artists,
         get_genres,
+        get_indexes,
+        get_music_directory,
         get_music_folders,
+        get_similar_songs,
+        get_similar_songs2,
         get_song,
         get_top_songs
     ],
+    extensions = [Informant]
 }
@@ -0,0 +1,115 @@
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use itertools::Itertools;
+pub use nghe_api::history::get_play_history::{Request, Response};
+use nghe_proc_macro::handler;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{id3, plays, songs};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let plays = plays::query::with_user_id(
+        user_id,
+        request.from.unwrap_or(OffsetDateTime::UNIX_EPOCH),
+        request.to.unwrap_or_else(OffsetDateTime::now_utc),
+    )
+    .order_by(plays::played_at.desc())
+    .limit(request.count.unwrap_or(50).into())
+    .offset(request.offset.unwrap_or(0).into())
+    .select(plays::History::as_select())
+    .get_results(&mut database.get().await?)
+    .await?;
+
+    let song_ids: Vec<_> = plays.iter().map(|play| play.song_id).unique().collect();
+    let songs = id3::song::short::query::with_user_id(user_id)
+        .filter(songs::id.eq_any(song_ids))
+        .get_results(&mut database.get().await?)
+        .await?
+        .into_iter()
+        .map(id3::song::short::Short::try_into)
+        .try_collect()?;
+
+    Ok(Response { plays: plays.into_iter().map(plays::History::into).collect(), songs })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+    use time::Duration;
+
+    use super::*;
+    use crate::route::media_annotation::scrobble;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(2, 1)]
+        mock: Mock,
+    ) {
+        let user_id = mock.user_id(0).await;
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song(3).call().await;
+        let ids: Vec<_> = music_folder.database.keys().copied().collect();
+
+        let now = OffsetDateTime::now_utc().replace_nanosecond(0).unwrap();
+        let times: Vec<_> = (0..3).map(|index| now - Duration::hours(index + 1)).collect();
+        scrobble::handler(
+            mock.database(),
+            &mock.scrobbler,
+            user_id,
+            Some("client".to_owned()),
+            scrobble::Request { ids: ids.clone(), times: Some(times.clone()), submission: None },
+        )
+        .await
+        .unwrap();
+        scrobble::handler(
+            mock.database(),
+            &mock.scrobbler,
+            mock.user_id(1).await,
+            None,
+            scrobble::Request { ids: ids.clone(), times: None, submission: None },
+        )
+        .await
+        .unwrap();
+
+        let response = handler(
+            mock.database(),
+            user_id,
+            Request { count: Some(2), offset: None, from: None, to: None },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            response.plays.iter().map(|play| (play.song_id, play.played_at)).collect::<Vec<_>>(),
+            vec![(ids[0], times[0]), (ids[1], times[1])]
+        );
+        assert!(response.plays.iter().all(|play| play.client.as_deref() == Some("client")));
+        assert_eq!(
+            response.songs.iter().map(|song| song.song.id).sorted().collect::<Vec<_>>(),
+            ids[0..2].iter().copied().sorted().collect::<Vec<_>>()
+        );
+
+        let response = handler(
+            mock.database(),
+            user_id,
+            Request { count: None, offset: Some(1), from: Some(times[2]), to: Some(times[0]) },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            response.plays.iter().map(|play| play.song_id).collect::<Vec<_>>(),
+            vec![ids[2]]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use diesel::dsl::count_star;
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::history::get_top_plays::{Request, Response};
+use nghe_proc_macro::handler;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{albums, artists, genres, plays, songs, songs_artists, songs_genres};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let count: i64 = request.count.unwrap_or(10).into();
+    let from = request.from.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+    let to = request.to.unwrap_or_else(OffsetDateTime::now_utc);
+    let query = || plays::query::with_user_id(user_id, from, to);
+
+    let songs = query()
+        .group_by(songs::id)
+        .select((songs::id, songs::title, count_star()))
+        .order_by((count_star().desc(), songs::title.asc()))
+        .limit(count)
+        .get_results::<plays::Top>(&mut database.get().await?)
+        .await?;
+
+    let albums = query()
+        .group_by(albums::id)
+        .select((albums::id, albums::name, count_star()))
+        .order_by((count_star().desc(), albums::name.asc()))
+        .limit(count)
+        .get_results::<plays::Top>(&mut database.get().await?)
+        .await?;
+
+    let artists = query()
+        .inner_join(songs_artists::table.on(songs_artists::song_id.eq(songs::id)))
+        .inner_join(artists::table.on(artists::id.eq(songs_artists::artist_id)))
+        .group_by(artists::id)
+        .select((artists::id, artists::name, count_star()))
+        .order_by((count_star().desc(), artists::name.asc()))
+        .limit(count)
+        .get_results::<plays::Top>(&mut database.get().await?)
+        .await?;
+
+    let genres = query()
+        .inner_join(songs_genres::table.on(songs_genres::song_id.eq(songs::id)))
+        .inner_join(genres::table.on(genres::id.eq(songs_genres::genre_id)))
+        .group_by(genres::id)
+        .select((genres::id, genres::value, count_star()))
+        .order_by((count_star().desc(), genres::value.asc()))
+        .limit(count)
+        .get_results::<plays::Top>(&mut database.get().await?)
+        .await?;
+
+    Ok(Response {
+        songs: songs.into_iter().map(plays::Top::into).collect(),
+        albums: albums.into_iter().map(plays::Top::into).collect(),
+        artists: artists.into_iter().map(plays::Top::into).collect(),
+        genres: genres.into_iter().map(plays::Top::into).collect(),
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::media_annotation::scrobble;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let user_id = mock.user_id(0).await;
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song(3).call().await;
+        let ids: Vec<_> = music_folder.database.keys().copied().collect();
+
+        for n_play in 1..=3 {
+            scrobble::handler(
+                mock.database(),
+                &mock.scrobbler,
+                user_id,
+                None,
+                scrobble::Request { ids: ids[0..n_play].to_vec(), times: None, submission: None },
+            )
+            .await
+            .unwrap();
+        }
+
+        let response =
+            handler(mock.database(), user_id, Request { count: Some(2), from: None, to: None })
+                .await
+                .unwrap();
+        assert_eq!(
+            response.songs.iter().map(|top| (top.id, top.play_count)).collect::<Vec<_>>(),
+            vec![(ids[0], 3), (ids[1], 2)]
+        );
+
+        let response =
+            handler(mock.database(), user_id, Request { count: None, from: None, to: None })
+                .await
+                .unwrap();
+        assert_eq!(response.songs.len(), 3);
+        assert_eq!(response.albums.iter().map(|top| top.play_count).sum::<u64>(), 6);
+        assert!(response.artists.iter().all(|top| top.play_count <= 6));
+
+        let response = handler(
+            mock.database(),
+            user_id,
+            Request { count: None, from: None, to: Some(OffsetDateTime::UNIX_EPOCH) },
+        )
+        .await
+        .unwrap();
+        assert!(response.songs.is_empty());
+    }
+}
@@ -0,0 +1,6 @@
+mod get_play_history;
+mod get_top_plays;
+
+nghe_proc_macro::build_router! {
+    modules = [get_play_history(internal = true), get_top_plays(internal = true)],
+}
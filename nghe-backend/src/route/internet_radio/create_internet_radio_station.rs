@@ -0,0 +1,70 @@
+use diesel_async::RunQueryDsl;
+pub use nghe_api::internet_radio::create_internet_radio_station::{Request, Response};
+use nghe_proc_macro::handler;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::internet_radio_stations;
+
+#[handler(role = admin)]
+pub async fn handler(database: &Database, request: Request) -> Result<Response, Error> {
+    diesel::insert_into(internet_radio_stations::table)
+        .values(
+            internet_radio_stations::Data {
+                name: request.name.into(),
+                stream_url: request.stream_url.into(),
+                homepage_url: request.homepage_url.map(Into::into),
+            }
+            .validate()?,
+        )
+        .execute(&mut database.get().await?)
+        .await?;
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::internet_radio::get_internet_radio_stations;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        handler(
+            mock.database(),
+            Request {
+                stream_url: "https://radio.example/stream".to_owned(),
+                name: "radio".to_owned(),
+                homepage_url: Some("https://radio.example".to_owned()),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(
+            handler(
+                mock.database(),
+                Request {
+                    stream_url: "file:///etc/passwd".to_owned(),
+                    name: "invalid".to_owned(),
+                    homepage_url: None,
+                },
+            )
+            .await
+            .is_err()
+        );
+
+        let stations = get_internet_radio_stations::handler(mock.database())
+            .await
+            .unwrap()
+            .internet_radio_stations
+            .internet_radio_station;
+        assert_eq!(stations.len(), 1);
+        assert_eq!(stations[0].name, "radio");
+        assert_eq!(stations[0].stream_url, "https://radio.example/stream");
+        assert_eq!(stations[0].homepage_url.as_deref(), Some("https://radio.example"));
+    }
+}
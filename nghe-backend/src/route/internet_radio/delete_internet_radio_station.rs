@@ -0,0 +1,17 @@
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+pub use nghe_api::internet_radio::delete_internet_radio_station::{Request, Response};
+use nghe_proc_macro::handler;
+
+use crate::database::Database;
+use crate::orm::internet_radio_stations;
+use crate::{Error, error};
+
+#[handler(role = admin)]
+pub async fn handler(database: &Database, request: Request) -> Result<Response, Error> {
+    let deleted = diesel::delete(internet_radio_stations::table)
+        .filter(internet_radio_stations::id.eq(request.id))
+        .execute(&mut database.get().await?)
+        .await?;
+    if deleted > 0 { Ok(Response) } else { error::Kind::NotFound.into() }
+}
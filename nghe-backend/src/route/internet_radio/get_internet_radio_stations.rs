@@ -0,0 +1,26 @@
+use diesel::{QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::internet_radio::get_internet_radio_stations::{
+    InternetRadioStations, Request, Response,
+};
+use nghe_proc_macro::handler;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::internet_radio_stations;
+
+#[handler]
+pub async fn handler(database: &Database) -> Result<Response, Error> {
+    Ok(Response {
+        internet_radio_stations: InternetRadioStations {
+            internet_radio_station: internet_radio_stations::table
+                .select(internet_radio_stations::Station::as_select())
+                .order_by(internet_radio_stations::name)
+                .get_results(&mut database.get().await?)
+                .await?
+                .into_iter()
+                .map(internet_radio_stations::Station::into)
+                .collect(),
+        },
+    })
+}
@@ -0,0 +1,18 @@
+pub mod create_internet_radio_station;
+pub mod delete_internet_radio_station;
+pub mod get_internet_radio_stations;
+mod stream_internet_radio_station;
+pub mod update_internet_radio_station;
+
+use crate::config;
+
+nghe_proc_macro::build_router! {
+    modules = [
+        create_internet_radio_station,
+        delete_internet_radio_station,
+        get_internet_radio_stations,
+        stream_internet_radio_station,
+        update_internet_radio_station,
+    ],
+    extensions = [config::Transcode],
+}
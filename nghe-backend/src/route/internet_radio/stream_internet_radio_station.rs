@@ -0,0 +1,41 @@
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use nghe_api::common::format;
+pub use nghe_api::internet_radio::stream_internet_radio_station::Request;
+use nghe_proc_macro::handler;
+
+use crate::database::Database;
+use crate::file::audio::transcode;
+use crate::http::binary;
+use crate::orm::internet_radio_stations;
+use crate::{Error, config};
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    config: config::Transcode,
+    request: Request,
+) -> Result<binary::Response, Error> {
+    let stream_url = internet_radio_stations::table
+        .filter(internet_radio_stations::id.eq(request.id))
+        .select(internet_radio_stations::stream_url)
+        .get_result::<String>(&mut database.get().await?)
+        .await?;
+
+    // Radio streams never end so they are neither seekable nor cached.
+    let format = request.format.unwrap_or(format::Transcode::Opus);
+    let (rx, _) = transcode::Transcoder::spawn(
+        &config,
+        transcode::Path { input: stream_url, output: None },
+        format,
+        request.max_bit_rate.unwrap_or(32),
+        0,
+        None,
+    );
+    binary::Response::from_rx(
+        rx,
+        format,
+        #[cfg(test)]
+        None,
+    )
+}
@@ -0,0 +1,94 @@
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+pub use nghe_api::internet_radio::update_internet_radio_station::{Request, Response};
+use nghe_proc_macro::handler;
+
+use crate::database::Database;
+use crate::orm::internet_radio_stations;
+use crate::{Error, error};
+
+#[handler(role = admin)]
+pub async fn handler(database: &Database, request: Request) -> Result<Response, Error> {
+    let updated = diesel::update(internet_radio_stations::table)
+        .filter(internet_radio_stations::id.eq(request.id))
+        .set(
+            internet_radio_stations::Data {
+                name: request.name.into(),
+                stream_url: request.stream_url.into(),
+                homepage_url: request.homepage_url.map(Into::into),
+            }
+            .validate()?,
+        )
+        .execute(&mut database.get().await?)
+        .await?;
+    if updated > 0 { Ok(Response) } else { error::Kind::NotFound.into() }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::route::internet_radio::{
+        create_internet_radio_station, get_internet_radio_stations,
+    };
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        create_internet_radio_station::handler(
+            mock.database(),
+            create_internet_radio_station::Request {
+                stream_url: "https://radio.example/stream".to_owned(),
+                name: "radio".to_owned(),
+                homepage_url: Some("https://radio.example".to_owned()),
+            },
+        )
+        .await
+        .unwrap();
+        let id = get_internet_radio_stations::handler(mock.database())
+            .await
+            .unwrap()
+            .internet_radio_stations
+            .internet_radio_station[0]
+            .id;
+
+        handler(
+            mock.database(),
+            Request {
+                id,
+                stream_url: "http://radio.example/new".to_owned(),
+                name: "new".to_owned(),
+                homepage_url: None,
+            },
+        )
+        .await
+        .unwrap();
+        let station = get_internet_radio_stations::handler(mock.database())
+            .await
+            .unwrap()
+            .internet_radio_stations
+            .internet_radio_station
+            .remove(0);
+        assert_eq!(station.name, "new");
+        assert_eq!(station.stream_url, "http://radio.example/new");
+        assert_eq!(station.homepage_url, None);
+
+        assert!(
+            handler(
+                mock.database(),
+                Request {
+                    id: Uuid::new_v4(),
+                    stream_url: "http://radio.example/new".to_owned(),
+                    name: "new".to_owned(),
+                    homepage_url: None,
+                },
+            )
+            .await
+            .is_err()
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use diesel::dsl::{max, sum};
-use diesel::{ExpressionMethods, JoinOnDsl, PgSortExpressionMethods as _, QueryDsl};
+use diesel::{
+    ExpressionMethods, JoinOnDsl, PgArrayExpressionMethods as _, PgSortExpressionMethods as _,
+    QueryDsl,
+};
 use diesel_async::RunQueryDsl;
 use nghe_api::lists::get_album_list2::{AlbumList2, Type};
 pub use nghe_api::lists::get_album_list2::{Request, Response};
@@ -8,7 +11,7 @@ use uuid::Uuid;
 
 use crate::Error;
 use crate::database::Database;
-use crate::orm::{albums, function, genres, id3, playbacks, songs};
+use crate::orm::{albums, function, genres, id3, playbacks, rating_albums, songs};
 
 #[handler]
 pub async fn handler(
@@ -16,9 +19,15 @@ pub async fn handler(
     user_id: Uuid,
     request: Request,
 ) -> Result<Response, Error> {
+    // An empty array is contained by every array so no release type means no filtering.
+    // Release types are stored in lowercase.
+    let release_types: Vec<_> =
+        request.release_type.map(|release_type| release_type.to_lowercase()).into_iter().collect();
+
     #[check_music_folder]
     {
         let query = id3::album::short::query::with_user_id(user_id)
+            .filter(albums::release_types.contains(&release_types))
             .limit(request.size.unwrap_or(10).into())
             .offset(request.offset.unwrap_or(0).into());
 
@@ -50,6 +59,13 @@ pub async fn handler(
                     .get_results(&mut database.get().await?)
                     .await?
             }
+            Type::Highest => {
+                query
+                    .filter(rating_albums::rating.is_not_null())
+                    .order_by(max(rating_albums::rating).desc())
+                    .get_results(&mut database.get().await?)
+                    .await?
+            }
             Type::AlphabeticalByName => query.get_results(&mut database.get().await?).await?,
             Type::ByYear { from_year, to_year } => {
                 let from_year: i16 = from_year.try_into()?;
@@ -85,3 +101,107 @@ pub async fn handler(
         })
     }
 }
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use std::borrow::Cow;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_alphabetical_by_name(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        for (name, sort_name) in [("The Wall", Some("Wall, The")), ("Ummagumma", None)] {
+            music_folder
+                .add_audio()
+                .album(audio::Album {
+                    main: audio::NameDateMbz {
+                        sort_name: sort_name.map(Cow::Borrowed),
+                        ..name.into()
+                    },
+                    ..Default::default()
+                })
+                .call()
+                .await;
+        }
+
+        let albums: Vec<_> = Box::pin(handler(
+            mock.database(),
+            mock.user_id(0).await,
+            Request {
+                ty: Type::AlphabeticalByName,
+                size: None,
+                offset: None,
+                music_folder_ids: None,
+                release_type: None,
+            },
+        ))
+        .await
+        .unwrap()
+        .album_list2
+        .album
+        .into_iter()
+        .map(|album| (album.name, album.sort_name))
+        .collect();
+        assert_eq!(
+            albums,
+            [("Ummagumma".to_owned(), None), ("The Wall".to_owned(), Some("Wall, The".to_owned()))]
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_release_type(
+        #[future(awt)] mock: Mock,
+        #[values(None, Some("single"), Some("Single"))] release_type: Option<&str>,
+    ) {
+        let mut music_folder = mock.music_folder(0).await;
+        for (name, release_types) in [("Album", ["album"]), ("Single", ["single"])] {
+            music_folder
+                .add_audio()
+                .album(audio::Album {
+                    release: audio::Release {
+                        release_types: release_types.map(Cow::Borrowed).into(),
+                        barcode: Some(name.into()),
+                        ..Default::default()
+                    },
+                    ..name.into()
+                })
+                .call()
+                .await;
+        }
+
+        let albums: Vec<_> = Box::pin(handler(
+            mock.database(),
+            mock.user_id(0).await,
+            Request {
+                ty: Type::AlphabeticalByName,
+                size: None,
+                offset: None,
+                music_folder_ids: None,
+                release_type: release_type.map(str::to_owned),
+            },
+        ))
+        .await
+        .unwrap()
+        .album_list2
+        .album
+        .into_iter()
+        .map(|album| (album.name, album.release_types, album.barcode))
+        .collect();
+
+        let album = ("Album".to_owned(), vec!["album".to_owned()], Some("Album".to_owned()));
+        let single = ("Single".to_owned(), vec!["single".to_owned()], Some("Single".to_owned()));
+        if release_type.is_some() {
+            assert_eq!(albums, [single]);
+        } else {
+            assert_eq!(albums, [album, single]);
+        }
+    }
+}
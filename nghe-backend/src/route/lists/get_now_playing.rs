@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use itertools::Itertools;
+use nghe_api::id3::song;
+use nghe_api::lists::get_now_playing::{Entry, NowPlaying};
+pub use nghe_api::lists::get_now_playing::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{id3, now_playings, songs, users};
+
+#[handler]
+pub async fn handler(database: &Database, user_id: Uuid) -> Result<Response, Error> {
+    let now = crate::time::now().await;
+    let now_playings = now_playings::table
+        .inner_join(users::table)
+        .filter(now_playings::updated_at.gt(now - now_playings::EXPIRY))
+        .order_by(now_playings::updated_at.desc())
+        .select((users::username, now_playings::NowPlaying::as_select()))
+        .get_results::<(String, now_playings::NowPlaying)>(&mut database.get().await?)
+        .await?;
+
+    let song_ids: Vec<_> =
+        now_playings.iter().map(|(_, now_playing)| now_playing.song_id).unique().collect();
+    let songs: HashMap<_, song::Full> = id3::song::full::query::with_user_id(user_id)
+        .filter(songs::id.eq_any(song_ids))
+        .get_results::<id3::song::full::Full>(&mut database.get().await?)
+        .await?
+        .into_iter()
+        .map(|song| Ok::<_, Error>((song.short.song.id, song.try_into()?)))
+        .try_collect()?;
+
+    // Songs that the user does not have access to are skipped.
+    let entry = now_playings
+        .into_iter()
+        .filter_map(|(username, now_playing)| {
+            songs.get(&now_playing.song_id).map(|song| {
+                Ok::<_, Error>(Entry {
+                    song: song.clone(),
+                    username,
+                    minutes_ago: (now - now_playing.updated_at).whole_minutes().try_into()?,
+                    player_id: now_playing.player_id.try_into()?,
+                    player_name: Some(now_playing.client).filter(|client| !client.is_empty()),
+                })
+            })
+        })
+        .try_collect::<_, Vec<_>, Error>()?;
+
+    Ok(Response { now_playing: NowPlaying { entry } })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::media_annotation::scrobble;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(2, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().allow(allow).call().await;
+        mock.add_music_folder().call().await;
+
+        let mut music_folder_deny = mock.music_folder(0).await;
+        music_folder_deny.add_audio().call().await;
+        let mut music_folder_allow = mock.music_folder(1).await;
+        music_folder_allow.add_audio().call().await;
+        let song_id_deny = music_folder_deny.database.get_index(0).unwrap().0;
+        let song_id_allow = music_folder_allow.database.get_index(0).unwrap().0;
+
+        let user_id = mock.user_id(0).await;
+        let other_user_id = mock.user_id(1).await;
+        for (user_id, client, song_id) in [
+            (user_id, "client", *song_id_deny),
+            (user_id, "client", *song_id_allow),
+            (other_user_id, "client", *song_id_deny),
+            (other_user_id, "other", *song_id_allow),
+        ] {
+            scrobble::handler(
+                mock.database(),
+                &mock.scrobbler,
+                user_id,
+                Some(client.to_owned()),
+                scrobble::Request { ids: vec![song_id], times: None, submission: Some(false) },
+            )
+            .await
+            .unwrap();
+        }
+
+        let entry = handler(mock.database(), user_id).await.unwrap().now_playing.entry;
+        let mut entry: Vec<_> = entry
+            .into_iter()
+            .map(|entry| (entry.song.short.song.id, entry.player_name.unwrap(), entry.minutes_ago))
+            .collect();
+        entry.sort();
+
+        let mut expected =
+            vec![(*song_id_allow, "client".to_owned(), 0), (*song_id_allow, "other".to_owned(), 0)];
+        if allow {
+            expected.push((*song_id_deny, "client".to_owned(), 0));
+        }
+        expected.sort();
+        assert_eq!(entry, expected);
+    }
+}
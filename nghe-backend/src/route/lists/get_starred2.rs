@@ -164,15 +164,18 @@ mod tests {
                 vec![album_id]
             );
 
-            let albums: Vec<_> =
-                get_artist::handler(database, user_id_star, get_artist::Request { id: artist_id })
-                    .await
-                    .unwrap()
-                    .artist
-                    .album
-                    .into_iter()
-                    .map(|album| album.starred.is_some())
-                    .collect();
+            let albums: Vec<_> = get_artist::handler(
+                database,
+                user_id_star,
+                get_artist::Request { id: artist_id, release_type: None },
+            )
+            .await
+            .unwrap()
+            .artist
+            .album
+            .into_iter()
+            .map(|album| album.starred.is_some())
+            .collect();
             assert_eq!(albums, vec![true]);
         }
 
@@ -182,15 +185,18 @@ mod tests {
             assert!(starred.album.is_empty());
             assert!(starred.artist.is_empty());
 
-            let albums: Vec<_> =
-                get_artist::handler(database, user_id, get_artist::Request { id: artist_id })
-                    .await
-                    .unwrap()
-                    .artist
-                    .album
-                    .into_iter()
-                    .map(|album| album.starred.is_some())
-                    .collect();
+            let albums: Vec<_> = get_artist::handler(
+                database,
+                user_id,
+                get_artist::Request { id: artist_id, release_type: None },
+            )
+            .await
+            .unwrap()
+            .artist
+            .album
+            .into_iter()
+            .map(|album| album.starred.is_some())
+            .collect();
             assert_eq!(albums, vec![false]);
         }
     }
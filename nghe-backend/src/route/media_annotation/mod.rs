@@ -1,12 +1,13 @@
-mod scrobble;
+pub mod scrobble;
+pub mod set_rating;
 pub mod star;
 pub mod unstar;
 mod update_artist_information;
 
 use crate::config;
-use crate::integration::Informant;
+use crate::integration::{Informant, Scrobbler};
 
 nghe_proc_macro::build_router! {
-    modules = [scrobble, star, unstar, update_artist_information(internal = true)],
-    extensions = [config::CoverArt, Informant]
+    modules = [scrobble, set_rating, star, unstar, update_artist_information(internal = true)],
+    extensions = [config::CoverArt, Informant, Scrobbler]
 }
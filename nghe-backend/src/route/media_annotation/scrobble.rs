@@ -1,37 +1,82 @@
+use std::borrow::Cow;
+
+use diesel_async::AsyncConnection;
+use diesel_async::scoped_futures::ScopedFutureExt;
 use itertools::{EitherOrBoth, Itertools};
 pub use nghe_api::media_annotation::scrobble::{Request, Response};
 use nghe_proc_macro::handler;
 use uuid::Uuid;
 
 use crate::database::Database;
-use crate::orm::playbacks;
+use crate::integration::Scrobbler;
+use crate::orm::{now_playings, playbacks, plays};
 use crate::{Error, error};
 
 #[handler]
 pub async fn handler(
     database: &Database,
+    scrobbler: &Scrobbler,
     user_id: Uuid,
+    user_client: Option<String>,
     request: Request,
 ) -> Result<Response, Error> {
     let submission = request.submission.unwrap_or(true);
     if submission {
-        let values: Vec<_> = request
+        let now = crate::time::now().await;
+        let plays: Vec<_> = request
             .ids
             .into_iter()
             .zip_longest(request.times.unwrap_or_default())
             .map(|data| match data {
-                EitherOrBoth::Both(song_id, updated_at) => {
-                    Ok(playbacks::Scrobble { user_id, song_id, updated_at })
-                }
-                EitherOrBoth::Left(song_id) => Ok(playbacks::Scrobble {
+                EitherOrBoth::Both(song_id, played_at) => Ok(plays::Play {
                     user_id,
                     song_id,
-                    updated_at: time::OffsetDateTime::now_utc(),
+                    played_at,
+                    client: user_client.as_deref().map(Cow::Borrowed),
+                }),
+                EitherOrBoth::Left(song_id) => Ok(plays::Play {
+                    user_id,
+                    song_id,
+                    played_at: now,
+                    client: user_client.as_deref().map(Cow::Borrowed),
                 }),
                 EitherOrBoth::Right(_) => error::Kind::InvalidScrobbleTimeSize.into(),
             })
             .try_collect()?;
-        playbacks::Scrobble::upsert(database, &values).await?;
+        // Playbacks are the per song aggregate of the play history so both are written together.
+        let values: Vec<_> = plays
+            .iter()
+            .map(|play| playbacks::Scrobble {
+                user_id,
+                song_id: play.song_id,
+                updated_at: play.played_at,
+            })
+            .collect();
+        let play_ids = database
+            .get()
+            .await?
+            .transaction(|connection| {
+                async move {
+                    let play_ids = plays::Play::insert(connection, &plays).await?;
+                    playbacks::Scrobble::upsert(connection, &values).await?;
+                    Ok::<_, Error>(play_ids)
+                }
+                .scope_boxed()
+            })
+            .await?;
+
+        // Forwarding happens in the background so slow services do not delay the request.
+        scrobbler.spawn_submit(database.clone(), user_id, play_ids);
+    } else if let Some(song_id) = request.ids.last().copied() {
+        now_playings::Upsert {
+            user_id,
+            client: user_client.as_deref().unwrap_or_default().into(),
+            song_id,
+            updated_at: crate::time::now().await,
+        }
+        .upsert(database)
+        .await?;
+        scrobbler.spawn_now_playing(database.clone(), user_id, song_id, user_client);
     }
 
     Ok(Response)
@@ -88,7 +133,9 @@ mod tests {
 
             let result = handler(
                 mock.database(),
+                &mock.scrobbler,
                 user_id,
+                Some("client".to_owned()),
                 Request { ids: ids.clone(), times, submission: None },
             )
             .await;
@@ -96,6 +143,17 @@ mod tests {
         }
 
         for (i, id) in ids.into_iter().enumerate() {
+            let n_history: usize = plays::table
+                .filter(plays::user_id.eq(user_id))
+                .filter(plays::song_id.eq(id))
+                .filter(plays::client.eq("client"))
+                .count()
+                .get_result::<i64>(&mut mock.get().await)
+                .await
+                .unwrap()
+                .try_into()
+                .unwrap();
+
             let (count, time) = playbacks::table
                 .filter(playbacks::user_id.eq(user_id))
                 .filter(playbacks::song_id.eq(id))
@@ -106,6 +164,7 @@ mod tests {
 
             let count: usize = count.try_into().unwrap();
             assert_eq!(count, if n_song >= n_time { n_play } else { n_play - 1 });
+            assert_eq!(n_history, count);
 
             if n_song >= n_time {
                 if i >= n_time {
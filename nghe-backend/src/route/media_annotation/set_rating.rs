@@ -0,0 +1,217 @@
+use diesel::dsl::{exists, select};
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::media_annotation::set_rating::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::{albums, artists, rating_albums, rating_artists, rating_songs, songs};
+use crate::{Error, error};
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let Request { id, rating } = request;
+    if rating > 5 {
+        return Err(error::Kind::InvalidRating(rating).into());
+    }
+    let rating = if rating > 0 { Some(rating.into()) } else { None };
+
+    // The id could belong to a song, an album or an artist.
+    if select(exists(songs::table.filter(songs::id.eq(id))))
+        .get_result(&mut database.get().await?)
+        .await?
+    {
+        if let Some(rating) = rating {
+            rating_songs::Upsert { user_id, song_id: id, rating }.upsert(database).await?;
+        } else {
+            diesel::delete(rating_songs::table)
+                .filter(rating_songs::user_id.eq(user_id))
+                .filter(rating_songs::song_id.eq(id))
+                .execute(&mut database.get().await?)
+                .await?;
+        }
+    } else if select(exists(albums::table.filter(albums::id.eq(id))))
+        .get_result(&mut database.get().await?)
+        .await?
+    {
+        if let Some(rating) = rating {
+            rating_albums::Upsert { user_id, album_id: id, rating }.upsert(database).await?;
+        } else {
+            diesel::delete(rating_albums::table)
+                .filter(rating_albums::user_id.eq(user_id))
+                .filter(rating_albums::album_id.eq(id))
+                .execute(&mut database.get().await?)
+                .await?;
+        }
+    } else if select(exists(artists::table.filter(artists::id.eq(id))))
+        .get_result(&mut database.get().await?)
+        .await?
+    {
+        if let Some(rating) = rating {
+            rating_artists::Upsert { user_id, artist_id: id, rating }.upsert(database).await?;
+        } else {
+            diesel::delete(rating_artists::table)
+                .filter(rating_artists::user_id.eq(user_id))
+                .filter(rating_artists::artist_id.eq(id))
+                .execute(&mut database.get().await?)
+                .await?;
+        }
+    } else {
+        return Err(error::Kind::NotFound.into());
+    }
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::route::browsing::{get_album, get_artist, get_song};
+    use crate::route::lists::get_album_list2;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_rate_song(
+        #[future(awt)]
+        #[with(2, 1)]
+        mock: Mock,
+    ) {
+        let database = mock.database();
+        let mut music_folder = mock.music_folder(0).await;
+        let user_id = mock.user_id(0).await;
+        let user_id_other = mock.user_id(1).await;
+        music_folder.add_audio().call().await;
+        let song_id = music_folder.song_id(0);
+
+        handler(database, user_id, Request { id: song_id, rating: 4 }).await.unwrap();
+        handler(database, user_id_other, Request { id: song_id, rating: 1 }).await.unwrap();
+
+        let song = get_song::handler(database, user_id, get_song::Request { id: song_id })
+            .await
+            .unwrap()
+            .song
+            .short
+            .song;
+        assert_eq!(song.user_rating, Some(4));
+        assert_eq!(song.average_rating, Some(2.5));
+
+        handler(database, user_id, Request { id: song_id, rating: 0 }).await.unwrap();
+        let song = get_song::handler(database, user_id, get_song::Request { id: song_id })
+            .await
+            .unwrap()
+            .song
+            .short
+            .song;
+        assert_eq!(song.user_rating, None);
+        assert_eq!(song.average_rating, Some(1.0));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_rate_album(
+        #[future(awt)]
+        #[with(1, 1)]
+        mock: Mock,
+    ) {
+        let database = mock.database();
+        let mut music_folder = mock.music_folder(0).await;
+        let user_id = mock.user_id(0).await;
+
+        let mut album_ids = vec![];
+        for _ in 0..3 {
+            let album: audio::Album = Faker.fake();
+            album_ids.push(album.upsert_mock(&mock, 0).await);
+            music_folder.add_audio().album(album).n_song((1..3).fake()).call().await;
+        }
+
+        handler(database, user_id, Request { id: album_ids[0], rating: 2 }).await.unwrap();
+        handler(database, user_id, Request { id: album_ids[1], rating: 5 }).await.unwrap();
+
+        let album = get_album::handler(database, user_id, get_album::Request { id: album_ids[0] })
+            .await
+            .unwrap()
+            .album
+            .album;
+        assert_eq!(album.user_rating, Some(2));
+        assert_eq!(album.average_rating, Some(2.0));
+
+        let albums: Vec<_> = Box::pin(get_album_list2::handler(
+            database,
+            user_id,
+            get_album_list2::Request {
+                ty: nghe_api::lists::get_album_list2::Type::Highest,
+                size: None,
+                offset: None,
+                music_folder_ids: None,
+                release_type: None,
+            },
+        ))
+        .await
+        .unwrap()
+        .album_list2
+        .album
+        .into_iter()
+        .map(|album| album.id)
+        .collect();
+        assert_eq!(albums, vec![album_ids[1], album_ids[0]]);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_rate_artist(
+        #[future(awt)]
+        #[with(1, 1)]
+        mock: Mock,
+    ) {
+        let database = mock.database();
+        let mut music_folder = mock.music_folder(0).await;
+        let user_id = mock.user_id(0).await;
+
+        let artist: audio::Artist = Faker.fake();
+        let artist_id = artist.upsert_mock(&mock).await;
+        music_folder.add_audio_artist([artist.clone()], [artist], false, 1).await;
+
+        handler(database, user_id, Request { id: artist_id, rating: 3 }).await.unwrap();
+        let artist = get_artist::handler(
+            database,
+            user_id,
+            get_artist::Request { id: artist_id, release_type: None },
+        )
+        .await
+        .unwrap()
+        .artist
+        .artist;
+        assert_eq!(artist.user_rating, Some(3));
+        assert_eq!(artist.average_rating, Some(3.0));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_rate_invalid(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+    ) {
+        let user_id = mock.user_id(0).await;
+        assert!(
+            handler(mock.database(), user_id, Request { id: Faker.fake(), rating: 6 })
+                .await
+                .is_err()
+        );
+        assert!(
+            handler(mock.database(), user_id, Request { id: Faker.fake(), rating: 1 })
+                .await
+                .is_err()
+        );
+    }
+}
@@ -1,5 +1,5 @@
 pub mod download;
-mod get_cover_art;
+pub mod get_cover_art;
 mod get_lyrics_by_song_id;
 mod stream;
 
@@ -1,14 +1,17 @@
 use axum_extra::headers::Range;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
 pub use nghe_api::media_retrieval::stream::{Format, Request};
 use nghe_proc_macro::handler;
 use uuid::Uuid;
 
 use super::download;
 use crate::database::Database;
-use crate::file::audio::transcode;
+use crate::file::audio::{self, transcode};
 use crate::filesystem::{Filesystem, Trait};
 use crate::http::binary;
 use crate::http::header::ToOffset;
+use crate::orm::songs;
 #[cfg(test)]
 use crate::test::binary::Status as BinaryStatus;
 use crate::{Error, config};
@@ -37,7 +40,28 @@ pub async fn handler(
     let property = source.property.replace(format);
     let source_path = source.path.to_path();
 
-    let transcode_args = if let Some(ref cache_dir) = config.cache_dir {
+    // ReplayGain is only applied while transcoding. Podcast episodes do not have any gain.
+    let volume = if let Some(mode) = request.replay_gain {
+        songs::table
+            .filter(songs::id.eq(request.id))
+            .select((
+                songs::replay_gain::ReplayGain::as_select(),
+                songs::loudness::Loudness::as_select(),
+            ))
+            .get_result(&mut database.get().await?)
+            .await
+            .optional()?
+            .and_then(|(replay_gain, loudness)| {
+                audio::ReplayGain::from(replay_gain).or_loudness(loudness).volume(mode)
+            })
+    } else {
+        None
+    };
+
+    // The transcoding cache does not depend on the volume so it is bypassed if a gain is applied.
+    let transcode_args = if let Some(ref cache_dir) = config.cache_dir
+        && volume.is_none()
+    {
         let output = property.path_create_dir(cache_dir, bitrate.to_string()).await?;
         let cache_exists = tokio::fs::try_exists(&output).await?;
 
@@ -87,8 +111,14 @@ pub async fn handler(
         )
     };
 
-    let (rx, _) =
-        transcode::Transcoder::spawn(&config, transcode_args.0, format, bitrate, time_offset);
+    let (rx, _) = transcode::Transcoder::spawn(
+        &config,
+        transcode_args.0,
+        format,
+        bitrate,
+        time_offset,
+        volume,
+    );
 
     binary::Response::from_rx(
         rx,
@@ -103,8 +133,10 @@ pub async fn handler(
 mod tests {
     use axum::http::StatusCode;
     use axum_extra::headers::HeaderMapExt;
+    use fake::{Fake, Faker};
     use itertools::Itertools;
     use nghe_api::common::{filesystem, format};
+    use nghe_api::media_retrieval::stream;
     use rstest::rstest;
 
     use super::*;
@@ -163,7 +195,7 @@ mod tests {
         let transcoded = {
             let path = music_folder.absolute_path(0);
             let input = music_folder.to_impl().transcode_input(path.to_path()).await.unwrap();
-            transcode::Transcoder::spawn_collect(config, &input, format, bitrate, 0).await
+            transcode::Transcoder::spawn_collect(config, &input, format, bitrate, 0, None).await
         };
 
         let request = Request {
@@ -171,6 +203,7 @@ mod tests {
             max_bit_rate: Some(bitrate),
             format: Some(format.into()),
             time_offset: None,
+            replay_gain: None,
         };
 
         let (responses, binary_status) = spawn_stream(&mock, 2, user_id, request).await;
@@ -213,7 +246,8 @@ mod tests {
         let transcoded = {
             let path = music_folder.absolute_path(0);
             let input = music_folder.to_impl().transcode_input(path.to_path()).await.unwrap();
-            transcode::Transcoder::spawn_collect(config, &input, format, bitrate, time_offset).await
+            transcode::Transcoder::spawn_collect(config, &input, format, bitrate, time_offset, None)
+                .await
         };
 
         let request = Request {
@@ -221,6 +255,7 @@ mod tests {
             max_bit_rate: Some(bitrate),
             format: Some(format.into()),
             time_offset: Some(time_offset),
+            replay_gain: None,
         };
 
         let (responses, binary_status) = spawn_stream(&mock, 2, user_id, request).await;
@@ -241,4 +276,62 @@ mod tests {
         }
         assert_eq!(binary_status, &[BinaryStatus::UseCachedOutput, BinaryStatus::UseCachedOutput]);
     }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_stream_replay_gain(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(stream::ReplayGain::Track, stream::ReplayGain::Album)] mode: stream::ReplayGain,
+    ) {
+        mock.add_music_folder().call().await;
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder
+            .add_audio_filesystem::<&str>()
+            .format(audio::Format::Flac)
+            .song(audio::Song {
+                replay_gain: audio::ReplayGain {
+                    track_gain: Some(-6.5),
+                    track_peak: Some(0.9),
+                    album_gain: Some(-7.25),
+                    album_peak: None,
+                },
+                ..Faker.fake()
+            })
+            .call()
+            .await;
+
+        let user_id = mock.user_id(0).await;
+        let song_id = music_folder.song_id_filesystem(0).await;
+        let config = &mock.config.transcode;
+        let format = format::Transcode::Opus;
+        let bitrate = 32;
+        let volume = match mode {
+            stream::ReplayGain::Track => -6.5,
+            stream::ReplayGain::Album => -7.25,
+        };
+
+        let transcoded = {
+            let path = music_folder.absolute_path(0);
+            let input = music_folder.to_impl().transcode_input(path.to_path()).await.unwrap();
+            transcode::Transcoder::spawn_collect(config, &input, format, bitrate, 0, Some(volume))
+                .await
+        };
+
+        let request = Request {
+            id: song_id,
+            max_bit_rate: Some(bitrate),
+            format: Some(format.into()),
+            time_offset: None,
+            replay_gain: Some(mode),
+        };
+
+        let (responses, binary_status) = spawn_stream(&mock, 2, user_id, request).await;
+        for (status, body) in responses {
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body, transcoded);
+        }
+        assert_eq!(binary_status, &[BinaryStatus::NoCache, BinaryStatus::NoCache]);
+    }
 }
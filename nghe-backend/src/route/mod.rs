@@ -1,5 +1,7 @@
 pub mod bookmarks;
 pub mod browsing;
+pub mod history;
+pub mod internet_radio;
 pub mod key;
 pub mod lists;
 pub mod media_annotation;
@@ -7,7 +9,10 @@ pub mod media_retrieval;
 pub mod music_folder;
 pub mod permission;
 pub mod playlists;
+pub mod podcasts;
 pub mod scan;
+pub mod scrobbler;
 pub mod search;
+pub mod sharing;
 pub mod system;
 pub mod user;
@@ -8,10 +8,12 @@ use crate::error::Error;
 use crate::filesystem::{self, Filesystem, Trait as _};
 use crate::orm::music_folders;
 use crate::route::permission;
+use crate::scan::watcher;
 
 async fn handler_impl(
     database: &Database,
     filesystem: filesystem::Impl<'_>,
+    subscriber: &watcher::Subscriber,
     request: Request,
 ) -> Result<Response, Error> {
     filesystem.check_folder(request.path.as_str().into()).await?;
@@ -34,6 +36,10 @@ async fn handler_impl(
         .await?;
     }
 
+    if request.ty == nghe_api::common::filesystem::Type::Local {
+        subscriber.subscribe(request.path);
+    }
+
     Ok(Response { music_folder_id })
 }
 
@@ -41,9 +47,10 @@ async fn handler_impl(
 pub async fn handler(
     database: &Database,
     filesystem: &Filesystem,
+    subscriber: &watcher::Subscriber,
     request: Request,
 ) -> Result<Response, Error> {
-    handler_impl(database, filesystem.to_impl(request.ty)?, request).await
+    handler_impl(database, filesystem.to_impl(request.ty)?, subscriber, request).await
 }
 
 #[cfg(test)]
@@ -1,7 +1,10 @@
 pub mod add;
 pub mod get;
 
+use crate::scan::watcher;
+
 nghe_proc_macro::build_router! {
     modules = [add(internal = true), get(internal = true)],
     filesystem = true,
+    extensions = [watcher::Subscriber],
 }
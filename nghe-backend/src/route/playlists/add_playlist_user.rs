@@ -0,0 +1,124 @@
+pub use nghe_api::playlists::add_playlist_user::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::{playlist, playlists_users};
+use crate::{Error, error};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let Request { playlist_id, user_id: target_user_id, write } = request;
+    playlist::permission::check_write(database, playlist_id, user_id, true).await?;
+    if target_user_id == user_id {
+        // The owner always has write access.
+        return error::Kind::Forbidden.into();
+    }
+    playlists_users::Upsert { playlist_id, user_id: target_user_id, write }
+        .upsert(database)
+        .await?;
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::playlists::{create_playlist, get_playlists, update_playlist};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_add_user(
+        #[future(awt)]
+        #[with(2, 1)]
+        mock: Mock,
+        #[values(true, false)] write: bool,
+    ) {
+        let database = mock.database();
+        let owner = mock.user(0).await;
+        let user_id = mock.user_id(1).await;
+
+        let playlist_id = create_playlist::handler(
+            database,
+            owner.id(),
+            create_playlist::Request {
+                create_or_update: Faker.fake::<String>().into(),
+                song_ids: None,
+            },
+        )
+        .await
+        .unwrap()
+        .playlist
+        .playlist
+        .id;
+
+        assert!(
+            get_playlists::handler(database, user_id).await.unwrap().playlists.playlist.is_empty()
+        );
+        // Only the owner can share the playlist.
+        assert!(
+            handler(database, user_id, Request { playlist_id, user_id, write: true })
+                .await
+                .is_err()
+        );
+
+        handler(database, owner.id(), Request { playlist_id, user_id, write }).await.unwrap();
+        let playlists = get_playlists::handler(database, user_id).await.unwrap().playlists.playlist;
+        assert_eq!(playlists.len(), 1);
+        assert_eq!(playlists[0].id, playlist_id);
+        assert_eq!(playlists[0].owner, owner.username());
+
+        let update = update_playlist::handler(
+            database,
+            user_id,
+            update_playlist::Request {
+                playlist_id,
+                name: Some(Faker.fake()),
+                ..Default::default()
+            },
+        )
+        .await;
+        assert_eq!(update.is_ok(), write);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_add_unknown_user(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+    ) {
+        let database = mock.database();
+        let owner_id = mock.user_id(0).await;
+        let playlist_id = create_playlist::handler(
+            database,
+            owner_id,
+            create_playlist::Request {
+                create_or_update: Faker.fake::<String>().into(),
+                song_ids: None,
+            },
+        )
+        .await
+        .unwrap()
+        .playlist
+        .playlist
+        .id;
+
+        let error = handler(
+            database,
+            owner_id,
+            Request { playlist_id, user_id: Faker.fake(), write: true },
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(error.status_code, axum::http::StatusCode::NOT_FOUND);
+    }
+}
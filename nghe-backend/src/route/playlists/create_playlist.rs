@@ -6,10 +6,10 @@ use nghe_proc_macro::handler;
 use uuid::Uuid;
 
 use super::get_playlist;
-use crate::Error;
 use crate::database::Database;
 use crate::orm::upsert::Insert;
 use crate::orm::{playlist, playlists, playlists_songs, playlists_users};
+use crate::{Error, error};
 
 #[handler]
 pub async fn handler(
@@ -27,6 +27,9 @@ pub async fn handler(
         }
         CreateOrUpdate::Update { playlist_id } => {
             playlist::permission::check_write(database, playlist_id, user_id, false).await?;
+            if playlist::smart::is_smart(database, playlist_id).await? {
+                return error::Kind::SmartPlaylistNotEditable.into();
+            }
             diesel::delete(playlists_songs::table)
                 .filter(playlists_songs::playlist_id.eq(playlist_id))
                 .execute(&mut database.get().await?)
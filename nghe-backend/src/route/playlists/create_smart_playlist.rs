@@ -0,0 +1,89 @@
+pub use nghe_api::playlists::create_smart_playlist::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use super::get_playlist;
+use crate::Error;
+use crate::database::Database;
+use crate::orm::upsert::{Insert as _, Update as _};
+use crate::orm::{playlist, playlists, playlists_rules, playlists_users};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let rules = playlists_rules::Data::try_from(request.rules)?;
+    playlist::smart::validate(&rules)?;
+
+    let playlist_id = playlists::Upsert { name: Some(request.name.into()), ..Default::default() }
+        .insert(database)
+        .await?;
+    playlists_users::Upsert::insert_owner(database, playlist_id, user_id).await?;
+    rules.update(database, playlist_id).await?;
+
+    Ok(Response {
+        playlist: get_playlist::handler(
+            database,
+            user_id,
+            get_playlist::Request { id: playlist_id },
+        )
+        .await?
+        .playlist,
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use nghe_api::playlists::rules::Rules;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::playlists::get_playlists;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song((2..4).fake()).call().await;
+
+        let user_id = mock.user_id(0).await;
+        let playlist = handler(
+            mock.database(),
+            user_id,
+            Request { name: Faker.fake(), rules: Rules::default() },
+        )
+        .await
+        .unwrap()
+        .playlist;
+        assert!(playlist.playlist.readonly);
+        assert_eq!(playlist.entry.len(), music_folder.database.len());
+
+        // New songs should be picked up without touching the playlist.
+        music_folder.add_audio().call().await;
+        let playlists =
+            get_playlists::handler(mock.database(), user_id).await.unwrap().playlists.playlist;
+        assert_eq!(playlists.len(), 1);
+        assert!(playlists[0].readonly);
+        assert_eq!(usize::from(playlists[0].song_count), music_folder.database.len());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_invalid_rating(#[future(awt)] mock: Mock) {
+        let result = handler(
+            mock.database(),
+            mock.user_id(0).await,
+            Request {
+                name: Faker.fake(),
+                rules: Rules { min_rating: Some(6), ..Default::default() },
+            },
+        )
+        .await;
+        assert!(result.is_err());
+    }
+}
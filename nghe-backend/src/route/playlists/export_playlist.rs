@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::playlists::export_playlist::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use super::get_playlist;
+use crate::database::Database;
+use crate::orm::songs;
+use crate::{Error, file};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let playlist =
+        get_playlist::handler(database, user_id, get_playlist::Request { id: request.id })
+            .await?
+            .playlist;
+
+    let song_ids: Vec<_> = playlist.entry.iter().map(|entry| entry.song.id).collect();
+    let paths: HashMap<Uuid, String> = songs::table
+        .filter(songs::id.eq_any(song_ids))
+        .select((songs::id, songs::relative_path))
+        .get_results(&mut database.get().await?)
+        .await?
+        .into_iter()
+        .collect();
+
+    let tracks: Vec<_> = playlist
+        .entry
+        .iter()
+        .filter_map(|entry| {
+            Some(file::playlist::Track {
+                path: paths.get(&entry.song.id)?,
+                title: &entry.song.title,
+                artist: &entry.song.artist,
+                album: &entry.album,
+                duration: entry.song.duration,
+            })
+        })
+        .collect();
+
+    Ok(Response {
+        content: file::playlist::render(request.format, &playlist.playlist.name, &tracks)?,
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use nghe_api::playlists::export_playlist::Format;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::playlists::{create_playlist, import_playlist};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_roundtrip(
+        #[future(awt)] mock: Mock,
+        #[values(Format::M3u8, Format::Xspf)] format: Format,
+    ) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().relative_path("a/b #1.mp3".into()).call().await;
+        music_folder.add_audio().relative_path("c/d.flac".into()).call().await;
+        music_folder.add_audio().relative_path("e.ogg".into()).call().await;
+
+        let user_id = mock.user_id(0).await;
+        let song_ids = vec![music_folder.song_id(2), music_folder.song_id(0)];
+        let id = create_playlist::handler(
+            mock.database(),
+            user_id,
+            create_playlist::Request {
+                create_or_update: Faker.fake::<String>().into(),
+                song_ids: Some(song_ids.clone()),
+            },
+        )
+        .await
+        .unwrap()
+        .playlist
+        .playlist
+        .id;
+
+        let content =
+            handler(mock.database(), user_id, Request { id, format }).await.unwrap().content;
+        let response = import_playlist::handler(
+            mock.database(),
+            user_id,
+            import_playlist::Request {
+                name: Faker.fake(),
+                music_folder_id: music_folder.id(),
+                content,
+            },
+        )
+        .await
+        .unwrap();
+        let database_song_ids: Vec<_> =
+            response.playlist.entry.iter().map(|entry| entry.song.id).collect();
+        assert_eq!(database_song_ids, song_ids);
+        assert!(response.unresolved.is_empty());
+    }
+}
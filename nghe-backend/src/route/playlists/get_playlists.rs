@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
 use diesel_async::RunQueryDsl;
 use nghe_api::playlists::get_playlists::Playlists;
 pub use nghe_api::playlists::get_playlists::{Request, Response};
@@ -6,20 +9,34 @@ use uuid::Uuid;
 
 use crate::Error;
 use crate::database::Database;
-use crate::orm::playlist;
+use crate::orm::{playlist, playlists_rules};
 
 #[handler]
 pub async fn handler(database: &Database, user_id: Uuid) -> Result<Response, Error> {
-    Ok(Response {
-        playlists: Playlists {
-            playlist: playlist::short::query::with_user_id(user_id)
-                .get_results(&mut database.get().await?)
-                .await?
-                .into_iter()
-                .map(playlist::short::Short::try_into)
-                .try_collect()?,
-        },
-    })
+    let shorts = playlist::short::query::with_user_id(user_id)
+        .get_results(&mut database.get().await?)
+        .await?;
+
+    let smart_ids: Vec<_> =
+        shorts.iter().filter(|short| short.playlist.smart).map(|short| short.playlist.id).collect();
+    let mut rules: HashMap<_, _> = playlists_rules::table
+        .filter(playlists_rules::playlist_id.eq_any(smart_ids))
+        .select((playlists_rules::playlist_id, playlists_rules::Data::as_select()))
+        .get_results::<(Uuid, playlists_rules::Data)>(&mut database.get().await?)
+        .await?
+        .into_iter()
+        .collect();
+
+    let mut playlists = Vec::with_capacity(shorts.len());
+    for mut short in shorts {
+        // Smart playlists do not have any stored song so their durations need to be evaluated.
+        if let Some(rules) = rules.remove(&short.playlist.id) {
+            short.durations = playlist::smart::durations(database, user_id, &rules).await?;
+        }
+        playlists.push(short.try_into()?);
+    }
+
+    Ok(Response { playlists: Playlists { playlist: playlists } })
 }
 
 #[cfg(test)]
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use diesel::{ExpressionMethods, JoinOnDsl, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use itertools::Itertools;
+use nghe_api::playlists::create_playlist::CreateOrUpdate;
+pub use nghe_api::playlists::import_playlist::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use super::create_playlist;
+use crate::database::Database;
+use crate::orm::function::{lower, regexp_replace};
+use crate::orm::{albums, artists, music_folders, permission, songs, songs_artists};
+use crate::{Error, error, file};
+
+// Normalized title to the normalized artists and id of every song with that title.
+type Candidates = HashMap<String, Vec<(String, Uuid)>>;
+
+// Punctuation and whitespace are ignored, so `Don't  Stop` matches `dont stop`.
+const NORMALIZE_PATTERN: &str = "[^[:alnum:]]+";
+
+// Same as `NORMALIZE_PATTERN` but computed in Rust.
+fn normalize(value: &str) -> String {
+    value.chars().filter(|char| char.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+async fn candidates<'a>(
+    database: &Database,
+    music_folder_id: Uuid,
+    titles: impl Iterator<Item = &'a str>,
+) -> Result<Candidates, Error> {
+    let titles: Vec<_> = titles.map(normalize).filter(|title| !title.is_empty()).collect();
+    if titles.is_empty() {
+        return Ok(Candidates::new());
+    }
+
+    let candidates = songs::table
+        .inner_join(albums::table)
+        .inner_join(songs_artists::table)
+        .inner_join(artists::table.on(artists::id.eq(songs_artists::artist_id)))
+        .filter(albums::music_folder_id.eq(music_folder_id))
+        .filter(regexp_replace(lower(songs::title), NORMALIZE_PATTERN, "", "g").eq_any(titles))
+        .select((songs::title, artists::name, songs::id))
+        .get_results::<(String, String, Uuid)>(&mut database.get().await?)
+        .await?;
+    Ok(candidates
+        .into_iter()
+        .map(|(title, artist, song_id)| (normalize(&title), (normalize(&artist), song_id)))
+        .into_group_map())
+}
+
+fn find(candidates: &Candidates, artist: Option<&str>, title: &str) -> Option<Uuid> {
+    let candidates = candidates.get(&normalize(title))?;
+    if let Some(artist) = artist {
+        let artist = normalize(artist);
+        candidates
+            .iter()
+            .find(|candidate| candidate.0 == artist)
+            .map(|candidate| candidate.1)
+            .or_else(
+                // A mismatched artist is only ignored when the title alone is not ambiguous.
+                || candidates.iter().map(|candidate| candidate.1).unique().exactly_one().ok(),
+            )
+    } else {
+        candidates.iter().next().map(|candidate| candidate.1)
+    }
+}
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let Request { name, music_folder_id, content } = request;
+    let music_folder_path: String = music_folders::table
+        .filter(music_folders::id.eq(music_folder_id))
+        .filter(permission::with_music_folder(user_id))
+        .select(music_folders::path)
+        .get_result(&mut database.get().await?)
+        .await
+        .optional()?
+        .ok_or_else(|| error::Kind::NotFound)?;
+
+    let entries = file::playlist::parse(&content)?;
+    let relative_paths: Vec<_> =
+        entries.iter().map(|entry| entry.relative_path(&music_folder_path)).collect();
+    let song_ids: HashMap<String, Uuid> = songs::table
+        .inner_join(albums::table)
+        .filter(albums::music_folder_id.eq(music_folder_id))
+        .filter(songs::relative_path.eq_any(relative_paths.iter().flatten()))
+        .select((songs::relative_path, songs::id))
+        .get_results(&mut database.get().await?)
+        .await?
+        .into_iter()
+        .collect();
+
+    let song_ids: Vec<_> = relative_paths
+        .into_iter()
+        .map(|relative_path| relative_path.and_then(|relative_path| song_ids.get(&relative_path)))
+        .collect();
+    // Fallback to the artist and title of the entries whose path does not match any song.
+    let candidates = candidates(
+        database,
+        music_folder_id,
+        entries
+            .iter()
+            .zip(&song_ids)
+            .filter(|(_, song_id)| song_id.is_none())
+            .filter_map(|(entry, _)| entry.title.as_deref()),
+    )
+    .await?;
+
+    let mut ids = vec![];
+    let mut unresolved = vec![];
+    for (entry, song_id) in entries.iter().zip(song_ids) {
+        let song_id = song_id.copied().or_else(|| {
+            entry
+                .title
+                .as_deref()
+                .and_then(|title| find(&candidates, entry.artist.as_deref(), title))
+        });
+
+        if let Some(song_id) = song_id {
+            ids.push(song_id);
+        } else {
+            unresolved.push(entry.location.clone().into_owned());
+        }
+    }
+
+    let playlist = create_playlist::handler(
+        database,
+        user_id,
+        create_playlist::Request {
+            create_or_update: CreateOrUpdate::Create { name },
+            song_ids: Some(ids),
+        },
+    )
+    .await?
+    .playlist;
+    Ok(Response { playlist, unresolved })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use concat_string::concat_string;
+    use fake::{Fake, Faker};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test::{Mock, mock};
+
+    #[test]
+    fn test_find() {
+        let ids: Vec<_> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let candidates: Candidates = [
+            (normalize("Don't Stop"), vec![(normalize("Artist"), ids[0])]),
+            (normalize("Song"), vec![(normalize("A"), ids[1]), (normalize("B"), ids[2])]),
+        ]
+        .into();
+
+        assert_eq!(find(&candidates, Some("ARTIST"), " dont   stop "), Some(ids[0]));
+        assert_eq!(find(&candidates, Some("Other"), "Don't Stop!"), Some(ids[0]));
+        assert_eq!(find(&candidates, Some("b"), "song"), Some(ids[2]));
+        assert_eq!(find(&candidates, Some("c"), "song"), None);
+        assert_eq!(find(&candidates, None, "song"), Some(ids[1]));
+        assert_eq!(find(&candidates, None, "?"), None);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().allow(allow).call().await;
+        mock.add_music_folder().call().await;
+        let mut other_music_folder = mock.music_folder(1).await;
+        other_music_folder.add_audio().call().await;
+        let other_information = &other_music_folder.database[0].information;
+        let other_title = other_information.metadata.song.main.name.clone();
+
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().relative_path("a/b/1.mp3".into()).call().await;
+        music_folder.add_audio().relative_path("a/2.flac".into()).call().await;
+        music_folder.add_audio().relative_path("3.mp3".into()).call().await;
+
+        let information = &music_folder.database[2].information;
+        let artist = information.metadata.artists.song.get_index(0).unwrap().name.to_uppercase();
+        let title = information.metadata.song.main.name.to_lowercase();
+        let content = concat_string!(
+            "#EXTM3U\n",
+            music_folder.path().join("a/2.flac").as_str(),
+            "\n#EXTINF:100,",
+            artist,
+            " - ",
+            title,
+            "\n../elsewhere/3.mp3\n",
+            "a/b/1.mp3\n",
+            "a/b/4.mp3\n",
+            "#EXTINF:100,",
+            other_title,
+            "\n../elsewhere/5.mp3\n"
+        );
+
+        let result = handler(
+            mock.database(),
+            mock.user_id(0).await,
+            Request { name: Faker.fake(), music_folder_id: music_folder.id(), content },
+        )
+        .await;
+
+        if allow {
+            let response = result.unwrap();
+            let song_ids: Vec<_> =
+                response.playlist.entry.iter().map(|entry| entry.song.id).collect();
+            assert_eq!(
+                song_ids,
+                [music_folder.song_id(1), music_folder.song_id(2), music_folder.song_id(0)]
+            );
+            // Songs of other music folders are never matched.
+            assert_eq!(response.unresolved, ["a/b/4.mp3", "../elsewhere/5.mp3"]);
+        } else {
+            assert!(result.is_err());
+        }
+    }
+}
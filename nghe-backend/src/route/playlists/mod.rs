@@ -1,9 +1,29 @@
+pub mod add_playlist_user;
 pub mod create_playlist;
-mod delete_playlist;
+pub mod create_smart_playlist;
+pub mod delete_playlist;
+mod export_playlist;
 pub mod get_playlist;
-mod get_playlists;
-mod update_playlist;
+pub mod get_playlists;
+mod import_playlist;
+mod remove_playlist_user;
+mod transfer_playlist_owner;
+pub mod update_playlist;
+mod update_smart_playlist;
 
 nghe_proc_macro::build_router! {
-    modules = [create_playlist, delete_playlist, get_playlist, get_playlists, update_playlist]
+    modules = [
+        add_playlist_user(internal = true),
+        create_playlist,
+        create_smart_playlist(internal = true),
+        delete_playlist,
+        export_playlist(internal = true),
+        get_playlist,
+        get_playlists,
+        import_playlist(internal = true),
+        remove_playlist_user(internal = true),
+        transfer_playlist_owner(internal = true),
+        update_playlist,
+        update_smart_playlist(internal = true)
+    ]
 }
@@ -0,0 +1,92 @@
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+pub use nghe_api::playlists::remove_playlist_user::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{playlist, playlists_users};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let Request { playlist_id, user_id: target_user_id } = request;
+    // Anyone can leave a playlist shared with them while only the owner can remove others.
+    if target_user_id != user_id {
+        playlist::permission::check_write(database, playlist_id, user_id, true).await?;
+    }
+    diesel::delete(playlists_users::table)
+        .filter(playlists_users::playlist_id.eq(playlist_id))
+        .filter(playlists_users::user_id.eq(target_user_id))
+        .filter(playlists_users::owner.eq(false))
+        .execute(&mut database.get().await?)
+        .await?;
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::playlists::{add_playlist_user, create_playlist, get_playlists};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_remove_user(
+        #[future(awt)]
+        #[with(2, 1)]
+        mock: Mock,
+        #[values(true, false)] by_owner: bool,
+    ) {
+        let database = mock.database();
+        let owner_id = mock.user_id(0).await;
+        let user_id = mock.user_id(1).await;
+
+        let playlist_id = create_playlist::handler(
+            database,
+            owner_id,
+            create_playlist::Request {
+                create_or_update: Faker.fake::<String>().into(),
+                song_ids: None,
+            },
+        )
+        .await
+        .unwrap()
+        .playlist
+        .playlist
+        .id;
+        add_playlist_user::handler(
+            database,
+            owner_id,
+            add_playlist_user::Request { playlist_id, user_id, write: true },
+        )
+        .await
+        .unwrap();
+
+        // Users with write access still can not remove the owner.
+        handler(database, user_id, Request { playlist_id, user_id: owner_id }).await.unwrap_err();
+
+        handler(
+            database,
+            if by_owner { owner_id } else { user_id },
+            Request { playlist_id, user_id },
+        )
+        .await
+        .unwrap();
+        assert!(
+            get_playlists::handler(database, user_id).await.unwrap().playlists.playlist.is_empty()
+        );
+        assert_eq!(
+            get_playlists::handler(database, owner_id).await.unwrap().playlists.playlist.len(),
+            1
+        );
+    }
+}
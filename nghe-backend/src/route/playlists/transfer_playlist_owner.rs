@@ -0,0 +1,109 @@
+pub use nghe_api::playlists::transfer_playlist_owner::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{playlist, playlists_users};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let Request { playlist_id, user_id: target_user_id } = request;
+    playlist::permission::check_write(database, playlist_id, user_id, true).await?;
+    if target_user_id != user_id {
+        playlists_users::Upsert::transfer_owner(database, playlist_id, target_user_id).await?;
+    }
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::playlists::{create_playlist, delete_playlist, get_playlists};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_transfer_owner(
+        #[future(awt)]
+        #[with(2, 1)]
+        mock: Mock,
+    ) {
+        let database = mock.database();
+        let owner_id = mock.user_id(0).await;
+        let user = mock.user(1).await;
+
+        let playlist_id = create_playlist::handler(
+            database,
+            owner_id,
+            create_playlist::Request {
+                create_or_update: Faker.fake::<String>().into(),
+                song_ids: None,
+            },
+        )
+        .await
+        .unwrap()
+        .playlist
+        .playlist
+        .id;
+
+        handler(database, user.id(), Request { playlist_id, user_id: user.id() })
+            .await
+            .unwrap_err();
+        handler(database, owner_id, Request { playlist_id, user_id: user.id() }).await.unwrap();
+
+        for user_id in [owner_id, user.id()] {
+            let playlists =
+                get_playlists::handler(database, user_id).await.unwrap().playlists.playlist;
+            assert_eq!(playlists.len(), 1);
+            assert_eq!(playlists[0].owner, user.username());
+        }
+
+        delete_playlist::handler(database, owner_id, delete_playlist::Request { id: playlist_id })
+            .await
+            .unwrap_err();
+        delete_playlist::handler(database, user.id(), delete_playlist::Request { id: playlist_id })
+            .await
+            .unwrap();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_transfer_unknown_user(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+    ) {
+        let database = mock.database();
+        let owner_id = mock.user_id(0).await;
+        let playlist_id = create_playlist::handler(
+            database,
+            owner_id,
+            create_playlist::Request {
+                create_or_update: Faker.fake::<String>().into(),
+                song_ids: None,
+            },
+        )
+        .await
+        .unwrap()
+        .playlist
+        .playlist
+        .id;
+
+        let error = handler(database, owner_id, Request { playlist_id, user_id: Faker.fake() })
+            .await
+            .unwrap_err();
+        assert_eq!(error.status_code, axum::http::StatusCode::NOT_FOUND);
+        let playlists =
+            get_playlists::handler(database, owner_id).await.unwrap().playlists.playlist;
+        assert_eq!(playlists.len(), 1);
+    }
+}
@@ -4,10 +4,10 @@ pub use nghe_api::playlists::update_playlist::{Request, Response};
 use nghe_proc_macro::handler;
 use uuid::Uuid;
 
-use crate::Error;
 use crate::database::Database;
 use crate::orm::upsert::Update;
 use crate::orm::{albums, playlist, playlists, playlists_songs, songs};
+use crate::{Error, error};
 
 #[handler]
 pub async fn handler(
@@ -18,6 +18,13 @@ pub async fn handler(
     let playlist_id = request.playlist_id;
     playlist::permission::check_write(database, playlist_id, user_id, false).await?;
 
+    // Rejected before any write so a failed request does not leave a partial update behind.
+    if (request.remove_indexes.is_some() || request.add_ids.is_some())
+        && playlist::smart::is_smart(database, playlist_id).await?
+    {
+        return error::Kind::SmartPlaylistNotEditable.into();
+    }
+
     if request.name.is_some() || request.comment.is_some() || request.public.is_some() {
         playlists::Upsert::from(&request).update(database, playlist_id).await?;
     }
@@ -0,0 +1,108 @@
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+pub use nghe_api::playlists::update_smart_playlist::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::upsert::Update as _;
+use crate::orm::{playlist, playlists, playlists_rules};
+use crate::{Error, error};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let playlist_id = request.playlist_id;
+    playlist::permission::check_write(database, playlist_id, user_id, false).await?;
+    if !playlist::smart::is_smart(database, playlist_id).await? {
+        return error::Kind::NotFound.into();
+    }
+
+    let rules = playlists_rules::Data::try_from(request.rules)?;
+    playlist::smart::validate(&rules)?;
+    rules.update(database, playlist_id).await?;
+    diesel::update(playlists::table)
+        .filter(playlists::id.eq(playlist_id))
+        .set(playlists::updated_at.eq(crate::time::now().await))
+        .execute(&mut database.get().await?)
+        .await?;
+
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use nghe_api::playlists::rules::Rules;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::playlists::{create_playlist, create_smart_playlist, get_playlist};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song((2..4).fake()).call().await;
+
+        let user_id = mock.user_id(0).await;
+        let playlist_id = create_smart_playlist::handler(
+            mock.database(),
+            user_id,
+            create_smart_playlist::Request { name: Faker.fake(), rules: Rules::default() },
+        )
+        .await
+        .unwrap()
+        .playlist
+        .playlist
+        .id;
+
+        handler(
+            mock.database(),
+            user_id,
+            Request { playlist_id, rules: Rules { limit: Some(1), ..Default::default() } },
+        )
+        .await
+        .unwrap();
+
+        let playlist = get_playlist::handler(
+            mock.database(),
+            user_id,
+            get_playlist::Request { id: playlist_id },
+        )
+        .await
+        .unwrap()
+        .playlist;
+        assert_eq!(playlist.entry.len(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_regular_playlist(#[future(awt)] mock: Mock) {
+        let user_id = mock.user_id(0).await;
+        let playlist_id = create_playlist::handler(
+            mock.database(),
+            user_id,
+            create_playlist::Request {
+                create_or_update: Faker.fake::<String>().into(),
+                song_ids: None,
+            },
+        )
+        .await
+        .unwrap()
+        .playlist
+        .playlist
+        .id;
+
+        assert!(
+            handler(mock.database(), user_id, Request { playlist_id, rules: Rules::default() })
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+pub use nghe_api::podcasts::create_podcast_channel::{Request, Response};
+use nghe_proc_macro::handler;
+
+use crate::Error;
+use crate::database::Database;
+use crate::integration::Podcast;
+
+#[handler(role = admin)]
+pub async fn handler(
+    database: &Database,
+    podcast: &Podcast,
+    request: Request,
+) -> Result<Response, Error> {
+    podcast.create_channel(database, &request.url).await?;
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::route::podcasts::get_podcasts;
+    use crate::test::{Mock, mock, podcast};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let server = podcast::Mock::new().await;
+        server.add_episode("first", audio::Format::Mpeg);
+        server.add_episode("second", audio::Format::Flac);
+
+        let podcast = Podcast::new(mock.config.podcast.clone());
+        // Subscribing twice does not create another channel.
+        for _ in 0..2 {
+            handler(mock.database(), &podcast, Request { url: server.feed_url() }).await.unwrap();
+        }
+
+        let channels = get_podcasts::handler(
+            mock.database(),
+            get_podcasts::Request { include_episodes: None, id: None },
+        )
+        .await
+        .unwrap()
+        .podcasts
+        .channel;
+        assert_eq!(channels.len(), 1);
+        let channel = &channels[0];
+        assert_eq!(channel.title.as_deref(), Some(podcast::Mock::TITLE));
+        assert_eq!(channel.description.as_deref(), Some(podcast::Mock::DESCRIPTION));
+        assert_eq!(channel.status, nghe_api::podcasts::Status::Completed);
+        assert_eq!(channel.episode.len(), 2);
+        assert!(
+            channel.episode.iter().all(|episode| episode.status == nghe_api::podcasts::Status::New)
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler_invalid_feed(#[future(awt)] mock: Mock) {
+        let server = podcast::Mock::new().await;
+        let podcast = Podcast::new(mock.config.podcast.clone());
+        let url = concat_string::concat_string!(server.url, "/invalid.xml");
+        handler(mock.database(), &podcast, Request { url }).await.unwrap();
+
+        let channels = get_podcasts::handler(
+            mock.database(),
+            get_podcasts::Request { include_episodes: None, id: None },
+        )
+        .await
+        .unwrap()
+        .podcasts
+        .channel;
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].status, nghe_api::podcasts::Status::Error);
+        assert!(channels[0].error_message.is_some());
+    }
+}
@@ -0,0 +1,66 @@
+pub use nghe_api::podcasts::delete_podcast_channel::{Request, Response};
+use nghe_proc_macro::handler;
+
+use crate::Error;
+use crate::database::Database;
+use crate::integration::Podcast;
+
+#[handler(role = admin)]
+pub async fn handler(
+    database: &Database,
+    podcast: &Podcast,
+    request: Request,
+) -> Result<Response, Error> {
+    podcast.delete_channel(database, request.id).await?;
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::route::podcasts::{create_podcast_channel, get_podcasts};
+    use crate::test::{Mock, mock, podcast};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let server = podcast::Mock::new().await;
+        server.add_episode("first", audio::Format::Mpeg);
+
+        let podcast = Podcast::new(mock.config.podcast.clone());
+        create_podcast_channel::handler(
+            mock.database(),
+            &podcast,
+            create_podcast_channel::Request { url: server.feed_url() },
+        )
+        .await
+        .unwrap();
+        let id = get_podcasts::handler(
+            mock.database(),
+            get_podcasts::Request { include_episodes: None, id: None },
+        )
+        .await
+        .unwrap()
+        .podcasts
+        .channel[0]
+            .id;
+
+        handler(mock.database(), &podcast, Request { id }).await.unwrap();
+        assert!(handler(mock.database(), &podcast, Request { id }).await.is_err());
+        assert!(
+            get_podcasts::handler(
+                mock.database(),
+                get_podcasts::Request { include_episodes: None, id: None }
+            )
+            .await
+            .unwrap()
+            .podcasts
+            .channel
+            .is_empty()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+pub use nghe_api::podcasts::delete_podcast_episode::{Request, Response};
+use nghe_proc_macro::handler;
+
+use crate::Error;
+use crate::database::Database;
+use crate::integration::Podcast;
+
+#[handler(role = admin)]
+pub async fn handler(database: &Database, request: Request) -> Result<Response, Error> {
+    Podcast::delete_episode(database, request.id).await?;
+    Ok(Response)
+}
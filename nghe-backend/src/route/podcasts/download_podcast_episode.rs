@@ -0,0 +1,170 @@
+pub use nghe_api::podcasts::download_podcast_episode::{Request, Response};
+use nghe_proc_macro::handler;
+use tracing::Instrument;
+
+use crate::Error;
+use crate::database::Database;
+use crate::integration::Podcast;
+
+#[handler(role = admin)]
+pub async fn handler(
+    database: &Database,
+    podcast: &Podcast,
+    request: Request,
+) -> Result<Response, Error> {
+    // Episodes that are already downloaded or being downloaded are left untouched.
+    if Podcast::start_download(database, request.id).await? {
+        let database = database.clone();
+        let podcast = podcast.clone();
+        let span = tracing::Span::current();
+        tokio::task::spawn(
+            async move { podcast.download(&database, request.id).await }.instrument(span),
+        );
+    }
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::http::StatusCode;
+    use nghe_api::podcasts::{Episode, Status};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::route::media_retrieval::download;
+    use crate::route::podcasts::{
+        create_podcast_channel, delete_podcast_episode, get_newest_podcasts, get_podcasts,
+    };
+    use crate::test::{Mock, mock, podcast};
+
+    async fn episodes(mock: &Mock) -> Vec<Episode> {
+        get_podcasts::handler(
+            mock.database(),
+            get_podcasts::Request { include_episodes: None, id: None },
+        )
+        .await
+        .unwrap()
+        .podcasts
+        .channel
+        .remove(0)
+        .episode
+    }
+
+    async fn wait(mock: &Mock, id: uuid::Uuid) -> Episode {
+        tokio::time::timeout(Duration::from_secs(30), async {
+            loop {
+                let episode =
+                    episodes(mock).await.into_iter().find(|episode| episode.id == id).unwrap();
+                if episode.status != Status::Downloading {
+                    return episode;
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        })
+        .await
+        .expect("Episode download did not finish in time")
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)] mock: Mock,
+        #[values(
+            audio::Format::Flac,
+            audio::Format::Mpeg,
+            audio::Format::Vorbis,
+            audio::Format::Opus,
+            audio::Format::Mp4
+        )]
+        format: audio::Format,
+    ) {
+        let server = podcast::Mock::new().await;
+        server.add_episode("episode", format);
+
+        let podcast = Podcast::new(mock.config.podcast.clone());
+        create_podcast_channel::handler(
+            mock.database(),
+            &podcast,
+            create_podcast_channel::Request { url: server.feed_url() },
+        )
+        .await
+        .unwrap();
+        let id = episodes(&mock).await[0].id;
+
+        handler(mock.database(), &podcast, Request { id }).await.unwrap();
+        let episode = wait(&mock, id).await;
+        assert_eq!(episode.status, Status::Completed);
+        assert_eq!(episode.stream_id, Some(id));
+        assert_eq!(episode.suffix.as_deref(), Some(format.as_ref()));
+        assert!(episode.duration.is_some());
+
+        let newest = get_newest_podcasts::handler(
+            mock.database(),
+            get_newest_podcasts::Request { count: None },
+        )
+        .await
+        .unwrap()
+        .newest_podcasts
+        .episode;
+        assert_eq!(newest.len(), 1);
+        assert_eq!(newest[0].id, id);
+
+        let user_id = mock.user_id(0).await;
+        let (status, _, body) = download::handler(
+            mock.database(),
+            mock.filesystem(),
+            None,
+            user_id,
+            download::Request { id },
+        )
+        .await
+        .unwrap()
+        .extract()
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, tokio::fs::read(crate::test::assets::path(format)).await.unwrap());
+
+        delete_podcast_episode::handler(mock.database(), delete_podcast_episode::Request { id })
+            .await
+            .unwrap();
+        let episode = wait(&mock, id).await;
+        assert_eq!(episode.status, Status::Deleted);
+        assert!(
+            download::handler(
+                mock.database(),
+                mock.filesystem(),
+                None,
+                user_id,
+                download::Request { id },
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler_missing(#[future(awt)] mock: Mock) {
+        let server = podcast::Mock::new().await;
+        server.add_missing_episode("missing");
+
+        let podcast = Podcast::new(mock.config.podcast.clone());
+        create_podcast_channel::handler(
+            mock.database(),
+            &podcast,
+            create_podcast_channel::Request { url: server.feed_url() },
+        )
+        .await
+        .unwrap();
+        let id = episodes(&mock).await[0].id;
+
+        handler(mock.database(), &podcast, Request { id }).await.unwrap();
+        let episode = wait(&mock, id).await;
+        assert_eq!(episode.status, Status::Error);
+        assert_eq!(episode.stream_id, None);
+    }
+}
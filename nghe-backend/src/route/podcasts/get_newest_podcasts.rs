@@ -0,0 +1,25 @@
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::podcasts::get_newest_podcasts::{NewestPodcasts, Request, Response};
+use nghe_proc_macro::handler;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::podcast_channels::Status;
+use crate::orm::podcast_episodes;
+
+#[handler]
+pub async fn handler(database: &Database, request: Request) -> Result<Response, Error> {
+    Ok(Response {
+        newest_podcasts: NewestPodcasts {
+            episode: podcast_episodes::query::unchecked()
+                .filter(podcast_episodes::status.eq(Status::Completed))
+                .limit(request.count.unwrap_or(20).into())
+                .get_results(&mut database.get().await?)
+                .await?
+                .into_iter()
+                .map(podcast_episodes::Episode::into)
+                .collect(),
+        },
+    })
+}
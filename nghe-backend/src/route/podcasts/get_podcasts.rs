@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use diesel::{ExpressionMethods, PgSortExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use itertools::Itertools;
+pub use nghe_api::podcasts::get_podcasts::{Podcasts, Request, Response};
+use nghe_proc_macro::handler;
+
+use crate::database::Database;
+use crate::orm::{podcast_channels, podcast_episodes};
+use crate::{Error, error};
+
+#[handler]
+pub async fn handler(database: &Database, request: Request) -> Result<Response, Error> {
+    let mut query = podcast_channels::table
+        .order_by((podcast_channels::title.asc().nulls_last(), podcast_channels::url))
+        .select(podcast_channels::Channel::as_select())
+        .into_boxed();
+    if let Some(id) = request.id {
+        query = query.filter(podcast_channels::id.eq(id));
+    }
+    let channels: Vec<podcast_channels::Channel> =
+        query.get_results(&mut database.get().await?).await?;
+    if request.id.is_some() && channels.is_empty() {
+        return error::Kind::NotFound.into();
+    }
+
+    let mut episodes = if request.include_episodes.unwrap_or(true) {
+        let mut query = podcast_episodes::query::unchecked().into_boxed();
+        if let Some(id) = request.id {
+            query = query.filter(podcast_episodes::channel_id.eq(id));
+        }
+        query
+            .get_results(&mut database.get().await?)
+            .await?
+            .into_iter()
+            .into_group_map_by(|episode| episode.channel_id)
+    } else {
+        HashMap::default()
+    };
+
+    Ok(Response {
+        podcasts: Podcasts {
+            channel: channels
+                .into_iter()
+                .map(|channel| {
+                    let episode = episodes
+                        .remove(&channel.id)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(podcast_episodes::Episode::into)
+                        .collect();
+                    channel.into(episode)
+                })
+                .collect(),
+        },
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::audio;
+    use crate::integration::Podcast;
+    use crate::route::podcasts::create_podcast_channel;
+    use crate::test::{Mock, mock, podcast};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let podcast = Podcast::new(mock.config.podcast.clone());
+        for n_episode in [1, 2] {
+            let server = podcast::Mock::new().await;
+            (0..n_episode).for_each(|i| server.add_episode(&i.to_string(), audio::Format::Mpeg));
+            create_podcast_channel::handler(
+                mock.database(),
+                &podcast,
+                create_podcast_channel::Request { url: server.feed_url() },
+            )
+            .await
+            .unwrap();
+        }
+
+        let channels = handler(mock.database(), Request { include_episodes: None, id: None })
+            .await
+            .unwrap()
+            .podcasts
+            .channel;
+        assert_eq!(channels.len(), 2);
+        assert_eq!(channels.iter().map(|channel| channel.episode.len()).sum::<usize>(), 3);
+
+        let channels = handler(
+            mock.database(),
+            Request { include_episodes: Some(false), id: Some(channels[0].id) },
+        )
+        .await
+        .unwrap()
+        .podcasts
+        .channel;
+        assert_eq!(channels.len(), 1);
+        assert!(channels[0].episode.is_empty());
+
+        assert!(
+            handler(
+                mock.database(),
+                Request { include_episodes: None, id: Some(uuid::Uuid::new_v4()) }
+            )
+            .await
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,22 @@
+pub mod create_podcast_channel;
+pub mod delete_podcast_channel;
+pub mod delete_podcast_episode;
+pub mod download_podcast_episode;
+pub mod get_newest_podcasts;
+pub mod get_podcasts;
+pub mod refresh_podcasts;
+
+use crate::integration::Podcast;
+
+nghe_proc_macro::build_router! {
+    modules = [
+        create_podcast_channel,
+        delete_podcast_channel,
+        delete_podcast_episode,
+        download_podcast_episode,
+        get_newest_podcasts,
+        get_podcasts,
+        refresh_podcasts,
+    ],
+    extensions = [Podcast],
+}
@@ -0,0 +1,16 @@
+pub use nghe_api::podcasts::refresh_podcasts::{Request, Response};
+use nghe_proc_macro::handler;
+use tracing::Instrument;
+
+use crate::Error;
+use crate::database::Database;
+use crate::integration::Podcast;
+
+#[handler(role = admin)]
+pub async fn handler(database: &Database, podcast: &Podcast) -> Result<Response, Error> {
+    let database = database.clone();
+    let podcast = podcast.clone();
+    let span = tracing::Span::current();
+    tokio::task::spawn(async move { podcast.refresh(&database).await }.instrument(span));
+    Ok(Response)
+}
@@ -0,0 +1,54 @@
+pub use nghe_api::scan::cancel::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::user_music_folder_permissions;
+use crate::scan::registry;
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    registry: &registry::Registry,
+    request: Request,
+) -> Result<Response, Error> {
+    user_music_folder_permissions::Permission::check_owner(
+        database,
+        user_id,
+        request.music_folder_id,
+    )
+    .await?;
+
+    registry.cancel(request.music_folder_id)?;
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+    use tokio_util::sync::CancellationToken;
+
+    use super::*;
+    use crate::orm::users;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock, #[values(true, false)] running: bool) {
+        let user_id =
+            mock.add_user().role(users::Role { admin: true }).call().await.user_id(1).await;
+        let music_folder_id = mock.music_folder_id(0).await;
+
+        let registry = registry::Registry::default();
+        let token = CancellationToken::new();
+        let _guard = running.then(|| registry.register(music_folder_id, token.clone()).unwrap());
+
+        let result =
+            handler(mock.database(), user_id, &registry, Request { music_folder_id }).await;
+        assert_eq!(result.is_ok(), running);
+        assert_eq!(token.is_cancelled(), running);
+    }
+}
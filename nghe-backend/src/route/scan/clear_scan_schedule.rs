@@ -0,0 +1,61 @@
+pub use nghe_api::scan::clear_scan_schedule::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{music_folders, user_music_folder_permissions};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    user_music_folder_permissions::Permission::check_owner(
+        database,
+        user_id,
+        request.music_folder_id,
+    )
+    .await?;
+
+    music_folders::schedule::Schedule::default().update(database, request.music_folder_id).await?;
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use nghe_api::scan;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::orm::users;
+    use crate::route::scan::{get_scan_schedules, set_scan_schedule};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let user_id =
+            mock.add_user().role(users::Role { admin: true }).call().await.user_id(1).await;
+        let music_folder_id = mock.music_folder_id(0).await;
+
+        set_scan_schedule::handler(
+            mock.database(),
+            user_id,
+            set_scan_schedule::Request {
+                music_folder_id,
+                schedule: scan::set_scan_schedule::Schedule::Interval(3600),
+                full: scan::start::Full::default(),
+            },
+        )
+        .await
+        .unwrap();
+        handler(mock.database(), user_id, Request { music_folder_id }).await.unwrap();
+
+        let scan_schedules =
+            get_scan_schedules::handler(mock.database(), user_id).await.unwrap().scan_schedules;
+        assert!(scan_schedules.is_empty());
+    }
+}
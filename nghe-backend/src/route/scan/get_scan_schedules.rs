@@ -0,0 +1,95 @@
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::scan::get_scan_schedules::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{music_folders, permission, users};
+
+#[handler(internal = true)]
+pub async fn handler(database: &Database, user_id: Uuid) -> Result<Response, Error> {
+    // Schedules are only visible to those who can set them, which are the owners of a music folder
+    // and the admins.
+    let mut query = music_folders::table
+        .filter(permission::with_music_folder(user_id))
+        .filter(music_folders::scan_next_at.is_not_null())
+        .order_by(music_folders::scan_next_at)
+        .select(music_folders::schedule::Scheduled::as_select())
+        .into_boxed();
+    if !users::Role::query(database, user_id).await?.admin {
+        query = query.filter(permission::with_music_folder_owner(user_id));
+    }
+
+    Ok(Response {
+        scan_schedules: query
+            .get_results(&mut database.get().await?)
+            .await?
+            .into_iter()
+            .map(music_folders::schedule::Scheduled::try_into)
+            .try_collect()?,
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use nghe_api::scan;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+        #[values(true, false)] owner: bool,
+    ) {
+        mock.add_music_folder().call().await;
+        mock.add_music_folder().allow(allow).call().await;
+        mock.add_music_folder().call().await;
+
+        let user_id = mock.user_id(0).await;
+        if owner {
+            route::permission::update::handler(
+                mock.database(),
+                route::permission::update::Request {
+                    user_id: Some(user_id),
+                    music_folder_id: None,
+                    permission: nghe_api::permission::Permission {
+                        owner: true,
+                        ..Default::default()
+                    },
+                },
+            )
+            .await
+            .unwrap();
+        }
+        for index in 0..2 {
+            let music_folder_id = mock.music_folder_id(index).await;
+            music_folders::schedule::Schedule::new(
+                &scan::set_scan_schedule::Schedule::Interval(3600),
+                scan::start::Full::default(),
+                crate::time::now().await,
+            )
+            .unwrap()
+            .update(mock.database(), music_folder_id)
+            .await
+            .unwrap();
+        }
+
+        let scan_schedules = handler(mock.database(), user_id).await.unwrap().scan_schedules;
+        let n_schedule = match (owner, allow) {
+            (false, _) => 0,
+            (true, true) => 2,
+            (true, false) => 1,
+        };
+        assert_eq!(scan_schedules.len(), n_schedule);
+    }
+}
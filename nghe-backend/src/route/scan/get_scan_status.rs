@@ -0,0 +1,62 @@
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+use nghe_api::scan::get_scan_status::ScanStatus;
+pub use nghe_api::scan::get_scan_status::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{music_folders, permission, scans};
+
+#[handler]
+pub async fn handler(database: &Database, user_id: Uuid) -> Result<Response, Error> {
+    // Only the latest scan of each music folder that the user has access to is taken into account.
+    let scans = scans::table
+        .inner_join(music_folders::table)
+        .filter(permission::with_music_folder(user_id))
+        .distinct_on(scans::music_folder_id)
+        .order_by((scans::music_folder_id, scans::started_at.desc()))
+        .select(scans::Scan::as_select())
+        .get_results(&mut database.get().await?)
+        .await?;
+
+    Ok(Response {
+        scan_status: ScanStatus {
+            scanning: scans.iter().any(|scan| scan.is_scanning),
+            count: scans.iter().map(|scan| scan.scanned_song_count.cast_unsigned()).sum(),
+            last_scan: scans.iter().filter_map(|scan| scan.finished_at).max(),
+        },
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().call().await;
+        mock.add_music_folder().allow(allow).call().await;
+        mock.music_folder(0).await.add_audio_filesystem::<&str>().n_song(5).call().await;
+        let mut music_folder = mock.music_folder(1).await;
+        music_folder.add_audio_filesystem::<&str>().n_song(10).call().await;
+        music_folder.remove_audio_filesystem::<&str>().call().await;
+
+        let user_id = mock.user_id(0).await;
+        let scan_status = handler(mock.database(), user_id).await.unwrap().scan_status;
+        assert!(!scan_status.scanning);
+        assert_eq!(scan_status.count, if allow { 14 } else { 5 });
+        assert!(scan_status.last_scan.is_some());
+    }
+}
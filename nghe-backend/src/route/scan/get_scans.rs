@@ -0,0 +1,91 @@
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::scan::get_scans::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::{scans, user_music_folder_permissions};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    user_music_folder_permissions::Permission::check_owner(
+        database,
+        user_id,
+        request.music_folder_id,
+    )
+    .await?;
+
+    Ok(Response {
+        scans: scans::table
+            .filter(scans::music_folder_id.eq(request.music_folder_id))
+            .order_by(scans::started_at.desc())
+            .limit(request.count.unwrap_or(10).into())
+            .offset(request.offset.unwrap_or(0).into())
+            .select(scans::Scan::as_select())
+            .get_results(&mut database.get().await?)
+            .await?
+            .into_iter()
+            .map(scans::Scan::into)
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::orm::users;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let user_id =
+            mock.add_user().role(users::Role { admin: true }).call().await.user_id(1).await;
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio_filesystem::<&str>().n_song(5).call().await;
+        music_folder.add_audio_filesystem::<&str>().n_song(5).call().await;
+
+        let scans = handler(
+            mock.database(),
+            user_id,
+            Request { music_folder_id: music_folder.id(), count: None, offset: None },
+        )
+        .await
+        .unwrap()
+        .scans;
+        assert_eq!(scans.len(), 2);
+        assert!(scans[0].started_at > scans[1].started_at);
+        assert_eq!(scans[0].scanned_song_count, 10);
+        assert_eq!(scans[0].upserted_song_count, 5);
+        assert_eq!(scans[1].scanned_song_count, 5);
+        assert_eq!(scans[1].upserted_song_count, 5);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler_not_owner(#[future(awt)] mock: Mock) {
+        let user_id = mock.add_user().call().await.user_id(1).await;
+        assert!(
+            handler(
+                mock.database(),
+                user_id,
+                Request {
+                    music_folder_id: mock.music_folder_id(0).await,
+                    count: None,
+                    offset: None
+                },
+            )
+            .await
+            .is_err()
+        );
+    }
+}
@@ -1,10 +1,24 @@
+mod cancel;
+mod clear_scan_schedule;
+mod get_scan_schedules;
+mod get_scan_status;
+mod get_scans;
+mod set_scan_schedule;
 mod start;
 
 use crate::integration::Informant;
-use crate::scan::scanner;
+use crate::scan::{registry, scanner};
 
 nghe_proc_macro::build_router! {
-    modules = [start(internal = true)],
+    modules = [
+        cancel(internal = true),
+        clear_scan_schedule(internal = true),
+        get_scan_schedules(internal = true),
+        get_scan_status,
+        get_scans(internal = true),
+        set_scan_schedule(internal = true),
+        start(internal = true),
+    ],
     filesystem = true,
-    extensions = [scanner::Config, Informant],
+    extensions = [scanner::Config, Informant, registry::Registry],
 }
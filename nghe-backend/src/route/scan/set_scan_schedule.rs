@@ -0,0 +1,88 @@
+pub use nghe_api::scan::set_scan_schedule::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::{music_folders, user_music_folder_permissions};
+use crate::{Error, error};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    user_music_folder_permissions::Permission::check_owner(
+        database,
+        user_id,
+        request.music_folder_id,
+    )
+    .await?;
+
+    let schedule = music_folders::schedule::Schedule::new(
+        &request.schedule,
+        request.full,
+        crate::time::now().await,
+    )?;
+    schedule.update(database, request.music_folder_id).await?;
+    Ok(Response {
+        next_scan_at: schedule
+            .next_at
+            .ok_or_else(|| error::Kind::InvalidScanSchedule("no next scan time".into()))?,
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use nghe_api::scan;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::orm::users;
+    use crate::route::scan::get_scan_schedules;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[case(scan::set_scan_schedule::Schedule::Interval(3600), true)]
+    #[case(scan::set_scan_schedule::Schedule::Interval(0), false)]
+    #[case(scan::set_scan_schedule::Schedule::Cron("0 3 * * *".into()), true)]
+    #[case(scan::set_scan_schedule::Schedule::Cron("invalid".into()), false)]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)] mock: Mock,
+        #[case] schedule: scan::set_scan_schedule::Schedule,
+        #[case] valid: bool,
+    ) {
+        let user_id =
+            mock.add_user().role(users::Role { admin: true }).call().await.user_id(1).await;
+        let music_folder_id = mock.music_folder_id(0).await;
+        music_folders::schedule::Schedule::default()
+            .update(mock.database(), music_folder_id)
+            .await
+            .unwrap();
+
+        let now = crate::time::now().await;
+        let full = scan::start::Full { file: true, ..Default::default() };
+        let result = handler(
+            mock.database(),
+            user_id,
+            Request { music_folder_id, schedule: schedule.clone(), full },
+        )
+        .await;
+        assert_eq!(result.is_ok(), valid);
+
+        let scan_schedules =
+            get_scan_schedules::handler(mock.database(), user_id).await.unwrap().scan_schedules;
+        if valid {
+            let next_scan_at = result.unwrap().next_scan_at;
+            assert!(next_scan_at > now);
+            assert_eq!(scan_schedules.len(), 1);
+            assert_eq!(scan_schedules[0].music_folder_id, music_folder_id);
+            assert!(scan_schedules[0].full.file);
+            assert_eq!(scan_schedules[0].next_scan_at, next_scan_at);
+        } else {
+            assert!(scan_schedules.is_empty());
+        }
+    }
+}
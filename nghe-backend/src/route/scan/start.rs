@@ -8,7 +8,7 @@ use crate::database::Database;
 use crate::filesystem::Filesystem;
 use crate::integration::Informant;
 use crate::orm::user_music_folder_permissions;
-use crate::scan::scanner;
+use crate::scan::{registry, scanner};
 
 #[handler(internal = true)]
 pub async fn handler(
@@ -17,6 +17,7 @@ pub async fn handler(
     user_id: Uuid,
     config: scanner::Config,
     informant: Informant,
+    registry: &registry::Registry,
     request: Request,
 ) -> Result<Response, Error> {
     user_music_folder_permissions::Permission::check_owner(
@@ -28,8 +29,84 @@ pub async fn handler(
 
     let scanner =
         scanner::Scanner::new(database, filesystem, config, informant, request).await?.into_owned();
+    let guard = registry.register(scanner.music_folder.id, scanner.token.clone())?;
+    // The start is recorded before returning so a scan running in another process is reported.
+    let key = scanner.start().await?;
 
     let span = tracing::Span::current();
-    tokio::task::spawn(async move { scanner.run().await }.instrument(span));
+    tokio::task::spawn(
+        async move {
+            let _guard = guard;
+            scanner.run_started(key).await
+        }
+        .instrument(span),
+    );
     Ok(Response)
 }
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+    use tokio_util::sync::CancellationToken;
+
+    use super::*;
+    use crate::orm::{scans, users};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler_running(#[future(awt)] mock: Mock) {
+        let user_id =
+            mock.add_user().role(users::Role { admin: true }).call().await.user_id(1).await;
+        let music_folder_id = mock.music_folder_id(0).await;
+
+        let registry = registry::Registry::default();
+        let _guard = registry.register(music_folder_id, CancellationToken::new()).unwrap();
+
+        assert!(
+            Box::pin(handler(
+                mock.database(),
+                mock.filesystem(),
+                user_id,
+                mock.config.scanner(),
+                mock.informant.clone(),
+                &registry,
+                Request { music_folder_id, full: nghe_api::scan::start::Full::default() },
+            ))
+            .await
+            .is_err()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler_running_database(#[future(awt)] mock: Mock) {
+        let user_id =
+            mock.add_user().role(users::Role { admin: true }).call().await.user_id(1).await;
+        let music_folder_id = mock.music_folder_id(0).await;
+
+        // A scan can also be running in another process which only shows up in the database.
+        scans::Key::start(
+            mock.database(),
+            scans::Key { started_at: crate::time::now().await, music_folder_id },
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert!(
+            Box::pin(handler(
+                mock.database(),
+                mock.filesystem(),
+                user_id,
+                mock.config.scanner(),
+                mock.informant.clone(),
+                &registry::Registry::default(),
+                Request { music_folder_id, full: nghe_api::scan::start::Full::default() },
+            ))
+            .await
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::scrobbler::get::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::user_scrobblers;
+
+#[handler(internal = true)]
+pub async fn handler(database: &Database, user_id: Uuid) -> Result<Response, Error> {
+    Ok(Response {
+        scrobblers: user_scrobblers::table
+            .filter(user_scrobblers::user_id.eq(user_id))
+            .order_by(user_scrobblers::service)
+            .select(user_scrobblers::Scrobbler::as_select())
+            .get_results(&mut database.get().await?)
+            .await?
+            .into_iter()
+            .map(user_scrobblers::Scrobbler::into)
+            .collect(),
+    })
+}
@@ -0,0 +1,68 @@
+pub use nghe_api::scrobbler::link::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::integration::Scrobbler;
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    scrobbler: &Scrobbler,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    Ok(Response {
+        username: scrobbler.link(database, user_id, request.service.into(), &request.token).await?,
+    })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use nghe_api::scrobbler::Service;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test::{Mock, listenbrainz, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let listenbrainz = listenbrainz::Mock::new().await;
+        let scrobbler = Scrobbler::new(listenbrainz.integration());
+        let user_id = mock.user_id(0).await;
+
+        let response = handler(
+            mock.database(),
+            &scrobbler,
+            user_id,
+            Request { service: Service::Listenbrainz, token: listenbrainz::Mock::TOKEN.to_owned() },
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.username, listenbrainz::Mock::USERNAME);
+
+        assert!(
+            handler(
+                mock.database(),
+                &scrobbler,
+                user_id,
+                Request { service: Service::Listenbrainz, token: "invalid".to_owned() },
+            )
+            .await
+            .is_err()
+        );
+        assert!(
+            handler(
+                mock.database(),
+                &scrobbler,
+                user_id,
+                Request { service: Service::Lastfm, token: "token".to_owned() },
+            )
+            .await
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,10 @@
+pub mod get;
+pub mod link;
+pub mod unlink;
+
+use crate::integration::Scrobbler;
+
+nghe_proc_macro::build_router! {
+    modules = [get(internal = true), link(internal = true), unlink(internal = true)],
+    extensions = [Scrobbler],
+}
@@ -0,0 +1,74 @@
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::scrobbler::unlink::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::Error;
+use crate::database::Database;
+use crate::orm::user_scrobblers::Service;
+use crate::orm::{plays, scrobble_retries, user_scrobblers};
+
+#[handler(internal = true)]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let service: Service = request.service.into();
+    diesel::delete(scrobble_retries::table)
+        .filter(scrobble_retries::service.eq(service))
+        .filter(
+            scrobble_retries::play_id
+                .eq_any(plays::table.filter(plays::user_id.eq(user_id)).select(plays::id)),
+        )
+        .execute(&mut database.get().await?)
+        .await?;
+    diesel::delete(user_scrobblers::table)
+        .filter(user_scrobblers::user_id.eq(user_id))
+        .filter(user_scrobblers::service.eq(service))
+        .execute(&mut database.get().await?)
+        .await?;
+    Ok(Response)
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use nghe_api::scrobbler::Service;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::integration::Scrobbler;
+    use crate::route::scrobbler::{get, link};
+    use crate::test::{Mock, listenbrainz, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let listenbrainz = listenbrainz::Mock::new().await;
+        let scrobbler = Scrobbler::new(listenbrainz.integration());
+        let user_id = mock.user_id(0).await;
+
+        link::handler(
+            mock.database(),
+            &scrobbler,
+            user_id,
+            link::Request {
+                service: Service::Listenbrainz,
+                token: listenbrainz::Mock::TOKEN.to_owned(),
+            },
+        )
+        .await
+        .unwrap();
+        let scrobblers = get::handler(mock.database(), user_id).await.unwrap().scrobblers;
+        assert_eq!(scrobblers.len(), 1);
+        assert_eq!(scrobblers[0].service, Service::Listenbrainz);
+        assert_eq!(scrobblers[0].username, listenbrainz::Mock::USERNAME);
+
+        handler(mock.database(), user_id, Request { service: Service::Listenbrainz })
+            .await
+            .unwrap();
+        assert!(get::handler(mock.database(), user_id).await.unwrap().scrobblers.is_empty());
+    }
+}
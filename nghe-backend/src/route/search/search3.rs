@@ -13,7 +13,7 @@ use uuid::Uuid;
 
 use crate::Error;
 use crate::database::Database;
-use crate::orm::{albums, artists, id3, songs};
+use crate::orm::{albums, artists, function, id3, songs};
 
 const USIMPLE_TS_CONFIGURATION: TsConfigurationByName = TsConfigurationByName("usimple");
 
@@ -34,7 +34,10 @@ pub async fn handler(
             let query = id3::artist::query::with_user_id(user_id).limit(count).offset(offset);
             if sync {
                 query
-                    .order_by((artists::name, artists::mbz_id))
+                    .order_by((
+                        function::coalesce(artists::sort_name, artists::name),
+                        artists::mbz_id,
+                    ))
                     .get_results(&mut database.get().await?)
                     .await?
             } else {
@@ -66,7 +69,7 @@ pub async fn handler(
             let query = id3::album::short::query::with_user_id(user_id).limit(count).offset(offset);
             if sync {
                 query
-                    .order_by((albums::name, albums::mbz_id))
+                    .order_by((function::coalesce(albums::sort_name, albums::name), albums::mbz_id))
                     .get_results(&mut database.get().await?)
                     .await?
             } else {
@@ -98,7 +101,7 @@ pub async fn handler(
             let query = id3::song::short::query::with_user_id(user_id).limit(count).offset(offset);
             if sync {
                 query
-                    .order_by((songs::title, songs::mbz_id))
+                    .order_by((function::coalesce(songs::sort_name, songs::title), songs::mbz_id))
                     .get_results(&mut database.get().await?)
                     .await?
             } else {
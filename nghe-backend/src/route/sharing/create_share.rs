@@ -0,0 +1,167 @@
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::sharing::create_share::{Request, Response};
+use nghe_api::sharing::get_shares::Shares;
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::{albums, permission, playlists_users, shares, songs};
+use crate::{Error, config, error};
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    config: config::Share,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let ids = &request.ids;
+    let song_ids: Vec<Uuid> = songs::table
+        .inner_join(albums::table)
+        .filter(permission::with_album(user_id))
+        .filter(songs::id.eq_any(ids))
+        .select(songs::id)
+        .get_results(&mut database.get().await?)
+        .await?;
+    let album_ids: Vec<Uuid> = albums::table
+        .filter(permission::with_album(user_id))
+        .filter(albums::id.eq_any(ids))
+        .select(albums::id)
+        .get_results(&mut database.get().await?)
+        .await?;
+    let playlist_ids: Vec<Uuid> = playlists_users::table
+        .filter(playlists_users::user_id.eq(user_id))
+        .filter(playlists_users::playlist_id.eq_any(ids))
+        .select(playlists_users::playlist_id)
+        .get_results(&mut database.get().await?)
+        .await?;
+
+    if ids.is_empty()
+        || ids.iter().any(|id| {
+            !song_ids.contains(id) && !album_ids.contains(id) && !playlist_ids.contains(id)
+        })
+    {
+        return error::Kind::NotFound.into();
+    }
+
+    // Keep the order of the request.
+    let filter =
+        |kind_ids: &[Uuid]| ids.iter().filter(|id| kind_ids.contains(id)).copied().collect();
+    let share_id: Uuid = diesel::insert_into(shares::table)
+        .values(shares::Data {
+            user_id,
+            description: request.description.map(Into::into),
+            song_ids: filter(&song_ids),
+            album_ids: filter(&album_ids),
+            playlist_ids: filter(&playlist_ids),
+            expires_at: request.expires,
+        })
+        .returning(shares::id)
+        .get_result(&mut database.get().await?)
+        .await?;
+
+    let share = shares::query::unchecked()
+        .filter(shares::id.eq(share_id))
+        .get_result(&mut database.get().await?)
+        .await?
+        .try_into(database, &config)
+        .await?;
+    Ok(Response { shares: Shares { share: vec![share] } })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::playlists::create_playlist;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(true, false)] allow: bool,
+    ) {
+        mock.add_music_folder().allow(allow).call().await;
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song(2).call().await;
+
+        let user_id = mock.user_id(0).await;
+        let description: Option<String> = Some(Faker.fake());
+        let request = Request {
+            ids: vec![music_folder.song_id(0)],
+            description: description.clone(),
+            expires: None,
+        };
+        let share = handler(mock.database(), config::Share::default(), user_id, request).await;
+        assert_eq!(share.is_ok(), allow);
+
+        if allow {
+            let share = share.unwrap().shares.share.remove(0);
+            assert_eq!(share.url, format!("/frontend/share/{}", share.id));
+            assert_eq!(share.description, description);
+            assert_eq!(share.visit_count, 0);
+            assert_eq!(
+                share.entry.into_iter().map(|song| song.song.id).collect::<Vec<_>>(),
+                vec![music_folder.song_id(0)]
+            );
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_playlist(
+        #[future(awt)]
+        #[with(2, 1)]
+        mock: Mock,
+    ) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song((2..4).fake()).call().await;
+
+        let user_id = mock.user_id(0).await;
+        let playlist = create_playlist::handler(
+            mock.database(),
+            user_id,
+            create_playlist::Request {
+                create_or_update: Faker.fake::<String>().into(),
+                song_ids: Some(music_folder.database.keys().copied().collect()),
+            },
+        )
+        .await
+        .unwrap()
+        .playlist;
+
+        let config = config::Share { base_url: Some("https://example.com/".to_owned()) };
+        let share = handler(
+            mock.database(),
+            config,
+            user_id,
+            Request { ids: vec![playlist.playlist.id], description: None, expires: None },
+        )
+        .await
+        .unwrap()
+        .shares
+        .share
+        .remove(0);
+        assert_eq!(share.url, format!("https://example.com/frontend/share/{}", share.id));
+        assert_eq!(share.entry.len(), playlist.entry.len());
+
+        let other_user_id = mock.user_id(1).await;
+        assert!(
+            handler(
+                mock.database(),
+                config::Share::default(),
+                other_user_id,
+                Request { ids: vec![playlist.playlist.id], description: None, expires: None },
+            )
+            .await
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+pub use nghe_api::sharing::delete_share::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::shares;
+use crate::{Error, error};
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let deleted = diesel::delete(shares::table)
+        .filter(shares::id.eq(request.id))
+        .filter(shares::user_id.eq(user_id))
+        .execute(&mut database.get().await?)
+        .await?;
+    if deleted > 0 { Ok(Response) } else { error::Kind::NotFound.into() }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::config;
+    use crate::route::sharing::{create_share, get_shares};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(2, 1)]
+        mock: Mock,
+    ) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().call().await;
+
+        let user_id = mock.user_id(0).await;
+        let id = create_share::handler(
+            mock.database(),
+            config::Share::default(),
+            user_id,
+            create_share::Request {
+                ids: vec![music_folder.song_id(0)],
+                description: None,
+                expires: None,
+            },
+        )
+        .await
+        .unwrap()
+        .shares
+        .share[0]
+            .id;
+
+        let other_user_id = mock.user_id(1).await;
+        assert!(handler(mock.database(), other_user_id, Request { id }).await.is_err());
+        handler(mock.database(), user_id, Request { id }).await.unwrap();
+        assert!(
+            get_shares::handler(mock.database(), config::Share::default(), user_id)
+                .await
+                .unwrap()
+                .shares
+                .share
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use axum_extra::headers::Range;
+pub use nghe_api::sharing::download_public_share::Request;
+use nghe_proc_macro::handler;
+
+use crate::database::Database;
+use crate::filesystem::Filesystem;
+use crate::http::binary;
+use crate::http::header::ToOffset;
+use crate::orm::shares;
+use crate::route::media_retrieval::download;
+use crate::{Error, error};
+
+#[handler(need_auth = false)]
+pub async fn handler(
+    database: &Database,
+    filesystem: &Filesystem,
+    #[handler(header)] range: Option<Range>,
+    request: Request,
+) -> Result<binary::Response, Error> {
+    let share = shares::Share::query_unexpired(database, request.share_id).await?;
+    if !share.entries(database).await?.iter().any(|entry| entry.song.id == request.id) {
+        return error::Kind::NotFound.into();
+    }
+
+    let (filesystem, source) =
+        binary::Source::audio(database, filesystem, share.user_id, request.id).await?;
+    let offset = range.map(|range| range.to_offset(source.property.size.into())).transpose()?;
+    download::handler_impl(filesystem, source, offset).await
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use axum::http::StatusCode;
+    use nghe_api::common::filesystem;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::config;
+    use crate::file::audio;
+    use crate::filesystem::Trait as _;
+    use crate::route::sharing::create_share;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(1, 0)]
+        mock: Mock,
+        #[values(filesystem::Type::Local, filesystem::Type::S3)] ty: filesystem::Type,
+    ) {
+        mock.add_music_folder().ty(ty).call().await;
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio_filesystem::<&str>().format(audio::Format::Flac).call().await;
+        music_folder.add_audio_filesystem::<&str>().format(audio::Format::Flac).call().await;
+        let shared_id = music_folder.song_id_filesystem(0).await;
+        let other_id = music_folder.song_id_filesystem(1).await;
+
+        let user_id = mock.user_id(0).await;
+        let share_id = create_share::handler(
+            mock.database(),
+            config::Share::default(),
+            user_id,
+            create_share::Request { ids: vec![shared_id], description: None, expires: None },
+        )
+        .await
+        .unwrap()
+        .shares
+        .share[0]
+            .id;
+
+        let binary =
+            handler(mock.database(), mock.filesystem(), None, Request { share_id, id: shared_id })
+                .await
+                .unwrap();
+        let (status, _, body) = binary.extract().await;
+        assert_eq!(status, StatusCode::OK);
+        let local_bytes =
+            music_folder.to_impl().read(music_folder.absolute_path(0).to_path()).await.unwrap();
+        assert_eq!(body, local_bytes);
+
+        assert!(
+            handler(mock.database(), mock.filesystem(), None, Request { share_id, id: other_id })
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use diesel::ExpressionMethods;
+use diesel_async::RunQueryDsl;
+pub use nghe_api::sharing::get_public_share::{Request, Response};
+use nghe_proc_macro::handler;
+
+use crate::database::Database;
+use crate::orm::shares;
+use crate::{Error, config};
+
+#[handler(internal = true, need_auth = false)]
+pub async fn handler(
+    database: &Database,
+    config: config::Share,
+    request: Request,
+) -> Result<Response, Error> {
+    let share = shares::Share::query_unexpired(database, request.id).await?;
+    diesel::update(shares::table)
+        .filter(shares::id.eq(share.id))
+        .set((
+            shares::visit_count.eq(shares::visit_count + 1),
+            shares::last_visited_at.eq(crate::time::now().await),
+        ))
+        .execute(&mut database.get().await?)
+        .await?;
+    Ok(Response { share: share.try_into(database, &config).await? })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+    use time::{Duration, OffsetDateTime};
+
+    use super::*;
+    use crate::route::sharing::{create_share, get_shares};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock, #[values(true, false)] expired: bool) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().call().await;
+
+        let user_id = mock.user_id(0).await;
+        let expires = OffsetDateTime::now_utc() + Duration::days(if expired { -1 } else { 1 });
+        let id = create_share::handler(
+            mock.database(),
+            config::Share::default(),
+            user_id,
+            create_share::Request {
+                ids: vec![music_folder.song_id(0)],
+                description: None,
+                expires: Some(expires),
+            },
+        )
+        .await
+        .unwrap()
+        .shares
+        .share[0]
+            .id;
+
+        for _ in 0..2 {
+            let share = handler(mock.database(), config::Share::default(), Request { id }).await;
+            assert_eq!(share.is_ok(), !expired);
+            if !expired {
+                assert_eq!(share.unwrap().share.entry[0].song.id, music_folder.song_id(0));
+            }
+        }
+
+        let share = get_shares::handler(mock.database(), config::Share::default(), user_id)
+            .await
+            .unwrap()
+            .shares
+            .share
+            .remove(0);
+        assert_eq!(share.visit_count, if expired { 0 } else { 2 });
+        assert_eq!(share.last_visited.is_some(), !expired);
+    }
+}
@@ -0,0 +1,92 @@
+use axum_extra::headers::Range;
+pub use nghe_api::sharing::get_public_share_cover_art::Request;
+use nghe_proc_macro::handler;
+
+use crate::database::Database;
+use crate::http::binary;
+use crate::orm::shares;
+use crate::route::media_retrieval::get_cover_art;
+use crate::{Error, config, error};
+
+#[handler(need_auth = false)]
+pub async fn handler(
+    database: &Database,
+    config: config::CoverArt,
+    #[handler(header)] range: Option<Range>,
+    request: Request,
+) -> Result<binary::Response, Error> {
+    let share = shares::Share::query_unexpired(database, request.share_id).await?;
+    if !share.entries(database).await?.iter().any(|entry| entry.song.cover_art == Some(request.id))
+    {
+        return error::Kind::NotFound.into();
+    }
+
+    get_cover_art::handler(
+        database,
+        config,
+        range,
+        get_cover_art::Request { id: request.id, size: request.size },
+    )
+    .await
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use axum::http::StatusCode;
+    use fake::{Fake, Faker};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::file::image;
+    use crate::route::sharing::create_share;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().image(Some(Faker.fake())).call().await;
+
+        let user_id = mock.user_id(0).await;
+        let share = create_share::handler(
+            mock.database(),
+            config::Share::default(),
+            user_id,
+            create_share::Request {
+                ids: vec![music_folder.song_id(0)],
+                description: None,
+                expires: None,
+            },
+        )
+        .await
+        .unwrap()
+        .shares
+        .share
+        .remove(0);
+        let share_id = share.id;
+        let id = share.entry[0].song.cover_art.unwrap();
+
+        let binary = handler(
+            mock.database(),
+            mock.config.cover_art.clone(),
+            None,
+            Request { share_id, id, size: None },
+        )
+        .await
+        .unwrap();
+        assert_eq!(binary.extract().await.0, StatusCode::OK);
+
+        let other_id = Faker.fake::<image::Image>().upsert_mock(&mock, None::<&str>).await;
+        assert!(
+            handler(
+                mock.database(),
+                mock.config.cover_art.clone(),
+                None,
+                Request { share_id, id: other_id, size: None },
+            )
+            .await
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use diesel_async::RunQueryDsl;
+use nghe_api::sharing::get_shares::Shares;
+pub use nghe_api::sharing::get_shares::{Request, Response};
+use nghe_proc_macro::handler;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::shares;
+use crate::{Error, config};
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    config: config::Share,
+    user_id: Uuid,
+) -> Result<Response, Error> {
+    let shares =
+        shares::query::with_user_id(user_id).get_results(&mut database.get().await?).await?;
+
+    let mut share = Vec::with_capacity(shares.len());
+    for value in shares {
+        share.push(value.try_into(database, &config).await?);
+    }
+    Ok(Response { shares: Shares { share } })
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::route::sharing::create_share;
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(2, 1)]
+        mock: Mock,
+    ) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().n_song(2).call().await;
+
+        let user_id = mock.user_id(0).await;
+        for i in 0..2 {
+            create_share::handler(
+                mock.database(),
+                config::Share::default(),
+                user_id,
+                create_share::Request {
+                    ids: vec![music_folder.song_id(i)],
+                    description: None,
+                    expires: None,
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        let shares =
+            handler(mock.database(), config::Share::default(), user_id).await.unwrap().shares.share;
+        // Newest shares come first.
+        assert_eq!(
+            shares.iter().map(|share| share.entry[0].song.id).collect::<Vec<_>>(),
+            vec![music_folder.song_id(1), music_folder.song_id(0)]
+        );
+
+        let other_user_id = mock.user_id(1).await;
+        assert!(
+            handler(mock.database(), config::Share::default(), other_user_id)
+                .await
+                .unwrap()
+                .shares
+                .share
+                .is_empty()
+        );
+    }
+}
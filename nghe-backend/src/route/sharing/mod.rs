@@ -0,0 +1,23 @@
+pub mod create_share;
+mod delete_share;
+mod download_public_share;
+pub mod get_public_share;
+mod get_public_share_cover_art;
+pub mod get_shares;
+mod update_share;
+
+use crate::config;
+
+nghe_proc_macro::build_router! {
+    modules = [
+        create_share,
+        delete_share,
+        download_public_share,
+        get_public_share(internal = true),
+        get_public_share_cover_art,
+        get_shares,
+        update_share
+    ],
+    filesystem = true,
+    extensions = [config::CoverArt, config::Share],
+}
@@ -0,0 +1,112 @@
+use diesel::dsl::{exists, select};
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+pub use nghe_api::sharing::update_share::{Request, Response};
+use nghe_proc_macro::handler;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::orm::shares;
+use crate::{Error, error};
+
+#[handler]
+pub async fn handler(
+    database: &Database,
+    user_id: Uuid,
+    request: Request,
+) -> Result<Response, Error> {
+    let query = shares::table.filter(shares::id.eq(request.id)).filter(shares::user_id.eq(user_id));
+    let update = shares::Update {
+        description: request
+            .description
+            .map(|value| if value.is_empty() { None } else { Some(value.into()) }),
+        expires_at: request
+            .expires
+            .map(|value| if value == OffsetDateTime::UNIX_EPOCH { None } else { Some(value) }),
+    };
+
+    let found = if update.description.is_none() && update.expires_at.is_none() {
+        select(exists(query)).get_result(&mut database.get().await?).await?
+    } else {
+        diesel::update(query).set(update).execute(&mut database.get().await?).await? > 0
+    };
+    if found { Ok(Response) } else { error::Kind::NotFound.into() }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use fake::{Fake, Faker};
+    use rstest::rstest;
+    use time::Duration;
+
+    use super::*;
+    use crate::config;
+    use crate::route::sharing::{create_share, get_shares};
+    use crate::test::{Mock, mock};
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler(
+        #[future(awt)]
+        #[with(2, 1)]
+        mock: Mock,
+    ) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio().call().await;
+
+        let user_id = mock.user_id(0).await;
+        let id = create_share::handler(
+            mock.database(),
+            config::Share::default(),
+            user_id,
+            create_share::Request {
+                ids: vec![music_folder.song_id(0)],
+                description: Some(Faker.fake()),
+                expires: None,
+            },
+        )
+        .await
+        .unwrap()
+        .shares
+        .share[0]
+            .id;
+
+        let other_user_id = mock.user_id(1).await;
+        let request = Request { id, description: None, expires: None };
+        assert!(handler(mock.database(), other_user_id, request).await.is_err());
+        let request = Request { id, description: None, expires: None };
+        handler(mock.database(), user_id, request).await.unwrap();
+
+        let description: String = Faker.fake();
+        let expires =
+            (OffsetDateTime::now_utc() + Duration::days(1)).replace_millisecond(0).unwrap();
+        let request =
+            Request { id, description: Some(description.clone()), expires: Some(expires) };
+        handler(mock.database(), user_id, request).await.unwrap();
+        let share = get_shares::handler(mock.database(), config::Share::default(), user_id)
+            .await
+            .unwrap()
+            .shares
+            .share
+            .remove(0);
+        assert_eq!(share.description, Some(description));
+        assert_eq!(share.expires, Some(expires));
+
+        let request = Request {
+            id,
+            description: Some(String::default()),
+            expires: Some(OffsetDateTime::UNIX_EPOCH),
+        };
+        handler(mock.database(), user_id, request).await.unwrap();
+        let share = get_shares::handler(mock.database(), config::Share::default(), user_id)
+            .await
+            .unwrap()
+            .shares
+            .share
+            .remove(0);
+        assert!(share.description.is_none());
+        assert!(share.expires.is_none());
+    }
+}
@@ -1,17 +1,41 @@
-use diesel::ExpressionMethods;
-use diesel_async::RunQueryDsl;
+use diesel::{ExpressionMethods, QueryDsl};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
 pub use nghe_api::user::delete::{Request, Response};
 use nghe_proc_macro::handler;
 
 use crate::Error;
 use crate::database::Database;
-use crate::orm::users;
+use crate::orm::{playlists, playlists_users, users};
 
 #[handler(role = admin, internal = true)]
 pub async fn handler(database: &Database, request: Request) -> Result<Response, Error> {
-    diesel::delete(users::table)
-        .filter(users::id.eq(request.user_id))
-        .execute(&mut database.get().await?)
+    let user_id = request.user_id;
+    database
+        .get()
+        .await?
+        .transaction(|connection| {
+            async move {
+                // Playlists can not exist without an owner so they are deleted with their owner.
+                diesel::delete(playlists::table)
+                    .filter(
+                        playlists::id.eq_any(
+                            playlists_users::table
+                                .filter(playlists_users::user_id.eq(user_id))
+                                .filter(playlists_users::owner)
+                                .select(playlists_users::playlist_id),
+                        ),
+                    )
+                    .execute(connection)
+                    .await?;
+                diesel::delete(users::table)
+                    .filter(users::id.eq(user_id))
+                    .execute(connection)
+                    .await?;
+                Ok::<_, Error>(())
+            }
+            .scope_boxed()
+        })
         .await?;
     Ok(Response)
 }
@@ -19,9 +43,11 @@ pub async fn handler(database: &Database, request: Request) -> Result<Response,
 #[cfg(test)]
 #[coverage(off)]
 mod tests {
+    use fake::{Fake, Faker};
     use rstest::rstest;
 
     use super::*;
+    use crate::route::playlists::{add_playlist_user, create_playlist, get_playlists};
     use crate::route::user::list;
     use crate::test::{Mock, mock};
 
@@ -39,4 +65,42 @@ mod tests {
         assert_eq!(users.len(), 1);
         assert_eq!(users[0].id, user_id_2);
     }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_delete_playlist_owner(
+        #[future(awt)]
+        #[with(2, 0)]
+        mock: Mock,
+    ) {
+        let database = mock.database();
+        let owner_id = mock.user_id(0).await;
+        let user_id = mock.user_id(1).await;
+
+        let playlist_id = create_playlist::handler(
+            database,
+            owner_id,
+            create_playlist::Request {
+                create_or_update: Faker.fake::<String>().into(),
+                song_ids: None,
+            },
+        )
+        .await
+        .unwrap()
+        .playlist
+        .playlist
+        .id;
+        add_playlist_user::handler(
+            database,
+            owner_id,
+            add_playlist_user::Request { playlist_id, user_id, write: true },
+        )
+        .await
+        .unwrap();
+
+        handler(database, Request { user_id: owner_id }).await.unwrap();
+        assert!(
+            get_playlists::handler(database, user_id).await.unwrap().playlists.playlist.is_empty()
+        );
+    }
 }
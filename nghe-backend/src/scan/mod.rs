@@ -1 +1,4 @@
+pub mod registry;
 pub mod scanner;
+pub mod scheduler;
+pub mod watcher;
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::{Error, error};
+
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    scans: Arc<Mutex<HashMap<Uuid, CancellationToken>>>,
+}
+
+#[derive(Debug)]
+pub struct Guard {
+    registry: Registry,
+    music_folder_id: Uuid,
+}
+
+impl Registry {
+    fn scans(&self) -> MutexGuard<'_, HashMap<Uuid, CancellationToken>> {
+        // The map is always left in a consistent state so it is safe to ignore the poison.
+        self.scans.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn register(
+        &self,
+        music_folder_id: Uuid,
+        token: CancellationToken,
+    ) -> Result<Guard, Error> {
+        let mut scans = self.scans();
+        if scans.contains_key(&music_folder_id) {
+            return error::Kind::ScanAlreadyRunning(music_folder_id).into();
+        }
+        scans.insert(music_folder_id, token);
+        Ok(Guard { registry: self.clone(), music_folder_id })
+    }
+
+    pub fn cancel(&self, music_folder_id: Uuid) -> Result<(), Error> {
+        self.scans()
+            .get(&music_folder_id)
+            .ok_or_else(|| error::Kind::ScanNotRunning(music_folder_id))?
+            .cancel();
+        Ok(())
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        self.registry.scans().remove(&self.music_folder_id);
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let registry = Registry::default();
+        let music_folder_id = Uuid::new_v4();
+
+        let token = CancellationToken::new();
+        let guard = registry.register(music_folder_id, token.clone()).unwrap();
+        assert!(registry.scans().contains_key(&music_folder_id));
+        assert!(registry.register(music_folder_id, CancellationToken::new()).is_err());
+        assert!(registry.register(Uuid::new_v4(), CancellationToken::new()).is_ok());
+
+        registry.cancel(music_folder_id).unwrap();
+        assert!(token.is_cancelled());
+
+        drop(guard);
+        assert!(!registry.scans().contains_key(&music_folder_id));
+        assert!(registry.cancel(music_folder_id).is_err());
+        assert!(registry.register(music_folder_id, CancellationToken::new()).is_ok());
+    }
+}
@@ -1,8 +1,11 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ffi::CString;
 use std::sync::Arc;
 
 use diesel::{
-    ExpressionMethods, NullableExpressionMethods, OptionalExtension, QueryDsl, SelectableHelper,
+    BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods, OptionalExtension,
+    QueryDsl, SelectableHelper,
 };
 use diesel_async::RunQueryDsl;
 use lofty::config::ParseOptions;
@@ -10,6 +13,7 @@ use loole::Receiver;
 use nghe_api::scan;
 use tokio::sync::Semaphore;
 use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 use tracing::{Instrument, instrument};
 use typed_path::Utf8TypedPath;
 use uuid::Uuid;
@@ -18,7 +22,7 @@ use crate::database::Database;
 use crate::file::{self, File, audio, image, lyric};
 use crate::filesystem::{self, Entry, Filesystem, Trait, entry};
 use crate::integration::Informant;
-use crate::orm::{albums, music_folders, songs};
+use crate::orm::{albums, directories, music_folders, scans, songs};
 use crate::{Error, config, error};
 
 #[derive(Debug, Clone)]
@@ -38,9 +42,12 @@ pub struct Scanner<'db, 'fs, 'mf> {
     pub informant: Informant,
     pub music_folder: music_folders::MusicFolder<'mf>,
     pub full: scan::start::Full,
+    pub token: CancellationToken,
 }
 
 impl<'db, 'fs, 'mf> Scanner<'db, 'fs, 'mf> {
+    const PROGRESS_BATCH_SIZE: i64 = 64;
+
     #[coverage(off)]
     pub async fn new(
         database: &'db Database,
@@ -75,6 +82,7 @@ impl<'db, 'fs, 'mf> Scanner<'db, 'fs, 'mf> {
             informant,
             music_folder,
             full,
+            token: CancellationToken::new(),
         })
     }
 
@@ -216,7 +224,12 @@ impl<'db, 'fs, 'mf> Scanner<'db, 'fs, 'mf> {
             err(Debug)
         )
     )]
-    async fn one(&self, entry: &Entry, started_at: time::OffsetDateTime) -> Result<Uuid, Error> {
+    // Returns the song id and whether its information is upserted into the database.
+    pub async fn one(
+        &self,
+        entry: &Entry,
+        started_at: time::OffsetDateTime,
+    ) -> Result<(Uuid, bool), Error> {
         let database = &self.database;
 
         // Query the database to see if we have any song within this music folder that has the same
@@ -240,7 +253,7 @@ impl<'db, 'fs, 'mf> Scanner<'db, 'fs, 'mf> {
                 // in the previous scan but not in the current scan, thus `scanned_at` is sooner
                 // than `started_at`. We want to skip this file as well (unless in full mode) hence
                 // we have to check for its `last_modified` along with `scanned_at`.
-                return Ok(song_time.id);
+                return Ok((song_time.id, false));
             }
             Some(song_time.id)
         } else {
@@ -276,7 +289,7 @@ impl<'db, 'fs, 'mf> Scanner<'db, 'fs, 'mf> {
 
                 self.update_external(started_at, song_path.id, absolute_path, dir_image_id).await?;
                 tracing::debug!("already scanned");
-                return Ok(song_path.id);
+                return Ok((song_path.id, false));
             } else if let Some(song_id) = song_id {
                 // `DatabaseCorruption` can happen if all the below conditions hold:
                 //  - There is a file on the filesystem that has the same hash and size as those of
@@ -310,7 +323,7 @@ impl<'db, 'fs, 'mf> Scanner<'db, 'fs, 'mf> {
                         self.update_external(started_at, song_path.id, absolute_path, dir_image_id)
                             .await?;
                         tracing::debug!("stale last_modified");
-                        return Ok(song_path.id);
+                        return Ok((song_path.id, false));
                     }
                 } else {
                     // Since `song_id` is queried only by music folder and relative path and there
@@ -339,7 +352,7 @@ impl<'db, 'fs, 'mf> Scanner<'db, 'fs, 'mf> {
                 tracing::warn!(
                     old = %song_path.relative_path, new = %relative_path, "renamed duplication"
                 );
-                return Ok(song_path.id);
+                return Ok((song_path.id, false));
             }
         } else {
             song_id
@@ -364,37 +377,228 @@ impl<'db, 'fs, 'mf> Scanner<'db, 'fs, 'mf> {
         self.update_external_lyric(None, song_id, absolute_path).await?;
         audio::Information::cleanup_one(database, started_at, song_id).await?;
 
-        Ok(song_id)
+        Ok((song_id, true))
+    }
+
+    async fn measure_loudness_one(&self, song_id: Uuid, relative_path: &str) -> Result<(), Error> {
+        let input =
+            self.filesystem.transcode_input(self.path().join(relative_path).to_path()).await?;
+        let loudness = tokio::task::spawn_blocking(move || {
+            audio::transcode::Loudness::measure(&CString::new(input)?)
+        })
+        .await?;
+        // A song that can not be measured simply falls back to its tags. It is still marked as
+        // measured so it is not decoded again by every scan.
+        if let Err(ref error) = loudness {
+            tracing::warn!(%relative_path, loudness_error = ?error);
+        }
+        let loudness = loudness.ok();
+
+        diesel::update(songs::table)
+            .filter(songs::id.eq(song_id))
+            .set((
+                songs::loudness_integrated.eq(loudness.map(|loudness| loudness.integrated)),
+                songs::loudness_peak.eq(loudness.map(|loudness| loudness.peak)),
+                songs::loudness_measured_at.eq(crate::time::now().await),
+            ))
+            .execute(&mut self.database.get().await?)
+            .await?;
+        Ok(())
+    }
+
+    async fn derive_album_loudness(&self, album_ids: Vec<Uuid>) -> Result<(), Error> {
+        let songs = songs::table
+            .filter(songs::album_id.eq_any(&album_ids))
+            .select((
+                songs::album_id,
+                songs::duration,
+                songs::loudness_integrated,
+                songs::loudness_peak,
+            ))
+            .get_results::<(Uuid, f32, Option<f32>, Option<f32>)>(&mut self.database.get().await?)
+            .await?;
+
+        let mut albums: HashMap<_, Vec<_>> = album_ids.into_iter().map(|id| (id, vec![])).collect();
+        for (album_id, duration, integrated, peak) in songs {
+            albums.entry(album_id).or_default().push(integrated.zip(peak).map(
+                |(integrated, peak)| (duration, audio::transcode::Loudness { integrated, peak }),
+            ));
+        }
+
+        for (album_id, songs) in albums {
+            // The album loudness is only derived if every song of that album is measured.
+            let loudness = songs
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .and_then(audio::transcode::Loudness::album);
+            diesel::update(albums::table)
+                .filter(albums::id.eq(album_id))
+                .set((
+                    albums::loudness_integrated.eq(loudness.map(|loudness| loudness.integrated)),
+                    albums::loudness_peak.eq(loudness.map(|loudness| loudness.peak)),
+                ))
+                .execute(&mut self.database.get().await?)
+                .await?;
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(not(coverage_nightly), instrument(skip_all, err(Debug)))]
+    async fn measure_loudness(&self, started_at: time::OffsetDateTime) -> Result<(), Error> {
+        // Songs are measured if they have never been measured, if they are updated in the current
+        // scan or if the loudness full mode is enabled. Songs with both a track and an album gain
+        // tag are skipped since the measurement would never be used for them, songs with only a
+        // track gain tag are still measured to derive the loudness of their album. Measurements
+        // are only stored in the database and never written back to the files.
+        let mut query = songs::table
+            .inner_join(albums::table)
+            .filter(albums::music_folder_id.eq(self.music_folder.id))
+            .filter(
+                songs::replay_gain_track_gain.is_null().or(songs::replay_gain_album_gain.is_null()),
+            )
+            .select((songs::id, songs::album_id, songs::relative_path))
+            .into_boxed();
+        if !self.full.loudness {
+            query = query
+                .filter(songs::loudness_measured_at.is_null().or(songs::updated_at.ge(started_at)));
+        }
+        let songs: Vec<(Uuid, Uuid, String)> =
+            query.get_results(&mut self.database.get().await?).await?;
+        tracing::info!(n_song = songs.len(), "measuring loudness");
+
+        let permit = Arc::new(Semaphore::const_new(self.config.scan.pool_size));
+        let mut join_set = tokio::task::JoinSet::new();
+        let mut album_ids = vec![];
+        for (song_id, album_id, relative_path) in songs {
+            if self.token.is_cancelled() {
+                break;
+            }
+            album_ids.push(album_id);
+
+            let permit = permit.clone().acquire_owned().await?;
+            let scanner = self.clone().into_owned();
+            join_set.spawn(
+                async move {
+                    let _guard = permit;
+                    if let Err(error) = scanner.measure_loudness_one(song_id, &relative_path).await
+                    {
+                        tracing::warn!(%relative_path, loudness_error = ?error);
+                    }
+                }
+                .instrument(tracing::Span::current()),
+            );
+        }
+        join_set.join_all().await;
+
+        album_ids.sort_unstable();
+        album_ids.dedup();
+        self.derive_album_loudness(album_ids).await
+    }
+
+    // Records the start of a scan, only one scan can run at a time for each music folder.
+    pub async fn start(&self) -> Result<scans::Key, Error> {
+        let started_at = crate::time::now().await;
+        scans::Key::start(
+            &self.database,
+            scans::Key { started_at, music_folder_id: self.music_folder.id },
+        )
+        .await?
+        .ok_or_else(|| error::Kind::ScanAlreadyRunning(self.music_folder.id).into())
     }
 
-    #[cfg_attr(not(coverage_nightly), instrument(skip_all, fields(started_at), err(Debug)))]
     pub async fn run(&self) -> Result<(), Error> {
+        let key = self.start().await?;
+        self.run_started(key).await
+    }
+
+    #[cfg_attr(not(coverage_nightly), instrument(skip_all, fields(started_at), err(Debug)))]
+    pub async fn run_started(&self, key: scans::Key) -> Result<(), Error> {
         let span = tracing::Span::current();
-        let started_at = crate::time::now().await;
+        let started_at = key.started_at;
         span.record("started_at", tracing::field::display(&started_at));
         tracing::info!(music_folder = ?self.music_folder);
 
+        let result = Box::pin(self.run_impl(key, span)).await;
+        // A cancelled scan is not a failure, the music folder can simply be scanned again.
+        let cancelled = matches!(result, Ok(false));
+        let finished = key.finish(&self.database, result.is_err(), cancelled).await;
+        // The error of the scan itself takes priority over the one of recording its end.
+        if let Err(finish_error) = &finished
+            && result.is_err()
+        {
+            tracing::error!(?finish_error, "could not record the end of the scan");
+        }
+        let completed = result?;
+        finished?;
+        if !completed {
+            return error::Kind::ScanCancelled.into();
+        }
+
+        let latency: std::time::Duration =
+            (time::OffsetDateTime::now_utc() - started_at).try_into()?;
+        tracing::info!(took = ?latency);
+        Ok(())
+    }
+
+    // Returns whether the scan is completed or cancelled.
+    async fn run_impl(&self, key: scans::Key, span: tracing::Span) -> Result<bool, Error> {
+        let started_at = key.started_at;
         let (scan_handle, permit, rx) = self.init();
         let mut join_set = tokio::task::JoinSet::new();
+        let mut progress = scans::Progress::default();
 
-        while let Ok(entry) = rx.recv_async().await {
+        while let Some(entry) = tokio::select! {
+            biased;
+            () = self.token.cancelled() => None,
+            entry = rx.recv_async() => entry.ok(),
+        } {
             let permit = permit.clone().acquire_owned().await?;
             let scanner = self.clone().into_owned();
             join_set.spawn(
                 async move {
                     let _guard = permit;
-                    scanner.one(&entry, started_at).await
+                    // Errors of one song are already logged and only counted so they do not stop
+                    // the whole scan.
+                    let result = scanner.one(&entry, started_at).await;
+                    (result.as_ref().is_ok_and(|(_, upserted)| *upserted), result.is_err())
                 }
                 .instrument(span.clone()),
             );
+
+            // The progress is written in batches instead of one update per song.
+            while let Some(result) = join_set.try_join_next() {
+                let (upserted, error) = result?;
+                progress.add(upserted, error);
+            }
+            if progress.scanned >= Self::PROGRESS_BATCH_SIZE {
+                key.progress(&self.database, std::mem::take(&mut progress)).await?;
+            }
         }
 
+        if self.token.is_cancelled() {
+            scan_handle.abort();
+        }
+        // Songs that are being processed are always finished, even if the scan is cancelled, so
+        // that none of them is partially upserted.
         while let Some(result) = join_set.join_next().await {
-            result??;
+            let (upserted, error) = result?;
+            progress.add(upserted, error);
+        }
+        key.progress(&self.database, progress).await?;
+        if self.token.is_cancelled() {
+            // Cleanup is skipped since not every song is scanned.
+            return Ok(false);
         }
         scan_handle.await??;
 
-        audio::Information::cleanup(&self.database, started_at).await?;
+        let deleted =
+            audio::Information::cleanup(&self.database, started_at, self.music_folder.id).await?;
+        key.deleted(&self.database, deleted).await?;
+        directories::Upsert::sync(&self.database, self.music_folder.id).await?;
+
+        if self.config.scan.loudness {
+            self.measure_loudness(started_at).await?;
+        }
 
         self.database.upsert_config(&self.config.index).await?;
         self.informant
@@ -404,22 +608,31 @@ impl<'db, 'fs, 'mf> Scanner<'db, 'fs, 'mf> {
                 self.full.information,
             )
             .await?;
-
-        let latency: std::time::Duration =
-            (time::OffsetDateTime::now_utc() - started_at).try_into()?;
-        tracing::info!(took = ?latency);
-        Ok(())
+        self.informant
+            .search_and_upsert_similar_songs(
+                &self.database,
+                self.music_folder.id,
+                self.full.information,
+                &self.token,
+            )
+            .await?;
+        Ok(true)
     }
 }
 
 #[cfg(test)]
 #[coverage(off)]
 mod tests {
+    use axum::http::StatusCode;
+    use diesel::{ExpressionMethods, QueryDsl, SelectableHelper};
+    use diesel_async::RunQueryDsl;
     use fake::{Fake, Faker};
     use nghe_api::scan;
     use rstest::rstest;
 
+    use super::*;
     use crate::file::audio;
+    use crate::test::filesystem::Trait as _;
     use crate::test::{Mock, mock};
 
     #[rstest]
@@ -478,14 +691,101 @@ mod tests {
         let mut join_set = tokio::task::JoinSet::new();
         for _ in 0..5 {
             let scanner = music_folder.scan(scan::start::Full::default()).into_owned();
-            join_set.spawn(async move { scanner.run().await.unwrap() });
+            join_set.spawn(async move { scanner.run().await });
         }
-        join_set.join_all().await;
+        // Scans that overlap with a running one are rejected.
+        let results = join_set.join_all().await;
+        assert!(results.iter().any(Result::is_ok));
+        assert!(results.iter().all(|result| {
+            result.as_ref().err().is_none_or(|error| error.status_code == StatusCode::CONFLICT)
+        }));
 
         let database_audio = music_folder.query_filesystem().await;
         assert_eq!(database_audio, music_folder.filesystem);
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_scan_record(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio_filesystem::<&str>().n_song(10).call().await;
+
+        // An invalid file is counted as an error but does not stop the scan.
+        music_folder
+            .to_impl()
+            .write(music_folder.absolutize("invalid.flac").to_path(), &vec![0; 200 * 1024])
+            .await;
+        music_folder.remove_audio_filesystem::<&str>().call().await;
+
+        let scans = scans::table
+            .filter(scans::music_folder_id.eq(music_folder.id()))
+            .order_by(scans::started_at)
+            .select(scans::Scan::as_select())
+            .get_results(&mut mock.get().await)
+            .await
+            .unwrap();
+        assert_eq!(scans.len(), 2);
+
+        assert!(!scans[0].is_scanning);
+        assert!(scans[0].finished_at.is_some());
+        assert_eq!(scans[0].unrecoverable, Some(false));
+        assert_eq!(scans[0].scanned_song_count, 10);
+        assert_eq!(scans[0].upserted_song_count, 10);
+        assert_eq!(scans[0].deleted_song_count, 0);
+        assert_eq!(scans[0].scan_error_count, 0);
+
+        assert!(!scans[1].is_scanning);
+        assert_eq!(scans[1].unrecoverable, Some(false));
+        assert_eq!(scans[1].scanned_song_count, 10);
+        assert_eq!(scans[1].upserted_song_count, 0);
+        assert_eq!(scans[1].deleted_song_count, 1);
+        assert_eq!(scans[1].scan_error_count, 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_scan_running(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio_filesystem::<&str>().scan(false).call().await;
+
+        let key =
+            scans::Key { started_at: crate::time::now().await, music_folder_id: music_folder.id() };
+        assert!(scans::Key::start(mock.database(), key).await.unwrap().is_some());
+        music_folder.scan(scan::start::Full::default()).run().await.unwrap();
+        assert!(music_folder.query_filesystem().await.is_empty());
+
+        scans::Key::abort_all(mock.database()).await.unwrap();
+        music_folder.scan(scan::start::Full::default()).run().await.unwrap();
+        assert_eq!(music_folder.query_filesystem().await, music_folder.filesystem);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_cancel(#[future(awt)] mock: Mock) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio_filesystem::<&str>().n_song(10).call().await;
+
+        let scanner = music_folder.scan(scan::start::Full::default());
+        scanner.token.cancel();
+        assert!(scanner.run().await.is_err());
+
+        // Songs that are not scanned are not deleted if the scan is cancelled.
+        let database_audio = music_folder.query_filesystem().await;
+        assert_eq!(database_audio, music_folder.filesystem);
+
+        let scan = scans::table
+            .filter(scans::music_folder_id.eq(music_folder.id()))
+            .order_by(scans::started_at.desc())
+            .select(scans::Scan::as_select())
+            .first(&mut mock.get().await)
+            .await
+            .unwrap();
+        assert!(!scan.is_scanning);
+        assert_eq!(scan.unrecoverable, Some(false));
+        assert!(scan.cancelled);
+        assert_eq!(scan.deleted_song_count, 0);
+    }
+
     mod filesystem {
         use super::*;
 
@@ -663,4 +963,50 @@ mod tests {
             .collect();
         assert!(database_dir_images.windows(2).all(|window| window[0] == window[1]));
     }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_derive_album_loudness(
+        #[future(awt)] mock: Mock,
+        #[values(true, false)] all_measured: bool,
+    ) {
+        let mut music_folder = mock.music_folder(0).await;
+        let album: audio::Album = Faker.fake();
+        music_folder.add_audio().album(album.clone()).n_song(2).call().await;
+        let album_id = album.upsert_mock(&mock, 0).await;
+
+        for (index, duration, integrated, peak) in [(0, 30.0, -10.0, 0.5), (1, 10.0, -20.0, 0.8)] {
+            let measured = all_measured || index == 0;
+            diesel::update(songs::table)
+                .filter(songs::id.eq(music_folder.song_id(index)))
+                .set((
+                    songs::duration.eq(duration),
+                    songs::loudness_integrated.eq(measured.then_some(integrated)),
+                    songs::loudness_peak.eq(measured.then_some(peak)),
+                ))
+                .execute(&mut mock.get().await)
+                .await
+                .unwrap();
+        }
+
+        music_folder
+            .scan(scan::start::Full::default())
+            .derive_album_loudness(vec![album_id])
+            .await
+            .unwrap();
+
+        let (integrated, peak) = albums::table
+            .filter(albums::id.eq(album_id))
+            .select((albums::loudness_integrated, albums::loudness_peak))
+            .get_result::<(Option<f32>, Option<f32>)>(&mut mock.get().await)
+            .await
+            .unwrap();
+        if all_measured {
+            assert!((integrated.unwrap() - -11.107).abs() < 1e-3);
+            assert_eq!(peak, Some(0.8));
+        } else {
+            assert_eq!(integrated, None);
+            assert_eq!(peak, None);
+        }
+    }
 }
@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use tokio::task::JoinSet;
+use tracing::{Instrument, instrument};
+
+use super::{registry, scanner};
+use crate::Error;
+use crate::database::Database;
+use crate::filesystem::Filesystem;
+use crate::integration::Informant;
+use crate::orm::music_folders;
+
+// Schedules can be added or changed at any time so we never sleep longer than this duration.
+const MAXIMUM_SLEEP: Duration = Duration::from_mins(1);
+
+#[derive(Clone)]
+pub struct Scheduler {
+    database: Database,
+    filesystem: Filesystem,
+    config: scanner::Config,
+    informant: Informant,
+    registry: registry::Registry,
+}
+
+impl Scheduler {
+    pub fn new(
+        database: Database,
+        filesystem: Filesystem,
+        config: scanner::Config,
+        informant: Informant,
+        registry: registry::Registry,
+    ) -> Self {
+        Self { database, filesystem, config, informant, registry }
+    }
+
+    #[coverage(off)]
+    pub fn spawn(self) {
+        tokio::spawn(async move {
+            loop {
+                // Errors are already logged by `run` and `sleep`.
+                if let Ok(mut join_set) = self.run().await {
+                    join_set.detach_all();
+                }
+                tokio::time::sleep(self.sleep().await.unwrap_or(MAXIMUM_SLEEP)).await;
+            }
+        });
+    }
+
+    #[cfg_attr(not(coverage_nightly), instrument(skip_all, err(Debug)))]
+    pub async fn run(&self) -> Result<JoinSet<Result<(), Error>>, Error> {
+        let now = crate::time::now().await;
+        let mut join_set = JoinSet::new();
+
+        for scheduled in music_folders::schedule::Scheduled::query_due(&self.database, now).await? {
+            let music_folder_id = scheduled.music_folder.id;
+            // An error of one music folder must not return early since dropping the join set
+            // aborts the scans of the other music folders.
+            let Ok(scanner) = self.prepare(now, scheduled).await.inspect_err(|error| {
+                tracing::error!(%music_folder_id, ?error, "could not start scheduled scan");
+            }) else {
+                continue;
+            };
+
+            // A scheduled scan is skipped if the music folder is already being scanned, it will be
+            // tried again at the next scheduled time.
+            let guard = match self.registry.register(scanner.music_folder.id, scanner.token.clone())
+            {
+                Ok(guard) => guard,
+                Err(error) => {
+                    tracing::warn!(?error, "skip scheduled scan");
+                    continue;
+                }
+            };
+            tracing::info!(%music_folder_id, "start scheduled scan");
+            join_set.spawn(
+                async move {
+                    let _guard = guard;
+                    scanner.run().await
+                }
+                .instrument(tracing::Span::current()),
+            );
+        }
+
+        Ok(join_set)
+    }
+
+    async fn prepare(
+        &self,
+        now: time::OffsetDateTime,
+        scheduled: music_folders::schedule::Scheduled<'static>,
+    ) -> Result<scanner::Scanner<'static, 'static, 'static>, Error> {
+        let music_folder = scheduled.music_folder;
+        let mut schedule = scheduled.schedule;
+        schedule.next_at = schedule.next(now)?;
+        schedule.update(&self.database, music_folder.id).await?;
+
+        Ok(scanner::Scanner::new_orm(
+            &self.database,
+            &self.filesystem,
+            self.config.clone(),
+            self.informant.clone(),
+            music_folder,
+            schedule.full.into(),
+        )?
+        .into_owned())
+    }
+
+    #[cfg_attr(not(coverage_nightly), instrument(skip_all, ret(level = "debug"), err(Debug)))]
+    async fn sleep(&self) -> Result<Duration, Error> {
+        let Some(next_at) =
+            music_folders::schedule::Scheduled::query_next_at(&self.database).await?
+        else {
+            return Ok(MAXIMUM_SLEEP);
+        };
+        let sleep = (next_at - crate::time::now().await).max(time::Duration::ZERO);
+        Ok(Duration::try_from(sleep)?.min(MAXIMUM_SLEEP))
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use diesel::{ExpressionMethods, NullableExpressionMethods, QueryDsl};
+    use diesel_async::RunQueryDsl;
+    use nghe_api::scan;
+    use rstest::rstest;
+    use tokio_util::sync::CancellationToken;
+
+    use super::*;
+    use crate::test::{Mock, mock};
+
+    async fn schedule(mock: &Mock, music_folder_id: uuid::Uuid) {
+        let schedule = scan::set_scan_schedule::Schedule::Interval(3600);
+        let mut schedule = music_folders::schedule::Schedule::new(
+            &schedule,
+            scan::start::Full::default(),
+            crate::time::now().await,
+        )
+        .unwrap();
+        schedule.next_at = Some(crate::time::now().await - time::Duration::SECOND);
+        schedule.update(mock.database(), music_folder_id).await.unwrap();
+    }
+
+    async fn next_at(mock: &Mock, music_folder_id: uuid::Uuid) -> time::OffsetDateTime {
+        music_folders::table
+            .filter(music_folders::id.eq(music_folder_id))
+            .select(music_folders::scan_next_at.assume_not_null())
+            .get_result(&mut mock.get().await)
+            .await
+            .unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_run(#[future(awt)] mock: Mock, #[values(true, false)] running: bool) {
+        let mut music_folder = mock.music_folder(0).await;
+        music_folder.add_audio_filesystem::<&str>().n_song(5).scan(false).call().await;
+        schedule(&mock, music_folder.id()).await;
+
+        let registry = registry::Registry::default();
+        let _guard = running
+            .then(|| registry.register(music_folder.id(), CancellationToken::new()).unwrap());
+        let scheduler = Scheduler::new(
+            mock.database().clone(),
+            mock.filesystem().clone(),
+            mock.config.scanner(),
+            mock.informant.clone(),
+            registry,
+        );
+
+        let now = crate::time::now().await;
+        let join_set = scheduler.run().await.unwrap();
+        assert_eq!(join_set.len(), usize::from(!running));
+        join_set.join_all().await.into_iter().collect::<Result<(), _>>().unwrap();
+        assert!(next_at(&mock, music_folder.id()).await > now);
+
+        let database_audio = music_folder.query_filesystem().await;
+        if running {
+            assert!(database_audio.is_empty());
+        } else {
+            assert_eq!(database_audio, music_folder.filesystem);
+        }
+
+        // The music folder is not due anymore.
+        assert!(scheduler.run().await.unwrap().is_empty());
+    }
+}
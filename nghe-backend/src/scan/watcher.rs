@@ -0,0 +1,323 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use indexmap::IndexSet;
+use nghe_api::scan;
+use notify_debouncer_full::notify::RecursiveMode;
+use notify_debouncer_full::{DebounceEventResult, new_debouncer};
+use tracing::instrument;
+use typed_path::Utf8TypedPathBuf;
+
+use super::registry::Registry;
+use super::scanner::{self, Scanner};
+use crate::database::Database;
+use crate::file::audio;
+use crate::filesystem::{Filesystem, Trait as _, entry, path};
+use crate::integration::Informant;
+use crate::orm::{directories, music_folders};
+use crate::{Error, config};
+
+#[derive(Clone)]
+pub struct Watcher {
+    database: Database,
+    filesystem: Filesystem,
+    config: scanner::Config,
+    informant: Informant,
+    registry: Registry,
+    // Changes of music folders that are being scanned are sent here again after a delay.
+    deferred: (loole::Sender<Vec<PathBuf>>, loole::Receiver<Vec<PathBuf>>),
+}
+
+// Sends the paths of music folders that are added after the watcher is spawned. It does nothing if
+// watching is disabled.
+#[derive(Debug, Clone, Default)]
+pub struct Subscriber {
+    tx: Option<loole::Sender<String>>,
+}
+
+impl Subscriber {
+    pub fn subscribe(&self, path: String) {
+        if let Some(tx) = &self.tx {
+            // The receiver lives as long as the server so sending can not fail.
+            let _ = tx.send(path);
+        }
+    }
+}
+
+impl Watcher {
+    const DEFER: Duration = Duration::from_secs(1);
+
+    pub fn new(
+        database: Database,
+        filesystem: Filesystem,
+        config: scanner::Config,
+        informant: Informant,
+        registry: Registry,
+    ) -> Self {
+        Self { database, filesystem, config, informant, registry, deferred: loole::unbounded() }
+    }
+
+    #[coverage(off)]
+    pub async fn spawn(self, config: config::filesystem::Watch) -> Result<Subscriber, Error> {
+        let (tx, rx) = loole::unbounded::<DebounceEventResult>();
+        let mut debouncer =
+            new_debouncer(Duration::from_millis(config.debounce), None, move |result| {
+                // The receiver lives as long as the server so sending can not fail.
+                let _ = tx.send(result);
+            })?;
+
+        for music_folder in music_folders::MusicFolder::query_local(&self.database).await? {
+            debouncer.watch(music_folder.data.path.as_str(), RecursiveMode::Recursive)?;
+            tracing::info!(watching = ?music_folder);
+        }
+
+        let (subscribe_tx, subscribe_rx) = loole::unbounded::<String>();
+        let deferred_rx = self.deferred.1.clone();
+        tokio::spawn(async move {
+            // Dropping the debouncer stops all the watches.
+            let mut debouncer = debouncer;
+            loop {
+                tokio::select! {
+                    result = rx.recv_async() => match result {
+                        Ok(Ok(events)) => {
+                            let paths = events
+                                .into_iter()
+                                .filter(|event| !event.kind.is_access())
+                                .flat_map(|event| event.event.paths);
+                            // Errors are already logged by `process`.
+                            let _ = Box::pin(self.process(paths)).await;
+                        }
+                        Ok(Err(errors)) => tracing::error!(watch_errors = ?errors),
+                        Err(_) => break,
+                    },
+                    Ok(paths) = deferred_rx.recv_async() => {
+                        let _ = Box::pin(self.process(paths)).await;
+                    }
+                    Ok(path) = subscribe_rx.recv_async() => {
+                        match debouncer.watch(path.as_str(), RecursiveMode::Recursive) {
+                            Ok(()) => tracing::info!(watching = %path),
+                            Err(error) => tracing::error!(%path, watch_error = ?error),
+                        }
+                    }
+                }
+            }
+        });
+        Ok(Subscriber { tx: Some(subscribe_tx) })
+    }
+
+    #[cfg_attr(not(coverage_nightly), instrument(skip_all, err(Debug)))]
+    pub async fn process(&self, paths: impl IntoIterator<Item = PathBuf>) -> Result<(), Error> {
+        let paths: IndexSet<_> = paths
+            .into_iter()
+            .filter_map(|path| match path.into_os_string().into_string() {
+                Ok(path) => Some(path::Local::from_string(path)),
+                Err(path) => {
+                    tracing::error!(watch_non_utf8_path = ?path);
+                    None
+                }
+            })
+            .collect();
+
+        for music_folder in music_folders::MusicFolder::query_local(&self.database).await? {
+            let paths: Vec<_> = paths
+                .iter()
+                .filter(|path| path.starts_with(music_folder.data.path.as_str()))
+                .collect();
+            if !paths.is_empty() {
+                // Errors are already logged by `process_music_folder` and should not stop the
+                // other music folders.
+                let _ = self.process_music_folder(music_folder, paths).await;
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(
+        not(coverage_nightly),
+        instrument(skip_all, fields(music_folder_id = %music_folder.id), err(Debug))
+    )]
+    async fn process_music_folder(
+        &self,
+        music_folder: music_folders::MusicFolder<'static>,
+        paths: Vec<&Utf8TypedPathBuf>,
+    ) -> Result<(), Error> {
+        let started_at = crate::time::now().await;
+        let music_folder_id = music_folder.id;
+        let scanner = Scanner::new_orm(
+            &self.database,
+            &self.filesystem,
+            self.config.clone(),
+            self.informant.clone(),
+            music_folder,
+            scan::start::Full::default(),
+        )?;
+        // Changes are registered like any other scan so they never run alongside another scan of
+        // the same music folder, they are deferred until that music folder is no longer scanned.
+        let Ok(_guard) = self.registry.register(music_folder_id, scanner.token.clone()) else {
+            tracing::info!("music folder is being scanned, defer changes");
+            let tx = self.deferred.0.clone();
+            let paths = paths.into_iter().map(|path| PathBuf::from(path.as_str())).collect();
+            tokio::spawn(async move {
+                tokio::time::sleep(Self::DEFER).await;
+                // The receiver lives as long as the watcher so sending can not fail.
+                let _ = tx.send(paths);
+            });
+            return Ok(());
+        };
+
+        // Paths that still exist are either created, modified or the destination of a rename and
+        // the others are either deleted or the source of a rename.
+        let (tx, rx) = loole::unbounded();
+        let sender =
+            || entry::Sender { tx: tx.clone(), minimum_size: self.config.scan.minimum_size };
+        let mut removed = vec![];
+        for path in paths {
+            match tokio::fs::metadata(path.as_str()).await {
+                Ok(metadata) if metadata.is_dir() => {
+                    scanner.filesystem.scan_folder(sender(), path.to_path()).await?;
+                }
+                Ok(metadata) => sender().send(path.clone(), &metadata).await?,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                    removed.push(
+                        path.strip_prefix(scanner.music_folder.data.path.as_str())?.to_string(),
+                    );
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+        drop(tx);
+
+        // Entries are upserted before removing so a renamed song keeps its id.
+        let mut upserted = false;
+        for entry in rx.drain() {
+            if scanner.token.is_cancelled() {
+                break;
+            }
+            // Errors of one song are already logged so they do not stop the others.
+            if let Ok((_, true)) = Box::pin(scanner.one(&entry, started_at)).await {
+                upserted = true;
+            }
+        }
+
+        let deleted =
+            audio::Information::cleanup_paths(&self.database, scanner.music_folder.id, &removed)
+                .await?;
+        tracing::info!(upserted, ?deleted);
+        if upserted || deleted.song > 0 {
+            directories::Upsert::sync(&self.database, scanner.music_folder.id).await?;
+        }
+
+        if upserted {
+            self.informant
+                .search_and_upsert_artists(&self.database, &self.config.cover_art, false)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[coverage(off)]
+mod tests {
+    use nghe_api::common::filesystem;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test::{Mock, mock};
+
+    fn watcher(mock: &Mock) -> Watcher {
+        Watcher::new(
+            mock.database().clone(),
+            mock.filesystem().clone(),
+            mock.config.scanner(),
+            mock.informant.clone(),
+            Registry::default(),
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_process(
+        #[future(awt)]
+        #[with(0, 0)]
+        mock: Mock,
+    ) {
+        mock.add_music_folder().ty(filesystem::Type::Local).call().await;
+        let mut music_folder = mock.music_folder(0).await;
+        let watcher = watcher(&mock);
+
+        music_folder.add_audio_filesystem::<&str>().n_song(5).scan(false).call().await;
+        let paths = (0..5).map(|index| music_folder.absolute_path(index).as_str().into());
+        watcher.process(paths).await.unwrap();
+        let database_audio = music_folder.query_filesystem().await;
+        assert_eq!(database_audio, music_folder.filesystem);
+
+        let path = music_folder.absolute_path(0);
+        music_folder.remove_audio_filesystem::<&str>().scan(false).call().await;
+        watcher.process([path.as_str().into()]).await.unwrap();
+        let database_audio = music_folder.query_filesystem().await;
+        assert_eq!(database_audio, music_folder.filesystem);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_process_directory(
+        #[future(awt)]
+        #[with(0, 0)]
+        mock: Mock,
+    ) {
+        mock.add_music_folder().ty(filesystem::Type::Local).call().await;
+        let mut music_folder = mock.music_folder(0).await;
+        let watcher = watcher(&mock);
+
+        let directory = music_folder.path().join("directory");
+        for index in 0..3 {
+            music_folder
+                .add_audio_filesystem()
+                .path(directory.join(index.to_string()).as_str())
+                .scan(false)
+                .call()
+                .await;
+        }
+        watcher.process([directory.as_str().into()]).await.unwrap();
+        let database_audio = music_folder.query_filesystem().await;
+        assert_eq!(database_audio, music_folder.filesystem);
+
+        tokio::fs::remove_dir_all(directory.as_str()).await.unwrap();
+        watcher.process([directory.as_str().into()]).await.unwrap();
+        assert!(music_folder.query_filesystem().await.is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_process_registered(
+        #[future(awt)]
+        #[with(0, 0)]
+        mock: Mock,
+    ) {
+        mock.add_music_folder().ty(filesystem::Type::Local).call().await;
+        let mut music_folder = mock.music_folder(0).await;
+        let watcher = watcher(&mock);
+        let guard = watcher
+            .registry
+            .register(music_folder.id(), tokio_util::sync::CancellationToken::new())
+            .unwrap();
+
+        // Changes are deferred while the music folder is being scanned.
+        music_folder.add_audio_filesystem::<&str>().n_song(2).scan(false).call().await;
+        let paths = (0..2).map(|index| music_folder.absolute_path(index).as_str().into());
+        watcher.process(paths).await.unwrap();
+        assert!(music_folder.query_filesystem().await.is_empty());
+
+        // They are sent again and processed once the scan is finished.
+        let deferred = watcher.deferred.1.recv_async().await.unwrap();
+        watcher.process(deferred).await.unwrap();
+        assert!(music_folder.query_filesystem().await.is_empty());
+
+        drop(guard);
+        let deferred = watcher.deferred.1.recv_async().await.unwrap();
+        watcher.process(deferred).await.unwrap();
+        let database_audio = music_folder.query_filesystem().await;
+        assert_eq!(database_audio, music_folder.filesystem);
+    }
+}
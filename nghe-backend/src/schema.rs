@@ -23,6 +23,13 @@ diesel::table! {
         ts -> Tsvector,
         music_folder_id -> Uuid,
         cover_art_id -> Nullable<Uuid>,
+        loudness_integrated -> Nullable<Float4>,
+        loudness_peak -> Nullable<Float4>,
+        sort_name -> Nullable<Text>,
+        release_types -> Array<Nullable<Text>>,
+        record_labels -> Array<Nullable<Text>>,
+        catalog_number -> Nullable<Text>,
+        barcode -> Nullable<Text>,
     }
 }
 
@@ -53,6 +60,21 @@ diesel::table! {
         scanned_at -> Timestamptz,
         mbz_id -> Nullable<Uuid>,
         ts -> Tsvector,
+        sort_name -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    bookmarks (user_id, song_id) {
+        user_id -> Uuid,
+        song_id -> Uuid,
+        position -> Int8,
+        comment -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -83,6 +105,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    directories (id) {
+        id -> Uuid,
+        music_folder_id -> Uuid,
+        parent_id -> Uuid,
+        path -> Text,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use diesel_full_text_search::*;
@@ -94,6 +128,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    internet_radio_stations (id) {
+        id -> Uuid,
+        name -> Text,
+        stream_url -> Text,
+        homepage_url -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use diesel_full_text_search::*;
@@ -123,6 +171,27 @@ diesel::table! {
         updated_at -> Timestamptz,
         fs_type -> Int2,
         created_at -> Timestamptz,
+        scan_cron -> Nullable<Text>,
+        scan_interval -> Nullable<Int8>,
+        scan_full_file -> Bool,
+        scan_full_external_lyric -> Bool,
+        scan_full_dir_image -> Bool,
+        scan_full_information -> Bool,
+        scan_next_at -> Nullable<Timestamptz>,
+        scan_full_loudness -> Bool,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    now_playings (user_id, client) {
+        user_id -> Uuid,
+        client -> Text,
+        player_id -> Int4,
+        song_id -> Uuid,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -152,6 +221,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    playlists_rules (playlist_id) {
+        playlist_id -> Uuid,
+        genres -> Array<Nullable<Text>>,
+        artists -> Array<Nullable<Text>>,
+        formats -> Array<Nullable<Text>>,
+        from_year -> Nullable<Int2>,
+        to_year -> Nullable<Int2>,
+        min_bit_depth -> Nullable<Int2>,
+        max_bit_depth -> Nullable<Int2>,
+        min_play_count -> Nullable<Int4>,
+        max_play_count -> Nullable<Int4>,
+        played_within_days -> Nullable<Int4>,
+        not_played_within_days -> Nullable<Int4>,
+        starred -> Nullable<Bool>,
+        min_rating -> Nullable<Int2>,
+        added_within_days -> Nullable<Int4>,
+        sort -> Int2,
+        descending -> Bool,
+        song_limit -> Nullable<Int4>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use diesel_full_text_search::*;
@@ -182,8 +277,101 @@ diesel::table! {
     playqueues (user_id) {
         user_id -> Uuid,
         ids -> Array<Nullable<Uuid>>,
-        current -> Nullable<Uuid>,
         position -> Nullable<Int8>,
+        current_index -> Nullable<Int4>,
+        changed_by -> Nullable<Text>,
+        changed_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    plays (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        song_id -> Uuid,
+        played_at -> Timestamptz,
+        client -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    podcast_channels (id) {
+        id -> Uuid,
+        url -> Text,
+        title -> Nullable<Text>,
+        description -> Nullable<Text>,
+        image_url -> Nullable<Text>,
+        status -> Int2,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    podcast_episodes (id) {
+        id -> Uuid,
+        channel_id -> Uuid,
+        guid -> Text,
+        title -> Text,
+        description -> Nullable<Text>,
+        url -> Text,
+        published_at -> Nullable<Timestamptz>,
+        status -> Int2,
+        error_message -> Nullable<Text>,
+        path -> Nullable<Text>,
+        duration -> Nullable<Float4>,
+        bitrate -> Nullable<Int4>,
+        file_hash -> Nullable<Int8>,
+        file_size -> Nullable<Int4>,
+        format -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    rating_albums (user_id, album_id) {
+        user_id -> Uuid,
+        album_id -> Uuid,
+        rating -> Int2,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    rating_artists (user_id, artist_id) {
+        user_id -> Uuid,
+        artist_id -> Uuid,
+        rating -> Int2,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    rating_songs (user_id, song_id) {
+        user_id -> Uuid,
+        song_id -> Uuid,
+        rating -> Int2,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -204,6 +392,63 @@ diesel::table! {
         deleted_genre_count -> Int8,
         scan_error_count -> Int8,
         unrecoverable -> Nullable<Bool>,
+        cancelled -> Bool,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    scrobble_retries (play_id, service) {
+        play_id -> Uuid,
+        service -> Int2,
+        attempt -> Int4,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    shares (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        description -> Nullable<Text>,
+        song_ids -> Array<Nullable<Uuid>>,
+        album_ids -> Array<Nullable<Uuid>>,
+        playlist_ids -> Array<Nullable<Uuid>>,
+        visit_count -> Int4,
+        expires_at -> Nullable<Timestamptz>,
+        last_visited_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    similar_artists (artist_id, name) {
+        artist_id -> Uuid,
+        name -> Text,
+        mbz_id -> Nullable<Uuid>,
+        score -> Float4,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    similar_songs (song_id, name, artist_name) {
+        song_id -> Uuid,
+        name -> Text,
+        artist_name -> Text,
+        score -> Float4,
+        created_at -> Timestamptz,
     }
 }
 
@@ -244,6 +489,15 @@ diesel::table! {
         mbz_id -> Nullable<Uuid>,
         ts -> Tsvector,
         bit_depth -> Nullable<Int2>,
+        replay_gain_track_gain -> Nullable<Float4>,
+        replay_gain_track_peak -> Nullable<Float4>,
+        replay_gain_album_gain -> Nullable<Float4>,
+        replay_gain_album_peak -> Nullable<Float4>,
+        loudness_integrated -> Nullable<Float4>,
+        loudness_peak -> Nullable<Float4>,
+        sort_name -> Nullable<Text>,
+        similar_songs_fetched_at -> Nullable<Timestamptz>,
+        loudness_measured_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -270,6 +524,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    songs_contributors (song_id, artist_id, role, sub_role) {
+        song_id -> Uuid,
+        artist_id -> Uuid,
+        role -> Int2,
+        sub_role -> Text,
+        upserted_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use diesel_full_text_search::*;
@@ -337,6 +604,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use diesel_full_text_search::*;
+
+    user_scrobblers (user_id, service) {
+        user_id -> Uuid,
+        service -> Int2,
+        username -> Text,
+        token -> Bytea,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use diesel_full_text_search::*;
@@ -356,21 +636,42 @@ diesel::joinable!(albums -> cover_arts (cover_art_id));
 diesel::joinable!(albums -> music_folders (music_folder_id));
 diesel::joinable!(artist_informations -> artists (artist_id));
 diesel::joinable!(artist_informations -> cover_arts (cover_art_id));
+diesel::joinable!(bookmarks -> songs (song_id));
+diesel::joinable!(bookmarks -> users (user_id));
+diesel::joinable!(directories -> music_folders (music_folder_id));
 diesel::joinable!(lyrics -> songs (song_id));
+diesel::joinable!(now_playings -> songs (song_id));
+diesel::joinable!(now_playings -> users (user_id));
 diesel::joinable!(playbacks -> songs (song_id));
 diesel::joinable!(playbacks -> users (user_id));
+diesel::joinable!(playlists_rules -> playlists (playlist_id));
 diesel::joinable!(playlists_songs -> playlists (playlist_id));
 diesel::joinable!(playlists_songs -> songs (song_id));
 diesel::joinable!(playlists_users -> playlists (playlist_id));
 diesel::joinable!(playlists_users -> users (user_id));
 diesel::joinable!(playqueues -> users (user_id));
+diesel::joinable!(plays -> songs (song_id));
+diesel::joinable!(plays -> users (user_id));
+diesel::joinable!(podcast_episodes -> podcast_channels (channel_id));
+diesel::joinable!(rating_albums -> albums (album_id));
+diesel::joinable!(rating_albums -> users (user_id));
+diesel::joinable!(rating_artists -> artists (artist_id));
+diesel::joinable!(rating_artists -> users (user_id));
+diesel::joinable!(rating_songs -> songs (song_id));
+diesel::joinable!(rating_songs -> users (user_id));
 diesel::joinable!(scans -> music_folders (music_folder_id));
+diesel::joinable!(scrobble_retries -> plays (play_id));
+diesel::joinable!(shares -> users (user_id));
+diesel::joinable!(similar_artists -> artists (artist_id));
+diesel::joinable!(similar_songs -> songs (song_id));
 diesel::joinable!(songs -> albums (album_id));
 diesel::joinable!(songs -> cover_arts (cover_art_id));
 diesel::joinable!(songs_album_artists -> artists (album_artist_id));
 diesel::joinable!(songs_album_artists -> songs (song_id));
 diesel::joinable!(songs_artists -> artists (artist_id));
 diesel::joinable!(songs_artists -> songs (song_id));
+diesel::joinable!(songs_contributors -> artists (artist_id));
+diesel::joinable!(songs_contributors -> songs (song_id));
 diesel::joinable!(songs_genres -> genres (genre_id));
 diesel::joinable!(songs_genres -> songs (song_id));
 diesel::joinable!(star_albums -> albums (album_id));
@@ -382,30 +683,48 @@ diesel::joinable!(star_songs -> users (user_id));
 diesel::joinable!(user_keys -> users (user_id));
 diesel::joinable!(user_music_folder_permissions -> music_folders (music_folder_id));
 diesel::joinable!(user_music_folder_permissions -> users (user_id));
+diesel::joinable!(user_scrobblers -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     albums,
     artist_informations,
     artists,
+    bookmarks,
     configs,
     cover_arts,
+    directories,
     genres,
+    internet_radio_stations,
     lyrics,
     music_folders,
+    now_playings,
     playbacks,
     playlists,
+    playlists_rules,
     playlists_songs,
     playlists_users,
     playqueues,
+    plays,
+    podcast_channels,
+    podcast_episodes,
+    rating_albums,
+    rating_artists,
+    rating_songs,
     scans,
+    scrobble_retries,
+    shares,
+    similar_artists,
+    similar_songs,
     songs,
     songs_album_artists,
     songs_artists,
+    songs_contributors,
     songs_genres,
     star_albums,
     star_artists,
     star_songs,
     user_keys,
     user_music_folder_permissions,
+    user_scrobblers,
     users,
 );
@@ -1,11 +1,14 @@
 use lofty::flac::FlacFile;
 use lofty::id3::v2::Id3v2Tag;
+use lofty::mp4::{Ilst, Mp4File};
 use lofty::mpeg::MpegFile;
-use lofty::ogg::{OggPictureStorage as _, VorbisComments};
+use lofty::ogg::{OggPictureStorage as _, OpusFile, VorbisComments, VorbisFile};
 
 use super::Metadata;
 use crate::config;
-use crate::file::audio::{Album, Artists, Genres, NameDateMbz, TrackDisc};
+use crate::file::audio::{
+    Artists, Contributors, Genres, NameDateMbz, Release, ReplayGain, TrackDisc,
+};
 use crate::file::image::Image;
 use crate::file::lyric::Lyric;
 
@@ -20,16 +23,30 @@ default impl<T: TagMut> Metadata for T {
         self
     }
 
-    fn dump_album(&mut self, config: &config::Parsing, album: Album<'_>) -> &mut Self {
+    fn dump_album(&mut self, config: &config::Parsing, album: NameDateMbz<'_>) -> &mut Self {
         self.tag_mut().dump_album(config, album);
         self
     }
 
+    fn dump_release(&mut self, config: &config::Parsing, release: Release<'_>) -> &mut Self {
+        self.tag_mut().dump_release(config, release);
+        self
+    }
+
     fn dump_artists(&mut self, config: &config::Parsing, artists: Artists<'_>) -> &mut Self {
         self.tag_mut().dump_artists(config, artists);
         self
     }
 
+    fn dump_contributors(
+        &mut self,
+        config: &config::Parsing,
+        contributors: Contributors<'_>,
+    ) -> &mut Self {
+        self.tag_mut().dump_contributors(config, contributors);
+        self
+    }
+
     fn dump_track_disc(&mut self, config: &config::Parsing, track_disc: TrackDisc) -> &mut Self {
         self.tag_mut().dump_track_disc(config, track_disc);
         self
@@ -44,6 +61,11 @@ default impl<T: TagMut> Metadata for T {
         self
     }
 
+    fn dump_replay_gain(&mut self, config: &config::Parsing, replay_gain: ReplayGain) -> &mut Self {
+        self.tag_mut().dump_replay_gain(config, replay_gain);
+        self
+    }
+
     fn dump_genres(&mut self, config: &config::Parsing, genres: Genres<'_>) -> &mut Self {
         self.tag_mut().dump_genres(config, genres);
         self
@@ -84,3 +106,30 @@ impl TagMut for MpegFile {
 }
 
 impl Metadata for MpegFile {}
+
+impl TagMut for VorbisFile {
+    type Tag = VorbisComments;
+    fn tag_mut(&mut self) -> &mut VorbisComments {
+        self.vorbis_comments_mut()
+    }
+}
+
+impl Metadata for VorbisFile {}
+
+impl TagMut for OpusFile {
+    type Tag = VorbisComments;
+    fn tag_mut(&mut self) -> &mut VorbisComments {
+        self.vorbis_comments_mut()
+    }
+}
+
+impl Metadata for OpusFile {}
+
+impl TagMut for Mp4File {
+    type Tag = Ilst;
+    fn tag_mut(&mut self) -> &mut Ilst {
+        self.ilst_mut().unwrap()
+    }
+}
+
+impl Metadata for Mp4File {}
@@ -4,16 +4,25 @@ mod tag;
 use isolang::Language;
 
 use crate::config;
-use crate::file::audio::{self, Album, Artists, File, Genres, NameDateMbz, TrackDisc};
+use crate::file::audio::{
+    self, Artists, Contributors, File, Genres, NameDateMbz, Release, ReplayGain, TrackDisc,
+};
 use crate::file::image::Image;
 use crate::file::lyric::Lyric;
 
 pub trait Metadata {
     fn dump_song(&mut self, config: &config::Parsing, song: NameDateMbz<'_>) -> &mut Self;
-    fn dump_album(&mut self, config: &config::Parsing, album: Album<'_>) -> &mut Self;
+    fn dump_album(&mut self, config: &config::Parsing, album: NameDateMbz<'_>) -> &mut Self;
+    fn dump_release(&mut self, config: &config::Parsing, release: Release<'_>) -> &mut Self;
     fn dump_artists(&mut self, config: &config::Parsing, artists: Artists<'_>) -> &mut Self;
+    fn dump_contributors(
+        &mut self,
+        config: &config::Parsing,
+        contributors: Contributors<'_>,
+    ) -> &mut Self;
     fn dump_track_disc(&mut self, config: &config::Parsing, track_disc: TrackDisc) -> &mut Self;
     fn dump_languages(&mut self, config: &config::Parsing, languages: Vec<Language>) -> &mut Self;
+    fn dump_replay_gain(&mut self, config: &config::Parsing, replay_gain: ReplayGain) -> &mut Self;
     fn dump_genres(&mut self, config: &config::Parsing, genres: Genres<'_>) -> &mut Self;
     fn dump_lyrics(&mut self, config: &config::Parsing, lyrics: Vec<Lyric<'_>>) -> &mut Self;
     fn dump_image(&mut self, image: Option<Image<'_>>) -> &mut Self;
@@ -23,13 +32,18 @@ pub trait Metadata {
         config: &config::Parsing,
         metadata: audio::Metadata<'_>,
     ) -> &mut Self {
-        let audio::Metadata { song, album, artists, genres, lyrics, image } = metadata;
-        let audio::Song { main, track_disc, languages } = song;
+        let audio::Metadata { song, album, artists, contributors, genres, lyrics, image } =
+            metadata;
+        let audio::Song { main, track_disc, languages, replay_gain } = song;
+        let audio::Album { main: album, release } = album;
         self.dump_song(config, main)
             .dump_album(config, album)
+            .dump_release(config, release)
             .dump_artists(config, artists)
+            .dump_contributors(config, contributors)
             .dump_track_disc(config, track_disc)
             .dump_languages(config, languages)
+            .dump_replay_gain(config, replay_gain)
             .dump_genres(config, genres)
             .dump_lyrics(config, lyrics)
             .dump_image(image)
@@ -45,11 +59,20 @@ impl Metadata for File {
             File::Mpeg { audio, .. } => {
                 audio.dump_song(config, song);
             }
+            File::Vorbis { audio, .. } => {
+                audio.dump_song(config, song);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_song(config, song);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_song(config, song);
+            }
         }
         self
     }
 
-    fn dump_album(&mut self, config: &config::Parsing, album: Album<'_>) -> &mut Self {
+    fn dump_album(&mut self, config: &config::Parsing, album: NameDateMbz<'_>) -> &mut Self {
         match self {
             File::Flac { audio, .. } => {
                 audio.dump_album(config, album);
@@ -57,6 +80,36 @@ impl Metadata for File {
             File::Mpeg { audio, .. } => {
                 audio.dump_album(config, album);
             }
+            File::Vorbis { audio, .. } => {
+                audio.dump_album(config, album);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_album(config, album);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_album(config, album);
+            }
+        }
+        self
+    }
+
+    fn dump_release(&mut self, config: &config::Parsing, release: Release<'_>) -> &mut Self {
+        match self {
+            File::Flac { audio, .. } => {
+                audio.dump_release(config, release);
+            }
+            File::Mpeg { audio, .. } => {
+                audio.dump_release(config, release);
+            }
+            File::Vorbis { audio, .. } => {
+                audio.dump_release(config, release);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_release(config, release);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_release(config, release);
+            }
         }
         self
     }
@@ -69,6 +122,40 @@ impl Metadata for File {
             File::Mpeg { audio, .. } => {
                 audio.dump_artists(config, artists);
             }
+            File::Vorbis { audio, .. } => {
+                audio.dump_artists(config, artists);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_artists(config, artists);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_artists(config, artists);
+            }
+        }
+        self
+    }
+
+    fn dump_contributors(
+        &mut self,
+        config: &config::Parsing,
+        contributors: Contributors<'_>,
+    ) -> &mut Self {
+        match self {
+            File::Flac { audio, .. } => {
+                audio.dump_contributors(config, contributors);
+            }
+            File::Mpeg { audio, .. } => {
+                audio.dump_contributors(config, contributors);
+            }
+            File::Vorbis { audio, .. } => {
+                audio.dump_contributors(config, contributors);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_contributors(config, contributors);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_contributors(config, contributors);
+            }
         }
         self
     }
@@ -81,6 +168,15 @@ impl Metadata for File {
             File::Mpeg { audio, .. } => {
                 audio.dump_track_disc(config, track_disc);
             }
+            File::Vorbis { audio, .. } => {
+                audio.dump_track_disc(config, track_disc);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_track_disc(config, track_disc);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_track_disc(config, track_disc);
+            }
         }
         self
     }
@@ -93,6 +189,36 @@ impl Metadata for File {
             File::Mpeg { audio, .. } => {
                 audio.dump_languages(config, languages);
             }
+            File::Vorbis { audio, .. } => {
+                audio.dump_languages(config, languages);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_languages(config, languages);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_languages(config, languages);
+            }
+        }
+        self
+    }
+
+    fn dump_replay_gain(&mut self, config: &config::Parsing, replay_gain: ReplayGain) -> &mut Self {
+        match self {
+            File::Flac { audio, .. } => {
+                audio.dump_replay_gain(config, replay_gain);
+            }
+            File::Mpeg { audio, .. } => {
+                audio.dump_replay_gain(config, replay_gain);
+            }
+            File::Vorbis { audio, .. } => {
+                audio.dump_replay_gain(config, replay_gain);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_replay_gain(config, replay_gain);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_replay_gain(config, replay_gain);
+            }
         }
         self
     }
@@ -105,6 +231,15 @@ impl Metadata for File {
             File::Mpeg { audio, .. } => {
                 audio.dump_genres(config, genres);
             }
+            File::Vorbis { audio, .. } => {
+                audio.dump_genres(config, genres);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_genres(config, genres);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_genres(config, genres);
+            }
         }
         self
     }
@@ -117,6 +252,15 @@ impl Metadata for File {
             File::Mpeg { audio, .. } => {
                 audio.dump_lyrics(config, lyrics);
             }
+            File::Vorbis { audio, .. } => {
+                audio.dump_lyrics(config, lyrics);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_lyrics(config, lyrics);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_lyrics(config, lyrics);
+            }
         }
         self
     }
@@ -129,6 +273,15 @@ impl Metadata for File {
             File::Mpeg { audio, .. } => {
                 audio.dump_image(image);
             }
+            File::Vorbis { audio, .. } => {
+                audio.dump_image(image);
+            }
+            File::Opus { audio, .. } => {
+                audio.dump_image(image);
+            }
+            File::Mp4 { audio, .. } => {
+                audio.dump_image(image);
+            }
         }
         self
     }
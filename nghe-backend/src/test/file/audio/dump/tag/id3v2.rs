@@ -3,13 +3,16 @@ use indexmap::IndexSet;
 use isolang::Language;
 use itertools::Itertools;
 use lofty::TextEncoding;
-use lofty::id3::v2::{Id3v2Tag, TextInformationFrame, TimestampFrame};
+use lofty::id3::v2::{Frame, Id3v2Tag, KeyValueFrame, TextInformationFrame, TimestampFrame};
 use uuid::Uuid;
 
 use crate::config;
 use crate::config::parsing::id3v2::frame;
 use crate::file::audio::position::Position;
-use crate::file::audio::{Album, Artist, Artists, Date, Genres, NameDateMbz, TrackDisc};
+use crate::file::audio::{
+    Artist, Artists, Contributors, Date, Genres, NameDateMbz, Release, ReplayGain, TrackDisc,
+    contributor,
+};
 use crate::file::image::Image;
 use crate::file::lyric::Lyric;
 use crate::test::file::audio::dump;
@@ -20,10 +23,31 @@ fn write_text(tag: &mut Id3v2Tag, frame_id: frame::Id, text: String) {
             tag.insert(TextInformationFrame::new(frame_id, TextEncoding::UTF8, text).into())
         }
         frame::Id::UserText(description) => tag.insert_user_text(description, text),
-        frame::Id::Time(_) => unreachable!(),
+        frame::Id::Time(_) | frame::Id::KeyValue(..) => unreachable!(),
     };
 }
 
+fn write_key_values(
+    tag: &mut Id3v2Tag,
+    frame_id: lofty::id3::v2::FrameId<'static>,
+    pairs: impl Iterator<Item = (String, String)>,
+) {
+    // Multiple roles can share the same key value frame, e.g. `TIPL`. The existing frame is
+    // removed since `insert` only replaces a key value frame with the same content.
+    let mut key_value_pairs =
+        if let Some(Frame::KeyValue(KeyValueFrame { key_value_pairs, .. })) =
+            tag.remove(&frame_id).next()
+        {
+            key_value_pairs
+        } else {
+            vec![]
+        };
+    key_value_pairs.extend(pairs.map(|(key, value)| (key.into(), value.into())));
+    if !key_value_pairs.is_empty() {
+        tag.insert(KeyValueFrame::new(frame_id, TextEncoding::UTF8, key_value_pairs).into());
+    }
+}
+
 fn write_texts(
     tag: &mut Id3v2Tag,
     frame_id: frame::Id,
@@ -56,7 +80,7 @@ impl Date {
 
 impl NameDateMbz<'_> {
     fn dump_id3v2(self, tag: &mut Id3v2Tag, config: config::parsing::id3v2::Common) {
-        let Self { name, date, release_date, original_release_date, mbz_id } = self;
+        let Self { name, date, release_date, original_release_date, mbz_id, sort_name } = self;
         write_text(tag, config.name, name.into_owned());
         date.dump_id3v2(tag, config.date);
         release_date.dump_id3v2(tag, config.release_date);
@@ -64,6 +88,9 @@ impl NameDateMbz<'_> {
         if let Some(mbz_id) = mbz_id {
             write_text(tag, config.mbz_id, mbz_id.to_string());
         }
+        if let Some(sort_name) = sort_name {
+            write_text(tag, config.sort_name, sort_name.into_owned());
+        }
     }
 }
 
@@ -73,6 +100,8 @@ impl Artist<'_> {
         tag: &mut Id3v2Tag,
         config: config::parsing::id3v2::Artist,
     ) {
+        let sort_names: Vec<_> =
+            artists.iter().filter_map(|artist| artist.sort_name.clone()).collect();
         let (names, mbz_ids): (Vec<_>, Vec<_>) = artists
             .into_iter()
             .map(|artist| {
@@ -81,6 +110,7 @@ impl Artist<'_> {
             .collect();
         write_texts(tag, config.name, names.into_iter());
         write_texts(tag, config.mbz_id, mbz_ids.into_iter());
+        write_texts(tag, config.sort_name, sort_names.into_iter());
     }
 }
 
@@ -106,11 +136,29 @@ impl dump::Metadata for Id3v2Tag {
         self
     }
 
-    fn dump_album(&mut self, config: &config::Parsing, album: Album<'_>) -> &mut Self {
+    fn dump_album(&mut self, config: &config::Parsing, album: NameDateMbz<'_>) -> &mut Self {
         album.dump_id3v2(self, config.id3v2.album.clone());
         self
     }
 
+    fn dump_release(&mut self, config: &config::Parsing, release: Release<'_>) -> &mut Self {
+        let config::parsing::id3v2::Release {
+            release_types,
+            record_labels,
+            catalog_number,
+            barcode,
+        } = config.id3v2.release.clone();
+        write_texts(self, release_types, release.release_types.into_iter());
+        write_texts(self, record_labels, release.record_labels.into_iter());
+        if let Some(value) = release.catalog_number {
+            write_text(self, catalog_number, value.into_owned());
+        }
+        if let Some(value) = release.barcode {
+            write_text(self, barcode, value.into_owned());
+        }
+        self
+    }
+
     fn dump_artists(&mut self, config: &config::Parsing, artists: Artists<'_>) -> &mut Self {
         Artist::dump_id3v2(artists.song, self, config.id3v2.artists.song.clone());
         Artist::dump_id3v2(artists.album, self, config.id3v2.artists.album.clone());
@@ -120,6 +168,52 @@ impl dump::Metadata for Id3v2Tag {
         self
     }
 
+    fn dump_contributors(
+        &mut self,
+        config: &config::Parsing,
+        contributors: Contributors<'_>,
+    ) -> &mut Self {
+        let config::parsing::id3v2::Contributors {
+            composer,
+            conductor,
+            performer,
+            lyricist,
+            arranger,
+            producer,
+        } = &config.id3v2.contributors;
+        for (role, frame_id) in [
+            (contributor::Role::Composer, composer),
+            (contributor::Role::Conductor, conductor),
+            (contributor::Role::Performer, performer),
+            (contributor::Role::Lyricist, lyricist),
+            (contributor::Role::Arranger, arranger),
+            (contributor::Role::Producer, producer),
+        ] {
+            let contributors =
+                contributors.value.iter().filter(|contributor| contributor.role == role);
+            match frame_id.clone() {
+                frame::Id::KeyValue(frame_id, key) => write_key_values(
+                    self,
+                    frame_id,
+                    contributors.map(|contributor| {
+                        (
+                            key.clone().unwrap_or_else(|| {
+                                contributor.sub_role.as_deref().unwrap_or_default().to_owned()
+                            }),
+                            contributor.artist.name.to_string(),
+                        )
+                    }),
+                ),
+                frame_id => write_texts(
+                    self,
+                    frame_id,
+                    contributors.map(contributor::Contributor::format_credit),
+                ),
+            }
+        }
+        self
+    }
+
     fn dump_track_disc(&mut self, config: &config::Parsing, track_disc: TrackDisc) -> &mut Self {
         track_disc.track.dump_id3v2(self, config.id3v2.track_disc.track_position.clone());
         track_disc.disc.dump_id3v2(self, config.id3v2.track_disc.disc_position.clone());
@@ -131,6 +225,22 @@ impl dump::Metadata for Id3v2Tag {
         self
     }
 
+    fn dump_replay_gain(&mut self, config: &config::Parsing, replay_gain: ReplayGain) -> &mut Self {
+        let config::parsing::id3v2::ReplayGain { track_gain, track_peak, album_gain, album_peak } =
+            &config.id3v2.replay_gain;
+        for (frame_id, value) in [
+            (track_gain, replay_gain.track_gain.map(ReplayGain::format_gain)),
+            (track_peak, replay_gain.track_peak.map(ReplayGain::format_peak)),
+            (album_gain, replay_gain.album_gain.map(ReplayGain::format_gain)),
+            (album_peak, replay_gain.album_peak.map(ReplayGain::format_peak)),
+        ] {
+            if let Some(value) = value {
+                write_text(self, frame_id.clone(), value);
+            }
+        }
+        self
+    }
+
     fn dump_genres(&mut self, config: &config::Parsing, genres: Genres<'_>) -> &mut Self {
         write_texts(
             self,
@@ -0,0 +1,204 @@
+use indexmap::IndexSet;
+use isolang::Language;
+use lofty::mp4::{Atom, AtomData, Ilst};
+use lofty::picture::Picture;
+use lofty::tag::Accessor;
+use uuid::Uuid;
+
+use crate::config;
+use crate::config::parsing::ilst::atom;
+use crate::file::audio::position::Position;
+use crate::file::audio::{
+    Artist, Artists, Contributors, Date, Genres, NameDateMbz, Release, ReplayGain, TrackDisc,
+    contributor,
+};
+use crate::file::image::{self, Image};
+use crate::file::lyric::Lyric;
+use crate::test::file::audio::dump;
+
+fn push_text(tag: &mut Ilst, atom_id: &atom::Id, text: String) {
+    tag.insert(Atom::new(atom_id.0.clone(), AtomData::UTF8(text)));
+}
+
+impl Date {
+    fn dump_ilst(self, tag: &mut Ilst, atom_id: Option<&atom::Id>) {
+        if let Some(atom_id) = atom_id
+            && self.is_some()
+        {
+            push_text(tag, atom_id, self.to_string());
+        }
+    }
+}
+
+impl NameDateMbz<'_> {
+    fn dump_ilst(self, tag: &mut Ilst, config: &config::parsing::ilst::Common) {
+        let Self { name, date, release_date, original_release_date, mbz_id, sort_name } = self;
+        push_text(tag, &config.name, name.into_owned());
+        date.dump_ilst(tag, config.date.as_ref());
+        release_date.dump_ilst(tag, config.release_date.as_ref());
+        original_release_date.dump_ilst(tag, config.original_release_date.as_ref());
+        if let Some(mbz_id) = mbz_id {
+            push_text(tag, &config.mbz_id, mbz_id.to_string());
+        }
+        if let Some(sort_name) = sort_name {
+            push_text(tag, &config.sort_name, sort_name.into_owned());
+        }
+    }
+}
+
+impl Artist<'_> {
+    fn dump_ilst(artists: IndexSet<Self>, tag: &mut Ilst, config: &config::parsing::ilst::Artist) {
+        for artist in artists {
+            push_text(tag, &config.name, artist.name.into_owned());
+            push_text(tag, &config.mbz_id, artist.mbz_id.unwrap_or(Uuid::nil()).to_string());
+            if let Some(sort_name) = artist.sort_name {
+                push_text(tag, &config.sort_name, sort_name.into_owned());
+            }
+        }
+    }
+}
+
+impl Position {
+    fn dump_ilst(
+        self,
+        tag: &mut Ilst,
+        set_number: fn(&mut Ilst, u32),
+        set_total: fn(&mut Ilst, u32),
+    ) {
+        if let Some(number) = self.number {
+            set_number(tag, number.into());
+        }
+        if let Some(total) = self.total {
+            set_total(tag, total.into());
+        }
+    }
+}
+
+impl dump::Metadata for Ilst {
+    fn dump_song(&mut self, config: &config::Parsing, song: NameDateMbz<'_>) -> &mut Self {
+        song.dump_ilst(self, &config.ilst.song);
+        self
+    }
+
+    fn dump_album(&mut self, config: &config::Parsing, album: NameDateMbz<'_>) -> &mut Self {
+        album.dump_ilst(self, &config.ilst.album);
+        self
+    }
+
+    fn dump_release(&mut self, config: &config::Parsing, release: Release<'_>) -> &mut Self {
+        let config::parsing::ilst::Release {
+            release_types,
+            record_labels,
+            catalog_number,
+            barcode,
+        } = &config.ilst.release;
+        for release_type in release.release_types {
+            push_text(self, release_types, release_type.into_owned());
+        }
+        for record_label in release.record_labels {
+            push_text(self, record_labels, record_label.into_owned());
+        }
+        if let Some(value) = release.catalog_number {
+            push_text(self, catalog_number, value.into_owned());
+        }
+        if let Some(value) = release.barcode {
+            push_text(self, barcode, value.into_owned());
+        }
+        self
+    }
+
+    fn dump_artists(&mut self, config: &config::Parsing, artists: Artists<'_>) -> &mut Self {
+        Artist::dump_ilst(artists.song, self, &config.ilst.artists.song);
+        Artist::dump_ilst(artists.album, self, &config.ilst.artists.album);
+        if artists.compilation {
+            self.set_flag(config.ilst.compilation.0.clone(), true);
+        }
+        self
+    }
+
+    fn dump_contributors(
+        &mut self,
+        config: &config::Parsing,
+        contributors: Contributors<'_>,
+    ) -> &mut Self {
+        let config::parsing::ilst::Contributors {
+            composer,
+            conductor,
+            performer,
+            lyricist,
+            arranger,
+            producer,
+        } = &config.ilst.contributors;
+        for contributor in contributors.value {
+            let atom_id = match contributor.role {
+                contributor::Role::Composer => composer,
+                contributor::Role::Conductor => conductor,
+                contributor::Role::Performer => performer,
+                contributor::Role::Lyricist => lyricist,
+                contributor::Role::Arranger => arranger,
+                contributor::Role::Producer => producer,
+            };
+            push_text(self, atom_id, contributor.format_credit());
+        }
+        self
+    }
+
+    fn dump_track_disc(&mut self, _: &config::Parsing, track_disc: TrackDisc) -> &mut Self {
+        track_disc.track.dump_ilst(self, Ilst::set_track, Ilst::set_track_total);
+        track_disc.disc.dump_ilst(self, Ilst::set_disk, Ilst::set_disk_total);
+        self
+    }
+
+    fn dump_languages(&mut self, config: &config::Parsing, languages: Vec<Language>) -> &mut Self {
+        for language in languages {
+            push_text(self, &config.ilst.languages, language.to_string());
+        }
+        self
+    }
+
+    fn dump_replay_gain(&mut self, config: &config::Parsing, replay_gain: ReplayGain) -> &mut Self {
+        let config::parsing::ilst::ReplayGain { track_gain, track_peak, album_gain, album_peak } =
+            &config.ilst.replay_gain;
+        for (atom_id, value) in [
+            (track_gain, replay_gain.track_gain.map(ReplayGain::format_gain)),
+            (track_peak, replay_gain.track_peak.map(ReplayGain::format_peak)),
+            (album_gain, replay_gain.album_gain.map(ReplayGain::format_gain)),
+            (album_peak, replay_gain.album_peak.map(ReplayGain::format_peak)),
+        ] {
+            if let Some(value) = value {
+                push_text(self, atom_id, value);
+            }
+        }
+        self
+    }
+
+    fn dump_genres(&mut self, config: &config::Parsing, genres: Genres<'_>) -> &mut Self {
+        for genre in genres.value {
+            push_text(self, &config.ilst.genres, genre.value.into_owned());
+        }
+        self
+    }
+
+    fn dump_lyrics(&mut self, config: &config::Parsing, lyrics: Vec<Lyric<'_>>) -> &mut Self {
+        for lyric in lyrics {
+            push_text(
+                self,
+                if lyric.is_sync() { &config.ilst.lyric.sync } else { &config.ilst.lyric.unsync },
+                lyric.to_string(),
+            );
+        }
+        self
+    }
+
+    fn dump_image(&mut self, image: Option<Image<'_>>) -> &mut Self {
+        if let Some(image) = image {
+            self.insert_picture(if image.property.format == image::Format::WebP {
+                // MP4 does not have a data type for WebP so it is stored as implicit data.
+                Picture::unchecked(image.data.into_owned()).build()
+            } else {
+                image.into()
+            });
+        }
+        self
+    }
+}
@@ -5,7 +5,10 @@ use uuid::Uuid;
 
 use crate::config;
 use crate::file::audio::position::Position;
-use crate::file::audio::{Album, Artist, Artists, Date, Genres, NameDateMbz, TrackDisc};
+use crate::file::audio::{
+    Artist, Artists, Contributors, Date, Genres, NameDateMbz, Release, ReplayGain, TrackDisc,
+    contributor,
+};
 use crate::file::image::Image;
 use crate::file::lyric::Lyric;
 use crate::test::file::audio::dump;
@@ -26,7 +29,7 @@ impl NameDateMbz<'_> {
         tag: &mut VorbisComments,
         config: &config::parsing::vorbis_comments::Common,
     ) {
-        let Self { name, date, release_date, original_release_date, mbz_id } = self;
+        let Self { name, date, release_date, original_release_date, mbz_id, sort_name } = self;
         tag.push(config.name.clone(), name.into_owned());
         date.dump_vorbis_comments(tag, config.date.as_deref());
         release_date.dump_vorbis_comments(tag, config.release_date.as_deref());
@@ -34,6 +37,9 @@ impl NameDateMbz<'_> {
         if let Some(mbz_id) = mbz_id {
             tag.push(config.mbz_id.clone(), mbz_id.to_string());
         }
+        if let Some(sort_name) = sort_name {
+            tag.push(config.sort_name.clone(), sort_name.into_owned());
+        }
     }
 }
 
@@ -46,6 +52,9 @@ impl Artist<'_> {
         for artist in artists {
             tag.push(config.name.clone(), artist.name.into_owned());
             tag.push(config.mbz_id.clone(), artist.mbz_id.unwrap_or(Uuid::nil()).to_string());
+            if let Some(sort_name) = artist.sort_name {
+                tag.push(config.sort_name.clone(), sort_name.into_owned());
+            }
         }
     }
 }
@@ -67,11 +76,33 @@ impl dump::Metadata for VorbisComments {
         self
     }
 
-    fn dump_album(&mut self, config: &config::Parsing, album: Album<'_>) -> &mut Self {
+    fn dump_album(&mut self, config: &config::Parsing, album: NameDateMbz<'_>) -> &mut Self {
         album.dump_vorbis_comments(self, &config.vorbis_comments.album);
         self
     }
 
+    fn dump_release(&mut self, config: &config::Parsing, release: Release<'_>) -> &mut Self {
+        let config::parsing::vorbis_comments::Release {
+            release_types,
+            record_labels,
+            catalog_number,
+            barcode,
+        } = &config.vorbis_comments.release;
+        for release_type in release.release_types {
+            self.push(release_types.clone(), release_type.into_owned());
+        }
+        for record_label in release.record_labels {
+            self.push(record_labels.clone(), record_label.into_owned());
+        }
+        if let Some(value) = release.catalog_number {
+            self.push(catalog_number.clone(), value.into_owned());
+        }
+        if let Some(value) = release.barcode {
+            self.push(barcode.clone(), value.into_owned());
+        }
+        self
+    }
+
     fn dump_artists(&mut self, config: &config::Parsing, artists: Artists<'_>) -> &mut Self {
         Artist::dump_vorbis_comments(artists.song, self, &config.vorbis_comments.artists.song);
         Artist::dump_vorbis_comments(artists.album, self, &config.vorbis_comments.artists.album);
@@ -81,6 +112,33 @@ impl dump::Metadata for VorbisComments {
         self
     }
 
+    fn dump_contributors(
+        &mut self,
+        config: &config::Parsing,
+        contributors: Contributors<'_>,
+    ) -> &mut Self {
+        let config::parsing::vorbis_comments::Contributors {
+            composer,
+            conductor,
+            performer,
+            lyricist,
+            arranger,
+            producer,
+        } = &config.vorbis_comments.contributors;
+        for contributor in contributors.value {
+            let key = match contributor.role {
+                contributor::Role::Composer => composer,
+                contributor::Role::Conductor => conductor,
+                contributor::Role::Performer => performer,
+                contributor::Role::Lyricist => lyricist,
+                contributor::Role::Arranger => arranger,
+                contributor::Role::Producer => producer,
+            };
+            self.push(key.clone(), contributor.format_credit());
+        }
+        self
+    }
+
     fn dump_track_disc(&mut self, config: &config::Parsing, track_disc: TrackDisc) -> &mut Self {
         track_disc.track.dump_vorbis_comments(
             self,
@@ -102,6 +160,26 @@ impl dump::Metadata for VorbisComments {
         self
     }
 
+    fn dump_replay_gain(&mut self, config: &config::Parsing, replay_gain: ReplayGain) -> &mut Self {
+        let config::parsing::vorbis_comments::ReplayGain {
+            track_gain,
+            track_peak,
+            album_gain,
+            album_peak,
+        } = &config.vorbis_comments.replay_gain;
+        for (key, value) in [
+            (track_gain, replay_gain.track_gain.map(ReplayGain::format_gain)),
+            (track_peak, replay_gain.track_peak.map(ReplayGain::format_peak)),
+            (album_gain, replay_gain.album_gain.map(ReplayGain::format_gain)),
+            (album_peak, replay_gain.album_peak.map(ReplayGain::format_peak)),
+        ] {
+            if let Some(value) = value {
+                self.push(key.clone(), value);
+            }
+        }
+        self
+    }
+
     fn dump_genres(&mut self, config: &config::Parsing, genres: Genres<'_>) -> &mut Self {
         for genre in genres.value {
             self.push(config.vorbis_comments.genres.clone(), genre.value.into_owned());
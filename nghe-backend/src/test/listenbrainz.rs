@@ -0,0 +1,84 @@
+use std::net::Ipv4Addr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use axum::Router;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::routing::{get, post};
+use concat_string::concat_string;
+use serde_json::{Value, json};
+
+use crate::config;
+
+#[derive(Clone, Default)]
+struct Data {
+    submissions: Arc<Mutex<Vec<Value>>>,
+    offline: Arc<AtomicBool>,
+}
+
+pub struct Mock {
+    pub config: config::integration::Listenbrainz,
+    data: Data,
+}
+
+impl Data {
+    fn authorized(headers: &HeaderMap) -> bool {
+        headers.get(header::AUTHORIZATION).and_then(|value| value.to_str().ok())
+            == Some(concat_string!("Token ", Mock::TOKEN).as_str())
+    }
+}
+
+async fn validate_token(headers: HeaderMap) -> axum::Json<Value> {
+    axum::Json(if Data::authorized(&headers) {
+        json!({ "valid": true, "user_name": Mock::USERNAME })
+    } else {
+        json!({ "valid": false })
+    })
+}
+
+async fn submit_listens(
+    State(data): State<Data>,
+    headers: HeaderMap,
+    axum::Json(submission): axum::Json<Value>,
+) -> StatusCode {
+    if !Data::authorized(&headers) {
+        StatusCode::UNAUTHORIZED
+    } else if data.offline.load(Ordering::Relaxed) {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        data.submissions.lock().unwrap().push(submission);
+        StatusCode::OK
+    }
+}
+
+impl Mock {
+    pub const TOKEN: &'static str = "listenbrainz-token";
+    pub const USERNAME: &'static str = "listenbrainz-user";
+
+    pub async fn new() -> Self {
+        let data = Data::default();
+        let router = Router::new()
+            .route("/1/validate-token", get(validate_token))
+            .route("/1/submit-listens", post(submit_listens))
+            .with_state(data.clone());
+
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let url = concat_string!("http://", listener.local_addr().unwrap().to_string());
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        Self { config: config::integration::Listenbrainz { url }, data }
+    }
+
+    pub fn integration(&self) -> config::Integration {
+        config::Integration { listenbrainz: self.config.clone(), ..Default::default() }
+    }
+
+    pub fn set_offline(&self, offline: bool) {
+        self.data.offline.store(offline, Ordering::Relaxed);
+    }
+
+    pub fn submissions(&self) -> Vec<Value> {
+        self.data.submissions.lock().unwrap().clone()
+    }
+}
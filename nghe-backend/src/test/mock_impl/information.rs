@@ -41,6 +41,7 @@ impl Mock<'static, 'static, 'static, 'static> {
         let album_id = upsert.foreign.album_id;
         let album = audio::Album::query_upsert(mock, upsert.foreign.album_id).await;
         let artists = audio::Artists::query(mock, id).await;
+        let contributors = audio::Contributors::query(mock, id).await;
         let genres = audio::Genres::query(mock, id).await;
         let lyrics = lyric::Lyric::query_embedded(mock, id).await;
         let image = image::Image::query_song(mock, id).await;
@@ -54,6 +55,7 @@ impl Mock<'static, 'static, 'static, 'static> {
                     song: upsert.data.song.try_into().unwrap(),
                     album: album.data.try_into().unwrap(),
                     artists,
+                    contributors,
                     genres,
                     lyrics,
                     image,
@@ -77,6 +79,7 @@ impl Mock<'static, 'static, 'static, 'static> {
         song: Option<audio::Song<'static>>,
         album: Option<audio::Album<'static>>,
         artists: Option<audio::Artists<'static>>,
+        contributors: Option<audio::Contributors<'static>>,
         genres: Option<audio::Genres<'static>>,
         lyrics: Option<Vec<lyric::Lyric<'static>>>,
         image: Option<Option<image::Image<'static>>>,
@@ -91,6 +94,7 @@ impl Mock<'static, 'static, 'static, 'static> {
             song: song.unwrap_or_else(|| Faker.fake()),
             album: album.unwrap_or_else(|| Faker.fake()),
             artists: artists.unwrap_or_else(|| Faker.fake()),
+            contributors: contributors.unwrap_or_default(),
             genres: genres.unwrap_or_else(|| Faker.fake()),
             lyrics: lyrics.unwrap_or_else(lyric::Lyric::fake_vec),
             image: image.unwrap_or_else(|| Faker.fake()),
@@ -20,9 +20,9 @@ use super::{database, filesystem};
 use crate::database::Database;
 use crate::file::audio;
 use crate::filesystem::Filesystem;
-use crate::integration::Informant;
+use crate::integration::{Informant, Scrobbler};
 use crate::orm::users;
-use crate::scan::scanner;
+use crate::scan::{scanner, watcher};
 use crate::{config, route};
 
 #[derive(Debug, Educe)]
@@ -36,6 +36,7 @@ pub struct Config {
     pub transcode: config::Transcode,
     pub cover_art: config::CoverArt,
     pub integration: config::Integration,
+    pub podcast: config::Podcast,
 
     pub lofty_parse: ParseOptions,
     pub lofty_write: WriteOptions,
@@ -47,6 +48,7 @@ pub struct Mock {
     pub database: database::Mock,
     pub filesystem: filesystem::Mock,
     pub informant: Informant,
+    pub scrobbler: Scrobbler,
 }
 
 impl Config {
@@ -54,6 +56,7 @@ impl Config {
         Self {
             transcode: self.transcode.with_prefix(prefix),
             cover_art: self.cover_art.with_prefix(prefix),
+            podcast: self.podcast.with_prefix(prefix),
             ..self
         }
     }
@@ -76,8 +79,9 @@ impl Mock {
         let filesystem = filesystem::Mock::new(prefix, &config).await;
         let config = config.with_prefix(&filesystem.prefix());
         let informant = Informant::new(config.integration.clone()).await;
+        let scrobbler = Scrobbler::new(config.integration.clone());
 
-        Self { config, database, filesystem, informant }
+        Self { config, database, filesystem, informant, scrobbler }
     }
 
     pub fn state(&self) -> &Database {
@@ -136,6 +140,7 @@ impl Mock {
         route::music_folder::add::handler(
             self.database(),
             self.filesystem(),
+            &watcher::Subscriber::default(),
             route::music_folder::add::Request {
                 ty,
                 allow,
@@ -178,7 +183,7 @@ pub async fn mock(
     #[default(None)] prefix: Option<&str>,
     #[default(false)] enable_integration: bool,
 ) -> Mock {
-    let mock = Mock::new(
+    let mock = Box::pin(Mock::new(
         prefix,
         Config {
             integration: if enable_integration {
@@ -188,7 +193,7 @@ pub async fn mock(
             },
             ..Default::default()
         },
-    )
+    ))
     .await;
     for _ in 0..n_user {
         mock.add_user().call().await;
@@ -16,7 +16,7 @@ use super::Information;
 use crate::database::Database;
 use crate::file::{self, File, audio, image, lyric};
 use crate::filesystem::Trait as _;
-use crate::orm::{albums, music_folders, songs};
+use crate::orm::{albums, directories, music_folders, songs};
 use crate::scan::scanner;
 use crate::test::filesystem::{self, Trait as _};
 
@@ -104,6 +104,7 @@ impl<'a> Mock<'a> {
         song: Option<audio::Song<'static>>,
         album: Option<audio::Album<'static>>,
         artists: Option<audio::Artists<'static>>,
+        contributors: Option<audio::Contributors<'static>>,
         genres: Option<audio::Genres<'static>>,
         image: Option<Option<image::Image<'static>>>,
         file_property: Option<file::Property<audio::Format>>,
@@ -118,6 +119,7 @@ impl<'a> Mock<'a> {
             .maybe_song(song)
             .maybe_album(album)
             .maybe_artists(artists)
+            .maybe_contributors(contributors)
             .maybe_genres(genres)
             .maybe_image(image)
             .maybe_file_property(file_property)
@@ -127,9 +129,10 @@ impl<'a> Mock<'a> {
 
         for _ in 0..n_song {
             let information = builder.clone().build();
-            let song_id = information.upsert(self, song_id).await;
+            let song_id = Box::pin(information.upsert(self, song_id)).await;
             self.database.insert(song_id, information);
         }
+        directories::Upsert::sync(self.database(), self.id()).await.unwrap();
 
         self
     }
@@ -177,7 +180,7 @@ impl<'a> Mock<'a> {
                 .format(format)
                 .relative_path(relative_path.to_string().into())
                 .build();
-            let information = information.dump(self).await;
+            let information = Box::pin(information.dump(self)).await;
 
             self.filesystem.shift_remove(&relative_path);
             self.filesystem.insert(relative_path.clone(), information);
@@ -3,7 +3,9 @@ pub mod binary;
 mod database;
 pub mod file;
 pub mod filesystem;
+pub mod listenbrainz;
 mod mock_impl;
+pub mod podcast;
 pub mod route;
 
 pub use mock_impl::{Config, Information, Mock, mock};
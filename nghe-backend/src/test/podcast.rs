@@ -0,0 +1,93 @@
+use std::net::Ipv4Addr;
+use std::sync::{Arc, Mutex};
+
+use axum::Router;
+use axum::extract::{Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use concat_string::concat_string;
+
+use super::assets;
+use crate::file::audio;
+
+#[derive(Clone, Default)]
+struct Data {
+    episodes: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+pub struct Mock {
+    pub url: String,
+    data: Data,
+}
+
+async fn feed(State(data): State<Data>) -> impl IntoResponse {
+    let items: String = data
+        .episodes
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(title, url)| {
+            concat_string!(
+                "<item><title>",
+                title,
+                "</title><guid>",
+                title,
+                "</guid><enclosure url=\"",
+                url,
+                "\"/></item>"
+            )
+        })
+        .collect();
+    (
+        [(header::CONTENT_TYPE, "application/rss+xml")],
+        concat_string!(
+            "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>",
+            Mock::TITLE,
+            "</title><description>",
+            Mock::DESCRIPTION,
+            "</description>",
+            items,
+            "</channel></rss>"
+        ),
+    )
+}
+
+async fn episode(Path(name): Path<String>) -> Result<Vec<u8>, StatusCode> {
+    let (_, extension) = name.rsplit_once('.').ok_or(StatusCode::NOT_FOUND)?;
+    let format: audio::Format = extension.parse().map_err(|_| StatusCode::NOT_FOUND)?;
+    tokio::fs::read(assets::path(format)).await.map_err(|_| StatusCode::NOT_FOUND)
+}
+
+impl Mock {
+    pub const TITLE: &'static str = "podcast-title";
+    pub const DESCRIPTION: &'static str = "podcast-description";
+
+    pub async fn new() -> Self {
+        let data = Data::default();
+        let router = Router::new()
+            .route("/feed.xml", get(feed))
+            .route("/episode/{name}", get(episode))
+            .with_state(data.clone());
+
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let url = concat_string!("http://", listener.local_addr().unwrap().to_string());
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        Self { url, data }
+    }
+
+    pub fn feed_url(&self) -> String {
+        concat_string!(self.url, "/feed.xml")
+    }
+
+    pub fn add_episode(&self, title: &str, format: audio::Format) {
+        let url = concat_string!(self.url, "/episode/", title, ".", format.as_ref());
+        self.data.episodes.lock().unwrap().push((title.to_owned(), url));
+    }
+
+    pub fn add_missing_episode(&self, title: &str) {
+        let url = concat_string!(self.url, "/episode/", title, ".missing");
+        self.data.episodes.lock().unwrap().push((title.to_owned(), url));
+    }
+}
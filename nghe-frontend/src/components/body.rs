@@ -3,7 +3,7 @@ use leptos::prelude::*;
 use leptos_router::components::{Route, Router, Routes};
 use leptos_router::path;
 
-use super::{Home, Loading, Root, Users, authentication};
+use super::{Home, Loading, Root, Share, Users, authentication};
 
 pub fn Body() -> impl IntoView {
     html::div().class("flex h-dvh box-border").child(Router(
@@ -35,6 +35,12 @@ pub fn Body() -> impl IntoView {
                                             .view(Users)
                                             .build(),
                                     ),
+                                    Route(
+                                        component_props_builder(&Route)
+                                            .path(path!("/share/:id"))
+                                            .view(Share)
+                                            .build(),
+                                    ),
                                     Route(
                                         component_props_builder(&Route)
                                             .path(path!("/setup"))
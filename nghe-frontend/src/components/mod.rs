@@ -8,6 +8,7 @@ mod form;
 mod home;
 mod loading;
 mod root;
+mod share;
 mod users;
 
 pub use body::Body;
@@ -16,4 +17,5 @@ pub use error::Boundary;
 pub use home::Home;
 pub use loading::Loading;
 pub use root::Root;
+pub use share::Share;
 pub use users::Users;
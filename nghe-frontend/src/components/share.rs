@@ -0,0 +1,137 @@
+use concat_string::concat_string;
+use leptos::prelude::*;
+use leptos::{ev, html};
+use leptos_router::hooks::use_params_map;
+use nghe_api::common::{BACKEND_PREFIX, FormURL};
+use nghe_api::id3;
+use nghe_api::sharing::get_public_share::Request;
+use nghe_api::sharing::{download_public_share, get_public_share_cover_art, share};
+use uuid::Uuid;
+
+use crate::client::Client;
+use crate::components::{Boundary, Loading};
+
+const COVER_ART_SIZE: u32 = 96;
+
+fn download_url(share_id: Uuid, id: Uuid) -> String {
+    concat_string!(
+        BACKEND_PREFIX,
+        <download_public_share::Request as FormURL>::URL_FORM,
+        "?shareId=",
+        share_id.to_string(),
+        "&id=",
+        id.to_string()
+    )
+}
+
+fn cover_art_url(share_id: Uuid, id: Uuid) -> String {
+    concat_string!(
+        BACKEND_PREFIX,
+        <get_public_share_cover_art::Request as FormURL>::URL_FORM,
+        "?shareId=",
+        share_id.to_string(),
+        "&id=",
+        id.to_string(),
+        "&size=",
+        COVER_ART_SIZE.to_string()
+    )
+}
+
+fn Row(
+    share_id: Uuid,
+    index: usize,
+    entry: id3::song::Short,
+    current: ReadSignal<usize>,
+    set_current: WriteSignal<usize>,
+) -> impl IntoView {
+    let album = entry.album;
+    let song = entry.song;
+    html::li()
+        .class(move || {
+            if current() == index {
+                "flex items-center p-2 space-x-3 rounded-lg cursor-pointer bg-gray-100 \
+                 dark:bg-gray-700"
+            } else {
+                "flex items-center p-2 space-x-3 rounded-lg cursor-pointer hover:bg-gray-100 \
+                 dark:hover:bg-gray-700"
+            }
+        })
+        .on(ev::click, move |_| set_current(index))
+        .child((
+            song.cover_art.map(|cover_art| {
+                html::img()
+                    .class("w-12 h-12 rounded")
+                    .src(cover_art_url(share_id, cover_art))
+                    .alt(album.clone())
+            }),
+            html::div().class("min-w-0 flex-1").child((
+                html::p()
+                    .class("text-sm font-medium text-gray-900 truncate dark:text-white")
+                    .child(song.title),
+                html::p()
+                    .class("text-sm text-gray-500 truncate dark:text-gray-400")
+                    .child(concat_string!(song.artist, " - ", album)),
+            )),
+        ))
+}
+
+fn Player(share: share::Share) -> impl IntoView {
+    let share_id = share.id;
+    let ids: Vec<_> = share.entry.iter().map(|entry| entry.song.id).collect();
+    let n_entry = ids.len();
+    let (current, set_current) = signal(0);
+
+    html::section().class("w-full p-4 overflow-y-auto bg-gray-50 dark:bg-gray-900").child(
+        html::div().class("mx-auto max-w-screen-md").child((
+            html::h1()
+                .class("mb-1 text-2xl font-semibold text-gray-900 dark:text-white")
+                .child(share.description.unwrap_or_else(|| "Shared music".to_owned())),
+            html::p()
+                .class("mb-4 text-sm text-gray-500 dark:text-gray-400")
+                .child(concat_string!("Shared by ", share.username)),
+            html::audio()
+                .class("w-full mb-4")
+                .controls(true)
+                .autoplay(true)
+                .src(move || ids.get(current()).map(|id| download_url(share_id, *id)))
+                .on(ev::ended, move |_| {
+                    let next = current.get_untracked() + 1;
+                    if next < n_entry {
+                        set_current(next);
+                    }
+                }),
+            html::ol().class("space-y-1").child(
+                share
+                    .entry
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, entry)| Row(share_id, index, entry, current, set_current))
+                    .collect::<Vec<_>>(),
+            ),
+        )),
+    )
+}
+
+pub fn Share() -> impl IntoView {
+    let params = use_params_map();
+    let share_resource = LocalResource::new(move || {
+        // An invalid id falls back to the nil id so the backend responds with a not found error.
+        let id = params.read().get("id").and_then(|id| id.parse().ok()).unwrap_or_default();
+        async move { Client::json_no_auth(&Request { id }).await.map(|response| response.share) }
+    });
+
+    Transition(
+        component_props_builder(&Transition)
+            .fallback(Loading)
+            .children(ToChildren::to_children(move || {
+                IntoRender::into_render(move || {
+                    Boundary(ToChildren::to_children(move || {
+                        IntoRender::into_render(move || {
+                            Suspend::new(async move { share_resource.await.map(Player) })
+                        })
+                    }))
+                })
+            }))
+            .build(),
+    )
+}
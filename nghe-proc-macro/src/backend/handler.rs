@@ -26,6 +26,7 @@ struct ArgConfig {
 enum Arg {
     Database { ident: syn::Ident, use_database: bool },
     User(syn::Ident),
+    Client,
     Request,
     Extension { ident: syn::Ident, ty: syn::TypePath, reference: bool },
     Header { ident: syn::Ident, ty: syn::TypePath },
@@ -57,6 +58,7 @@ impl Arg {
                 "database" => Ok(Self::Database { ident: pat.ident.clone(), use_database: true }),
                 "user_id" => Ok(Self::User(parse_quote!(id))),
                 "user_role" => Ok(Self::User(parse_quote!(role))),
+                "user_client" => Ok(Self::Client),
                 "request" => Ok(Self::Request),
                 _ => {
                     let ty = if config.header {
@@ -129,6 +131,7 @@ impl Arg {
                 if *use_database { Some(parse_quote!(&#ident)) } else { None },
             ),
             Arg::User(ident) => (None, Some(parse_quote!(user.user.#ident))),
+            Arg::Client => (None, Some(parse_quote!(user.client))),
             Arg::Request => (None, None),
             Arg::Extension { ident, ty, reference, .. } => (
                 Some(
@@ -153,7 +156,7 @@ impl Args {
             // Need for authentication or setup.
             value.push(Arg::Database { ident: format_ident!("_database"), use_database: false });
         }
-        let use_user = value.iter().any(|arg| matches!(arg, Arg::User(_)));
+        let use_user = value.iter().any(|arg| matches!(arg, Arg::User(_) | Arg::Client));
         let use_request = value.iter().any(|arg| matches!(arg, Arg::Request));
         Ok(Self { value, use_user, use_request })
     }
@@ -192,9 +195,14 @@ impl Handler {
             if self.config.need_auth {
                 additional_args
                     .push(parse_quote!(#user_ident: crate::http::extract::auth::Form<Request>));
-            }
-            if self.args.use_request {
-                additional_exprs.push(parse_quote!(user.request));
+                if self.args.use_request {
+                    additional_exprs.push(parse_quote!(user.request));
+                }
+            } else if self.args.use_request {
+                additional_args.push(parse_quote! {
+                    crate::http::extract::Form(request): crate::http::extract::Form<Request>
+                });
+                additional_exprs.push(parse_quote!(request));
             }
 
             Some(self.handler(